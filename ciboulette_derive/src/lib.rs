@@ -0,0 +1,485 @@
+//! # Introduction
+//!
+//! **_Ciboulette derive_** describes the resource types of a
+//! [ciboulette](https://docs.rs/ciboulette) store from Rust structs.
+//!
+//! ```ignore
+//! #[derive(CibouletteResource)]
+//! #[ciboulette(type_name = "comments")]
+//! struct Comment {
+//!     #[ciboulette(id)]
+//!     id: Uuid,
+//!     body: String,
+//!     #[ciboulette(belongs_to = "peoples", alias = "author", reverse = "comments")]
+//!     author: Uuid,
+//! }
+//! ```
+//!
+//! ## Container attributes
+//!
+//! - `type_name = "..."`: The name of the type in the store, defaults to the struct name in snake case
+//! - `many_to_many(to = "...", bucket = "...", key = "...", to_key = "...", alias = "...", reverse = "...", no_reverse)`:
+//!   A Many-to-Many relationship to the type `to`, through the type `bucket` whose fields `key`
+//!   and `to_key` point respectively to this type and to the type `to`
+//!
+//! ## Field attributes
+//!
+//! - `id`: The field is (part of) the id of the type
+//! - `parser = "..."`: The id is handled by a custom parser registered in the store builder
+//! - `rename = "..."`: The name of the member in the schema
+//! - `skip`: The field isn't part of the schema
+//! - `belongs_to = "..."`: The field points to a resource of another type, making a
+//!   Many-to-One relationship instead of an attribute, optional if the field is an [Option]
+//! - `alias = "..."`, `reverse = "..."`, `one_key = "..."`, `no_reverse`, `optional`: The options
+//!   of a `belongs_to` relationship
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote;
+use syn::spanned::Spanned;
+use syn::{parse_macro_input, Data, DeriveInput, Fields, Lit, Meta, NestedMeta};
+
+/// Implement `CibouletteResourceModel` for a struct
+#[proc_macro_derive(CibouletteResource, attributes(ciboulette))]
+pub fn derive_resource(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_resource(&input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+/// Implement `CibouletteAttributeModel` for a struct, described as an object
+#[proc_macro_derive(CibouletteAttributes, attributes(ciboulette))]
+pub fn derive_attributes(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_attributes(&input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+/// The options of a Many-to-Many relationship declared on a struct
+#[derive(Default)]
+struct ManyToManyOptions {
+    to: Option<String>,
+    bucket: Option<String>,
+    key: Option<String>,
+    to_key: Option<String>,
+    alias: Option<String>,
+    reverse: Option<String>,
+    no_reverse: bool,
+}
+
+/// The options of a struct
+#[derive(Default)]
+struct ContainerOptions {
+    type_name: Option<String>,
+    many_to_many: Vec<ManyToManyOptions>,
+}
+
+/// The options of a field
+#[derive(Default)]
+struct FieldOptions {
+    id: bool,
+    parser: Option<String>,
+    rename: Option<String>,
+    skip: bool,
+    belongs_to: Option<String>,
+    alias: Option<String>,
+    reverse: Option<String>,
+    one_key: Option<String>,
+    no_reverse: bool,
+    optional: bool,
+}
+
+/// A field of a struct, with its options
+struct Field<'a> {
+    name: String,
+    type_: &'a syn::Type,
+    options: FieldOptions,
+}
+
+/// List the `#[ciboulette(...)]` options
+fn ciboulette_options(attrs: &[syn::Attribute]) -> syn::Result<Vec<NestedMeta>> {
+    let mut res = Vec::new();
+    for attr in attrs.iter().filter(|x| x.path.is_ident("ciboulette")) {
+        match attr.parse_meta()? {
+            Meta::List(list) => res.extend(list.nested),
+            meta => return Err(syn::Error::new(meta.span(), "expected `ciboulette(...)`")),
+        }
+    }
+    Ok(res)
+}
+
+/// Get the string value of a `name = "value"` option
+fn string_value(lit: &Lit) -> syn::Result<String> {
+    match lit {
+        Lit::Str(x) => Ok(x.value()),
+        _ => Err(syn::Error::new(lit.span(), "expected a string literal")),
+    }
+}
+
+/// Get the name of an option
+fn option_name(path: &syn::Path) -> syn::Result<String> {
+    path.get_ident()
+        .map(|x| x.to_string())
+        .ok_or_else(|| syn::Error::new(path.span(), "unknown ciboulette option"))
+}
+
+fn unknown_option(span: Span, name: &str) -> syn::Error {
+    syn::Error::new(span, format!("unknown ciboulette option `{}`", name))
+}
+
+impl ManyToManyOptions {
+    fn parse(list: &syn::MetaList) -> syn::Result<Self> {
+        let mut res = ManyToManyOptions::default();
+        for nested in list.nested.iter() {
+            match nested {
+                NestedMeta::Meta(Meta::NameValue(x)) => {
+                    let value = Some(string_value(&x.lit)?);
+                    match option_name(&x.path)?.as_str() {
+                        "to" => res.to = value,
+                        "bucket" => res.bucket = value,
+                        "key" => res.key = value,
+                        "to_key" => res.to_key = value,
+                        "alias" => res.alias = value,
+                        "reverse" => res.reverse = value,
+                        name => return Err(unknown_option(x.span(), name)),
+                    }
+                }
+                NestedMeta::Meta(Meta::Path(x)) => match option_name(x)?.as_str() {
+                    "no_reverse" => res.no_reverse = true,
+                    name => return Err(unknown_option(x.span(), name)),
+                },
+                x => return Err(syn::Error::new(x.span(), "unexpected ciboulette option")),
+            }
+        }
+        for (name, value) in [
+            ("to", &res.to),
+            ("bucket", &res.bucket),
+            ("key", &res.key),
+            ("to_key", &res.to_key),
+        ]
+        .iter()
+        {
+            if value.is_none() {
+                return Err(syn::Error::new(
+                    list.span(),
+                    format!("`many_to_many` requires the `{}` option", name),
+                ));
+            }
+        }
+        Ok(res)
+    }
+}
+
+impl ContainerOptions {
+    fn parse(attrs: &[syn::Attribute]) -> syn::Result<Self> {
+        let mut res = ContainerOptions::default();
+        for nested in ciboulette_options(attrs)? {
+            match &nested {
+                NestedMeta::Meta(Meta::NameValue(x)) => match option_name(&x.path)?.as_str() {
+                    "type_name" => res.type_name = Some(string_value(&x.lit)?),
+                    name => return Err(unknown_option(x.span(), name)),
+                },
+                NestedMeta::Meta(Meta::List(x)) => match option_name(&x.path)?.as_str() {
+                    "many_to_many" => res.many_to_many.push(ManyToManyOptions::parse(x)?),
+                    name => return Err(unknown_option(x.span(), name)),
+                },
+                x => return Err(syn::Error::new(x.span(), "unexpected ciboulette option")),
+            }
+        }
+        Ok(res)
+    }
+}
+
+impl FieldOptions {
+    fn parse(attrs: &[syn::Attribute]) -> syn::Result<Self> {
+        let mut res = FieldOptions::default();
+        for nested in ciboulette_options(attrs)? {
+            match &nested {
+                NestedMeta::Meta(Meta::NameValue(x)) => {
+                    let value = Some(string_value(&x.lit)?);
+                    match option_name(&x.path)?.as_str() {
+                        "parser" => res.parser = value,
+                        "rename" => res.rename = value,
+                        "belongs_to" => res.belongs_to = value,
+                        "alias" => res.alias = value,
+                        "reverse" => res.reverse = value,
+                        "one_key" => res.one_key = value,
+                        name => return Err(unknown_option(x.span(), name)),
+                    }
+                }
+                NestedMeta::Meta(Meta::Path(x)) => match option_name(x)?.as_str() {
+                    "id" => res.id = true,
+                    "skip" => res.skip = true,
+                    "no_reverse" => res.no_reverse = true,
+                    "optional" => res.optional = true,
+                    name => return Err(unknown_option(x.span(), name)),
+                },
+                x => return Err(syn::Error::new(x.span(), "unexpected ciboulette option")),
+            }
+        }
+        Ok(res)
+    }
+}
+
+/// List the fields of a struct with named fields
+fn fields(input: &DeriveInput) -> syn::Result<Vec<Field<'_>>> {
+    let named = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(x) => &x.named,
+            _ => {
+                return Err(syn::Error::new(
+                    input.span(),
+                    "only the structs with named fields are supported",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new(
+                input.span(),
+                "only the structs with named fields are supported",
+            ))
+        }
+    };
+    let mut res = Vec::with_capacity(named.len());
+    for field in named.iter() {
+        let options = FieldOptions::parse(&field.attrs)?;
+        let name = match &options.rename {
+            Some(x) => x.clone(),
+            None => field
+                .ident
+                .as_ref()
+                .map(|x| x.to_string().trim_start_matches("r#").to_string())
+                .unwrap_or_default(),
+        };
+        res.push(Field {
+            name,
+            type_: &field.ty,
+            options,
+        });
+    }
+    Ok(res)
+}
+
+/// Check if a type is an [Option]
+fn is_option(type_: &syn::Type) -> bool {
+    match type_ {
+        syn::Type::Path(x) => x
+            .path
+            .segments
+            .last()
+            .map(|x| x.ident == "Option")
+            .unwrap_or(false),
+        _ => false,
+    }
+}
+
+/// Convert a struct name to snake case
+fn snake_case(name: &str) -> String {
+    let mut res = String::with_capacity(name.len() + 4);
+    for (i, c) in name.chars().enumerate() {
+        if c.is_uppercase() {
+            if i > 0 {
+                res.push('_');
+            }
+            res.extend(c.to_lowercase());
+        } else {
+            res.push(c);
+        }
+    }
+    res
+}
+
+fn quote_option(value: &Option<String>) -> TokenStream2 {
+    match value {
+        Some(x) => quote! { ::std::option::Option::Some(::std::string::String::from(#x)) },
+        None => quote! { ::std::option::Option::None },
+    }
+}
+
+/// Build the expression of a relationship end
+fn quote_end(
+    type_: TokenStream2,
+    key: &str,
+    alias: &Option<String>,
+    exposed: bool,
+) -> TokenStream2 {
+    let alias = quote_option(alias);
+    let end = quote! {
+        ::ciboulette::CibouletteRelationshipEndDefinition::new(
+            ::std::string::String::from(#type_),
+            ::std::string::String::from(#key),
+            #alias,
+        )
+    };
+    match exposed {
+        true => end,
+        false => quote! {
+            {
+                let mut end = #end;
+                *end.exposed_mut() = false;
+                end
+            }
+        },
+    }
+}
+
+/// Build the expression of the attributes map of a struct
+fn quote_attributes(fields: &[Field<'_>]) -> TokenStream2 {
+    let attributes = fields
+        .iter()
+        .filter(|x| !x.options.skip && !x.options.id && x.options.belongs_to.is_none())
+        .map(|field| {
+            let name = &field.name;
+            let type_ = field.type_;
+            quote! {
+                attributes.insert(
+                    ::std::string::String::from(#name),
+                    <#type_ as ::ciboulette::CibouletteAttributeModel>::attribute_definition(),
+                );
+            }
+        });
+    quote! {
+        {
+            let mut attributes = ::std::collections::BTreeMap::new();
+            #(#attributes)*
+            attributes
+        }
+    }
+}
+
+fn expand_resource(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let options = ContainerOptions::parse(&input.attrs)?;
+    let fields = fields(input)?;
+    let ident = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    let type_name = options
+        .type_name
+        .clone()
+        .unwrap_or_else(|| snake_case(&ident.to_string()));
+
+    let ids: Vec<TokenStream2> = fields
+        .iter()
+        .filter(|x| x.options.id)
+        .map(|field| {
+            let name = &field.name;
+            let type_ = field.type_;
+            match &field.options.parser {
+                Some(parser) => quote! {
+                    ::ciboulette::CibouletteIdDefinition::Custom {
+                        name: ::std::string::String::from(#name),
+                        parser: ::std::string::String::from(#parser),
+                    }
+                },
+                None => quote! {
+                    <#type_ as ::ciboulette::CibouletteIdModel>::id_definition(#name)
+                },
+            }
+        })
+        .collect();
+    if ids.is_empty() {
+        return Err(syn::Error::new(
+            input.span(),
+            "a resource requires at least one `#[ciboulette(id)]` field",
+        ));
+    }
+    let attributes = quote_attributes(&fields);
+
+    let mut relationships: Vec<TokenStream2> = Vec::new();
+    for field in fields.iter().filter(|x| !x.options.skip) {
+        let one_type = match &field.options.belongs_to {
+            Some(x) => x,
+            None => continue,
+        };
+        let one = quote_end(
+            quote! { #one_type },
+            field.options.one_key.as_deref().unwrap_or("id"),
+            &field.options.reverse,
+            !field.options.no_reverse,
+        );
+        let many = quote_end(
+            quote! { #type_name },
+            &field.name,
+            &field.options.alias,
+            true,
+        );
+        let optional = field.options.optional || is_option(field.type_);
+        relationships.push(quote! {
+            ::ciboulette::CibouletteRelationshipDefinition::OneToMany {
+                one: #one,
+                many: #many,
+                optional: #optional,
+            }
+        });
+    }
+    for rel in options.many_to_many.iter() {
+        let bucket = rel.bucket.as_deref().unwrap_or_default();
+        let from = quote_end(
+            quote! { #type_name },
+            rel.key.as_deref().unwrap_or_default(),
+            &rel.alias,
+            true,
+        );
+        let to_type = rel.to.as_deref().unwrap_or_default();
+        let to = quote_end(
+            quote! { #to_type },
+            rel.to_key.as_deref().unwrap_or_default(),
+            &rel.reverse,
+            !rel.no_reverse,
+        );
+        relationships.push(quote! {
+            ::ciboulette::CibouletteRelationshipDefinition::ManyToMany {
+                bucket: ::std::string::String::from(#bucket),
+                from: #from,
+                to: #to,
+            }
+        });
+    }
+
+    Ok(quote! {
+        impl #impl_generics ::ciboulette::CibouletteResourceModel for #ident #type_generics #where_clause {
+            fn type_name() -> &'static str {
+                #type_name
+            }
+
+            fn type_definition() -> ::ciboulette::CibouletteTypeDefinition {
+                ::ciboulette::CibouletteTypeDefinition::new(
+                    ::std::vec![#(#ids),*],
+                    #attributes,
+                )
+            }
+
+            fn relationship_definitions() -> ::std::vec::Vec<::ciboulette::CibouletteRelationshipDefinition> {
+                ::std::vec![#(#relationships),*]
+            }
+        }
+    })
+}
+
+fn expand_attributes(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = fields(input)?;
+    if let Some(field) = fields
+        .iter()
+        .find(|x| x.options.id || x.options.belongs_to.is_some())
+    {
+        return Err(syn::Error::new(
+            field.type_.span(),
+            "ids and relationships are only supported on resources",
+        ));
+    }
+    let ident = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    let attributes = quote_attributes(&fields);
+
+    Ok(quote! {
+        impl #impl_generics ::ciboulette::CibouletteAttributeModel for #ident #type_generics #where_clause {
+            fn attribute_definition() -> ::ciboulette::CibouletteAttributeDefinition {
+                ::ciboulette::CibouletteAttributeDefinition::Object {
+                    properties: #attributes,
+                    optional: false,
+                }
+            }
+        }
+    })
+}
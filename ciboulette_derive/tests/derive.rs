@@ -0,0 +1,188 @@
+use ciboulette::*;
+use ciboulette_derive::{CibouletteAttributes, CibouletteResource};
+use uuid::Uuid;
+
+#[derive(CibouletteAttributes)]
+#[allow(dead_code)]
+struct Address {
+    street: String,
+    zip: Option<u32>,
+}
+
+#[derive(CibouletteResource)]
+#[ciboulette(type_name = "peoples")]
+#[allow(dead_code)]
+struct People {
+    #[ciboulette(id)]
+    id: Uuid,
+    #[ciboulette(rename = "first-name")]
+    first_name: String,
+    age: Option<u8>,
+    tags: Vec<String>,
+    address: Option<Address>,
+    #[ciboulette(skip)]
+    password: String,
+}
+
+#[derive(CibouletteResource)]
+#[ciboulette(type_name = "articles")]
+#[ciboulette(many_to_many(
+    to = "peoples",
+    bucket = "people-article",
+    key = "article_id",
+    to_key = "people_id",
+    alias = "readers",
+    reverse = "read"
+))]
+#[allow(dead_code)]
+struct Article {
+    #[ciboulette(id)]
+    id: Uuid,
+    title: String,
+    #[ciboulette(belongs_to = "peoples", alias = "author", reverse = "articles")]
+    author: Option<Uuid>,
+}
+
+#[derive(CibouletteResource)]
+#[ciboulette(type_name = "people-article")]
+#[allow(dead_code)]
+struct PeopleArticle {
+    #[ciboulette(id)]
+    people_id: Uuid,
+    #[ciboulette(id)]
+    article_id: Uuid,
+}
+
+#[derive(CibouletteResource)]
+#[allow(dead_code)]
+struct FavoriteColor {
+    #[ciboulette(id)]
+    id: String,
+    color: String,
+}
+
+#[test]
+fn type_definition() {
+    let definition = People::type_definition();
+
+    assert_eq!(People::type_name(), "peoples");
+    assert_eq!(
+        definition.ids(),
+        &vec![CibouletteIdDefinition::Uuid {
+            name: "id".to_string()
+        }]
+    );
+    assert_eq!(
+        definition.attributes()["first-name"],
+        CibouletteAttributeDefinition::String { optional: false }
+    );
+    assert_eq!(
+        definition.attributes()["age"],
+        CibouletteAttributeDefinition::Number {
+            format: CibouletteNumberFormat::U8,
+            optional: true
+        }
+    );
+    assert_eq!(
+        definition.attributes()["address"],
+        CibouletteAttributeDefinition::Object {
+            properties: vec![
+                (
+                    "street".to_string(),
+                    CibouletteAttributeDefinition::String { optional: false }
+                ),
+                (
+                    "zip".to_string(),
+                    CibouletteAttributeDefinition::Number {
+                        format: CibouletteNumberFormat::U32,
+                        optional: true
+                    }
+                ),
+            ]
+            .into_iter()
+            .collect(),
+            optional: true
+        }
+    );
+    assert_eq!(definition.attributes().contains_key("password"), false);
+    assert_eq!(definition.attributes().contains_key("id"), false);
+}
+
+#[test]
+fn default_type_name() {
+    assert_eq!(FavoriteColor::type_name(), "favorite_color");
+    assert_eq!(
+        FavoriteColor::type_definition().ids(),
+        &vec![CibouletteIdDefinition::Text {
+            name: "id".to_string()
+        }]
+    );
+}
+
+#[test]
+fn relationships() {
+    let relationships = Article::relationship_definitions();
+
+    assert_eq!(relationships.len(), 2);
+    match &relationships[0] {
+        CibouletteRelationshipDefinition::OneToMany {
+            one,
+            many,
+            optional,
+        } => {
+            assert_eq!(one.type_(), "peoples");
+            assert_eq!(one.key(), "id");
+            assert_eq!(one.alias().as_deref(), Some("articles"));
+            assert_eq!(many.type_(), "articles");
+            assert_eq!(many.key(), "author");
+            assert_eq!(many.alias().as_deref(), Some("author"));
+            assert_eq!(*optional, true);
+        }
+        _ => panic!("Expected a One-to-Many relationship"),
+    }
+    // The key of a relationship isn't an attribute
+    assert_eq!(
+        Article::type_definition()
+            .attributes()
+            .contains_key("author"),
+        false
+    );
+}
+
+#[test]
+fn store() {
+    let mut builder = CibouletteStoreBuilder::default();
+    builder.add_model::<People>().unwrap();
+    builder.add_model::<Article>().unwrap();
+    builder.add_model::<PeopleArticle>().unwrap();
+    builder.add_model_relationships::<Article>().unwrap();
+    let store = builder.build().unwrap();
+
+    let articles = store.get_type("articles").unwrap();
+    assert_eq!(articles.relationships().contains_key("author"), true);
+    assert_eq!(articles.relationships().contains_key("readers"), true);
+    let peoples = store.get_type("peoples").unwrap();
+    assert_eq!(peoples.relationships().contains_key("articles"), true);
+    assert_eq!(peoples.relationships().contains_key("read"), true);
+    assert_eq!(peoples.mandatory_attributes().contains("tags"), true);
+}
+
+#[test]
+fn store_definition() {
+    let mut definition = CibouletteStoreDefinition::default();
+    definition.add_model::<People>().unwrap();
+    definition.add_model::<Article>().unwrap();
+    definition.add_model::<PeopleArticle>().unwrap();
+    let mut builder = CibouletteStoreBuilder::default();
+    builder.add_definition(&definition).unwrap();
+    let store = builder.build().unwrap();
+
+    assert_eq!(store.to_definition().unwrap().types(), definition.types());
+    assert_eq!(
+        matches!(
+            definition.add_model::<People>(),
+            Err(CibouletteError::UniqType(x)) if x == "peoples"
+        ),
+        true
+    );
+}
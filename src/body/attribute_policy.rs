@@ -0,0 +1,38 @@
+use super::*;
+
+/// ## Access policy of an attribute of a [resource type](CibouletteResourceType)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CibouletteAttributePolicy {
+    /// The attribute can be read and written
+    ReadWrite,
+    /// The attribute is generated by the server, it can't be set by the clients
+    ReadOnly,
+    /// The attribute can be set by the clients but is never sent back to them
+    WriteOnly,
+    /// The attribute can only be set when creating the resource
+    CreateOnly,
+}
+
+impl Default for CibouletteAttributePolicy {
+    fn default() -> Self {
+        CibouletteAttributePolicy::ReadWrite
+    }
+}
+
+impl CibouletteAttributePolicy {
+    /// Check if the attribute can be sent by the clients for a given intention
+    pub fn is_writable(&self, intention: &CibouletteIntention) -> bool {
+        match (self, intention) {
+            (CibouletteAttributePolicy::ReadOnly, _) => false,
+            (CibouletteAttributePolicy::CreateOnly, CibouletteIntention::Create) => true,
+            (CibouletteAttributePolicy::CreateOnly, _) => false,
+            (CibouletteAttributePolicy::ReadWrite, _)
+            | (CibouletteAttributePolicy::WriteOnly, _) => true,
+        }
+    }
+
+    /// Check if the attribute can be sent back to the clients
+    pub fn is_readable(&self) -> bool {
+        !matches!(self, CibouletteAttributePolicy::WriteOnly)
+    }
+}
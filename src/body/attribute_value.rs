@@ -0,0 +1,36 @@
+use super::*;
+
+/// ## State of an attribute sent by a client
+///
+/// Allows telling apart the attributes left unchanged by a partial update from the ones
+/// explicitly reset
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CibouletteAttributeValue<'a, 'request> {
+    /// The attribute wasn't sent
+    Absent,
+    /// The attribute was explicitly set to `null`
+    Null,
+    /// The attribute was set to a value
+    Set(&'a MessyJsonValue<'request>),
+}
+
+impl<'a, 'request> CibouletteAttributeValue<'a, 'request> {
+    /// Check if the attribute was sent by the client
+    pub fn is_present(&self) -> bool {
+        !matches!(self, CibouletteAttributeValue::Absent)
+    }
+}
+
+impl<'a, 'request> From<Option<&'a MessyJsonValue<'request>>>
+    for CibouletteAttributeValue<'a, 'request>
+{
+    fn from(value: Option<&'a MessyJsonValue<'request>>) -> Self {
+        match value {
+            None | Some(MessyJsonValue::Null(MessyJsonNullType::Absent, ..)) => {
+                CibouletteAttributeValue::Absent
+            }
+            Some(MessyJsonValue::Null(..)) => CibouletteAttributeValue::Null,
+            Some(value) => CibouletteAttributeValue::Set(value),
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use super::*;
+use getset::CopyGetters;
+
+/// ## Policy applied to the ids sent by the clients when creating a resource
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CibouletteClientIdPolicy {
+    /// The clients can't choose the id of the resources they create
+    Forbidden,
+    /// The clients may choose the id of the resources they create
+    Allowed,
+    /// The clients must choose the id of the resources they create
+    Required,
+}
+
+impl Default for CibouletteClientIdPolicy {
+    fn default() -> Self {
+        CibouletteClientIdPolicy::Allowed
+    }
+}
+
+/// ## Operations allowed on a [resource type](CibouletteResourceType)
+///
+/// Reading is always allowed. By default, every operation is allowed.
+#[derive(Debug, Clone, Copy, CopyGetters, MutGetters, PartialEq, Eq, Hash)]
+#[getset(get_copy = "pub", get_mut = "pub")]
+pub struct CibouletteTypeCapabilities {
+    /// The resources can be created
+    creatable: bool,
+    /// The resources can be updated
+    updatable: bool,
+    /// The resources can be deleted
+    deletable: bool,
+    /// The policy applied to the ids sent by the clients when creating a resource
+    client_ids: CibouletteClientIdPolicy,
+}
+
+impl Default for CibouletteTypeCapabilities {
+    fn default() -> Self {
+        CibouletteTypeCapabilities {
+            creatable: true,
+            updatable: true,
+            deletable: true,
+            client_ids: CibouletteClientIdPolicy::default(),
+        }
+    }
+}
+
+impl CibouletteTypeCapabilities {
+    /// The capabilities of a read-only type
+    pub fn read_only() -> Self {
+        CibouletteTypeCapabilities {
+            creatable: false,
+            updatable: false,
+            deletable: false,
+            client_ids: CibouletteClientIdPolicy::Forbidden,
+        }
+    }
+
+    /// Check if an intention is allowed on the resources of the type
+    pub fn allows(&self, intention: &CibouletteIntention) -> bool {
+        match intention {
+            CibouletteIntention::Read => true,
+            CibouletteIntention::Create => self.creatable,
+            CibouletteIntention::Update => self.updatable,
+            CibouletteIntention::Delete => self.deletable,
+        }
+    }
+}
+
+/// ## Operations allowed on a relationship through its endpoints
+///
+/// By default, every operation is allowed.
+#[derive(Debug, Clone, Copy, CopyGetters, MutGetters, PartialEq, Eq, Hash)]
+#[getset(get_copy = "pub", get_mut = "pub")]
+pub struct CibouletteRelationshipCapabilities {
+    /// The relationship can be fetched and included
+    readable: bool,
+    /// The relationship can be modified through its relationship endpoint
+    mutable: bool,
+}
+
+impl Default for CibouletteRelationshipCapabilities {
+    fn default() -> Self {
+        CibouletteRelationshipCapabilities {
+            readable: true,
+            mutable: true,
+        }
+    }
+}
+
+impl CibouletteRelationshipCapabilities {
+    /// Check if an intention is allowed on the relationship
+    pub fn allows(&self, intention: &CibouletteIntention) -> bool {
+        match intention {
+            CibouletteIntention::Read => self.readable,
+            _ => self.mutable,
+        }
+    }
+}
@@ -0,0 +1,167 @@
+use super::*;
+use std::hash::{Hash, Hasher};
+
+/// ## Custom validator of an attribute value
+pub trait CibouletteAttributeValidator: Send + Sync {
+    /// Check the value of the attribute, returning the reason of its rejection if invalid
+    fn validate(&self, value: &MessyJsonValue<'_>) -> Result<(), String>;
+}
+
+impl<F> CibouletteAttributeValidator for F
+where
+    F: Fn(&MessyJsonValue<'_>) -> Result<(), String> + Send + Sync,
+{
+    fn validate(&self, value: &MessyJsonValue<'_>) -> Result<(), String> {
+        self(value)
+    }
+}
+
+/// ## Constraint on the value of an attribute
+///
+/// The constraints are only checked against the values of the matching kind,
+/// i.e. a [Length](CibouletteAttributeConstraint::Length) constraint ignores numbers.
+#[derive(Clone)]
+pub enum CibouletteAttributeConstraint {
+    /// Bounds of the number of characters of a string
+    Length {
+        min: Option<usize>,
+        max: Option<usize>,
+    },
+    /// Pattern a string should match
+    Pattern(regex::Regex),
+    /// Bounds of a number
+    Range {
+        min: Option<u128>,
+        max: Option<u128>,
+    },
+    /// Values a string can take
+    OneOf(Vec<ArcStr>),
+    /// Bounds of the number of items of an array
+    ArrayLength {
+        min: Option<usize>,
+        max: Option<usize>,
+    },
+    /// Custom validation
+    Custom(Arc<dyn CibouletteAttributeValidator>),
+}
+
+impl std::fmt::Debug for CibouletteAttributeConstraint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CibouletteAttributeConstraint::Length { min, max } => f
+                .debug_struct("Length")
+                .field("min", min)
+                .field("max", max)
+                .finish(),
+            CibouletteAttributeConstraint::Pattern(x) => {
+                f.debug_tuple("Pattern").field(&x.as_str()).finish()
+            }
+            CibouletteAttributeConstraint::Range { min, max } => f
+                .debug_struct("Range")
+                .field("min", min)
+                .field("max", max)
+                .finish(),
+            CibouletteAttributeConstraint::OneOf(x) => f.debug_tuple("OneOf").field(x).finish(),
+            CibouletteAttributeConstraint::ArrayLength { min, max } => f
+                .debug_struct("ArrayLength")
+                .field("min", min)
+                .field("max", max)
+                .finish(),
+            CibouletteAttributeConstraint::Custom(_) => f.write_str("Custom"),
+        }
+    }
+}
+
+impl Hash for CibouletteAttributeConstraint {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            CibouletteAttributeConstraint::Length { min, max }
+            | CibouletteAttributeConstraint::ArrayLength { min, max } => {
+                min.hash(state);
+                max.hash(state);
+            }
+            CibouletteAttributeConstraint::Pattern(x) => x.as_str().hash(state),
+            CibouletteAttributeConstraint::Range { min, max } => {
+                min.hash(state);
+                max.hash(state);
+            }
+            CibouletteAttributeConstraint::OneOf(x) => x.hash(state),
+            CibouletteAttributeConstraint::Custom(_) => (),
+        }
+    }
+}
+
+/// Check that a size is within bounds
+#[inline]
+fn check_bounds<T>(kind: &str, value: T, min: &Option<T>, max: &Option<T>) -> Result<(), String>
+where
+    T: PartialOrd + std::fmt::Display + Copy,
+{
+    if let Some(min) = min {
+        if value < *min {
+            return Err(format!("{} should be at least {}", kind, min));
+        }
+    }
+    if let Some(max) = max {
+        if value > *max {
+            return Err(format!("{} should be at most {}", kind, max));
+        }
+    }
+    Ok(())
+}
+
+impl CibouletteAttributeConstraint {
+    /// Check a value against the constraint, returning the reason of its rejection if invalid
+    pub fn validate(&self, value: &MessyJsonValue<'_>) -> Result<(), String> {
+        match (self, value) {
+            (CibouletteAttributeConstraint::Length { min, max }, MessyJsonValue::String(x)) => {
+                check_bounds("The length", x.chars().count(), min, max)
+            }
+            (CibouletteAttributeConstraint::Pattern(pattern), MessyJsonValue::String(x)) => {
+                match pattern.is_match(x) {
+                    true => Ok(()),
+                    false => Err(format!("The value should match `{}`", pattern.as_str())),
+                }
+            }
+            (CibouletteAttributeConstraint::Range { min, max }, MessyJsonValue::Number(x)) => {
+                check_bounds("The value", *x, min, max)
+            }
+            (CibouletteAttributeConstraint::OneOf(values), MessyJsonValue::String(x)) => {
+                match values.iter().any(|v| v.as_str() == x.as_ref()) {
+                    true => Ok(()),
+                    false => Err(format!(
+                        "The value should be one of {}",
+                        values
+                            .iter()
+                            .map(|v| format!("`{}`", v))
+                            .collect::<Vec<String>>()
+                            .join(", ")
+                    )),
+                }
+            }
+            (CibouletteAttributeConstraint::ArrayLength { min, max }, MessyJsonValue::Array(x)) => {
+                check_bounds("The number of items", x.len(), min, max)
+            }
+            (CibouletteAttributeConstraint::Custom(validator), value) => validator.validate(value),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// ## Violation of an attribute constraint
+#[derive(Debug, Clone, Getters, PartialEq, Eq)]
+#[getset(get = "pub")]
+pub struct CibouletteConstraintViolation {
+    /// The attribute, as named by the clients
+    attribute: ArcStr,
+    /// The reason of the violation
+    reason: String,
+}
+
+impl CibouletteConstraintViolation {
+    /// Create a new violation
+    pub fn new(attribute: ArcStr, reason: String) -> Self {
+        CibouletteConstraintViolation { attribute, reason }
+    }
+}
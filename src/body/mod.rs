@@ -1,8 +1,13 @@
 use super::*;
 
+pub mod attribute_policy;
+pub mod attribute_value;
 pub mod body_optional_data;
+pub mod capabilities;
+pub mod constraint;
 pub mod errors_obj;
 pub mod link;
+pub(crate) mod pointer;
 pub mod relationship;
 pub mod request_body;
 pub mod request_body_data;
@@ -11,6 +16,8 @@ pub mod resource_obj;
 pub mod resource_obj_selector;
 pub mod resource_type;
 pub mod resource_type_builder;
+pub mod typed_attributes;
+pub mod unknown_members_policy;
 
 #[cfg(test)]
 mod tests;
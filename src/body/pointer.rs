@@ -0,0 +1,43 @@
+use super::*;
+use serde_path_to_error::{Path, Segment};
+
+/// Escape a member name to be used as a JSON pointer token
+pub(crate) fn escape_pointer_token(name: &str) -> Cow<'_, str> {
+    match name.contains(|c| c == '~' || c == '/') {
+        true => Cow::Owned(name.replace('~', "~0").replace('/', "~1")),
+        false => Cow::Borrowed(name),
+    }
+}
+
+/// Convert the path tracked while deserializing a document to a JSON pointer
+pub(crate) fn path_to_pointer(path: &Path) -> String {
+    path_to_pointer_with(path, |name| name)
+}
+
+/// Convert the path tracked while deserializing a document to a JSON pointer,
+/// translating its first member name with `root_member`
+pub(crate) fn path_to_pointer_with<'a, F>(path: &'a Path, root_member: F) -> String
+where
+    F: Fn(&'a str) -> &'a str,
+{
+    let mut res = String::new();
+
+    for (i, segment) in path.iter().enumerate() {
+        match segment {
+            Segment::Seq { index } => {
+                res.push('/');
+                res.push_str(index.to_string().as_str());
+            }
+            Segment::Map { key } => {
+                res.push('/');
+                let key = match i {
+                    0 => root_member(key.as_str()),
+                    _ => key.as_str(),
+                };
+                res.push_str(escape_pointer_token(key).as_ref());
+            }
+            _ => (),
+        }
+    }
+    res
+}
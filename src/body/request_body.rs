@@ -6,11 +6,21 @@ use std::fmt::Formatter;
 #[getset(get = "pub", get_mut = "pub")]
 pub struct CibouletteJsonApiVersion<'request> {
     version: Cow<'request, str>,
+    /// The URIs of the applied extensions
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    ext: Vec<Cow<'request, str>>,
+    /// The URIs of the applied profiles
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    profile: Vec<Cow<'request, str>>,
 }
 
 impl<'request> CibouletteJsonApiVersion<'request> {
     pub fn new(version: Cow<'request, str>) -> CibouletteJsonApiVersion<'request> {
-        CibouletteJsonApiVersion { version }
+        CibouletteJsonApiVersion {
+            version,
+            ext: Vec::new(),
+            profile: Vec::new(),
+        }
     }
 }
 
@@ -29,6 +39,10 @@ pub struct CibouletteBodyBuilder<'request> {
     links: Option<CibouletteBodyLink<'request>>,
     /// The included objects. Cannot be set without `data`
     included: Vec<CibouletteResourceBuilder<'request>>,
+    /// The extension members (`namespace:member`)
+    extensions: BTreeMap<Cow<'request, str>, Value>,
+    /// The members not defined by the specification nor by an extension
+    unknown_members: Vec<Cow<'request, str>>,
 }
 
 /// ## A `json:api` [document](https://jsonapi.org/format/#document-top-level) object
@@ -57,6 +71,15 @@ pub struct CibouletteBody<'request, I, B> {
     /// The included objects. Cannot be set without `data`
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub included: Vec<CibouletteResource<'request, B, I>>,
+    /// The extension members (`namespace:member`)
+    #[serde(flatten)]
+    pub extensions: BTreeMap<Cow<'request, str>, Value>,
+    /// The typed values of the members of the registered extensions
+    #[serde(skip_serializing)]
+    pub extension_data: CibouletteExtensionData,
+    /// The unknown members dropped while building the document, as JSON pointers
+    #[serde(skip_serializing)]
+    pub dropped_members: Vec<String>,
 }
 
 impl<'request, I, B> Default for CibouletteBody<'request, I, B>
@@ -71,6 +94,9 @@ where
             links: Option::default(),
             included: Vec::default(),
             jsonapi: Option::default(),
+            extensions: BTreeMap::default(),
+            extension_data: CibouletteExtensionData::default(),
+            dropped_members: Vec::default(),
         }
     }
 }
@@ -83,19 +109,38 @@ const CIBOULETTE_TOP_LEVEL_FIELDS: &[&str] =
 #[derive(Clone, Debug)]
 struct CibouletteBodyBuilderVisitor;
 
-enum CibouletteBodyField {
+enum CibouletteBodyField<'de> {
     Data,
     Errors,
     Meta,
     Links,
     Included,
     Jsonapi,
+    AtMember,
+    Extension(Cow<'de, str>),
+    Unknown(Cow<'de, str>),
     Ignore,
 }
 
+impl<'de> CibouletteBodyField<'de> {
+    /// Classify the members that are not defined by the specification
+    #[inline]
+    fn other(value: Cow<'de, str>) -> Self {
+        match CibouletteMemberKind::new(value.as_ref()) {
+            CibouletteMemberKind::At(_) => CibouletteBodyField::AtMember,
+            CibouletteMemberKind::Extension(_, _)
+                if check_member_name_version(value.as_ref(), CibouletteSpecVersion::V1_1) =>
+            {
+                CibouletteBodyField::Extension(value)
+            }
+            _ => CibouletteBodyField::Unknown(value),
+        }
+    }
+}
+
 struct CibouletteBodyFieldVisitor;
 impl<'de> Visitor<'de> for CibouletteBodyFieldVisitor {
-    type Value = CibouletteBodyField;
+    type Value = CibouletteBodyField<'de>;
 
     #[inline]
     fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
@@ -115,7 +160,23 @@ impl<'de> Visitor<'de> for CibouletteBodyFieldVisitor {
             "links" => Ok(CibouletteBodyField::Links),
             "included" => Ok(CibouletteBodyField::Included),
             "jsonapi" => Ok(CibouletteBodyField::Jsonapi),
-            _ => Ok(CibouletteBodyField::Ignore),
+            _ => Ok(CibouletteBodyField::other(Cow::Owned(value.to_string()))),
+        }
+    }
+
+    #[inline]
+    fn visit_borrowed_str<E>(self, value: &'de str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        match self.visit_str(value)? {
+            CibouletteBodyField::Extension(_) => {
+                Ok(CibouletteBodyField::Extension(Cow::Borrowed(value)))
+            }
+            CibouletteBodyField::Unknown(_) => {
+                Ok(CibouletteBodyField::Unknown(Cow::Borrowed(value)))
+            }
+            x => Ok(x),
         }
     }
 
@@ -132,11 +193,14 @@ impl<'de> Visitor<'de> for CibouletteBodyFieldVisitor {
             b"links" => Ok(CibouletteBodyField::Links),
             b"included" => Ok(CibouletteBodyField::Included),
             b"jsonapi" => Ok(CibouletteBodyField::Jsonapi),
-            _ => Ok(CibouletteBodyField::Ignore),
+            _ => match std::str::from_utf8(value) {
+                Ok(value) => Ok(CibouletteBodyField::other(Cow::Owned(value.to_string()))),
+                Err(_) => Ok(CibouletteBodyField::Ignore),
+            },
         }
     }
 }
-impl<'de> serde::Deserialize<'de> for CibouletteBodyField {
+impl<'de> serde::Deserialize<'de> for CibouletteBodyField<'de> {
     #[inline]
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -164,6 +228,8 @@ impl<'de> serde::de::Visitor<'de> for CibouletteBodyBuilderVisitor {
         let mut links: Option<CibouletteBodyLink<'de>> = None;
         let mut included: Option<CibouletteResourceSelectorBuilder<'de>> = None;
         let mut jsonapi: Option<CibouletteJsonApiVersion<'de>> = None;
+        let mut extensions: BTreeMap<Cow<'de, str>, Value> = BTreeMap::new();
+        let mut unknown_members: Vec<Cow<'de, str>> = Vec::new();
 
         while let Some(key) = match serde::de::MapAccess::next_key::<CibouletteBodyField>(&mut map)
         {
@@ -197,7 +263,19 @@ impl<'de> serde::de::Visitor<'de> for CibouletteBodyBuilderVisitor {
                     &mut map,
                     "jsonapi",
                 )?,
-                _ => {
+                CibouletteBodyField::Extension(name) => {
+                    crate::serde_utils::handle_extension_member_in_map(
+                        &mut extensions,
+                        &mut map,
+                        name,
+                    )?
+                }
+                CibouletteBodyField::Unknown(name) => {
+                    serde::de::MapAccess::next_value::<serde::de::IgnoredAny>(&mut map)?;
+                    unknown_members.push(name);
+                }
+                // `@`-members are ignored, as required by the specification
+                CibouletteBodyField::AtMember | CibouletteBodyField::Ignore => {
                     let _ =
                         match serde::de::MapAccess::next_value::<serde::de::IgnoredAny>(&mut map) {
                             Ok(val) => val,
@@ -231,6 +309,8 @@ impl<'de> serde::de::Visitor<'de> for CibouletteBodyBuilderVisitor {
             links,
             included,
             jsonapi,
+            extensions,
+            unknown_members,
         })
     }
 }
@@ -250,6 +330,22 @@ impl<'de> Deserialize<'de> for CibouletteBodyBuilder<'de> {
 }
 
 impl<'request> CibouletteBodyBuilder<'request> {
+    /// Parse a `JSON` document.
+    ///
+    /// The deserialization failures are located in the document by a JSON pointer
+    pub fn parse(body: &'request str) -> Result<Self, CibouletteError> {
+        let mut deserializer = serde_json::Deserializer::from_str(body);
+        let res = serde_path_to_error::deserialize(&mut deserializer).map_err(|err| {
+            let pointer = path_to_pointer(err.path());
+            match pointer.is_empty() {
+                true => CibouletteError::SerdeJson(err.into_inner()),
+                false => CibouletteError::InvalidMember(pointer, err.into_inner().to_string()),
+            }
+        })?;
+        deserializer.end()?;
+        Ok(res)
+    }
+
     /// Check that every objects in `data` is unique by `type` and `id`
     ///
     /// Shouldn't be called if creating an
@@ -481,6 +577,23 @@ impl<'request> CibouletteBodyBuilder<'request> {
             MessyJsonObjectValue<'request>,
         >,
         CibouletteError,
+    > {
+        self.build_with_extensions(bag, intention, &[])
+    }
+
+    /// Build the body, accepting the members of the negotiated extensions
+    pub fn build_with_extensions(
+        self,
+        bag: &CibouletteStore,
+        intention: &CibouletteIntention,
+        extensions: &[Arc<dyn CibouletteExtension>],
+    ) -> Result<
+        CibouletteBody<
+            'request,
+            CibouletteResourceIdentifierPermissive<'request>,
+            MessyJsonObjectValue<'request>,
+        >,
+        CibouletteError,
     > {
         let res: CibouletteBody<
             'request,
@@ -488,7 +601,15 @@ impl<'request> CibouletteBodyBuilder<'request> {
             MessyJsonObjectValue<'request>,
         >;
 
-        let data = self.data.build(&bag, &intention)?;
+        let mut dropped_members: Vec<String> = Vec::new();
+        let unknown_members_policy = bag.config().unknown_members_policy();
+        for name in self.unknown_members.iter() {
+            unknown_members_policy.handle(
+                format!("/{}", escape_pointer_token(name)),
+                &mut dropped_members,
+            )?;
+        }
+        let data = self.data.build_at(&bag, &intention, extensions, "/data")?;
         let mut included: Vec<
             CibouletteResource<
                 'request,
@@ -496,10 +617,29 @@ impl<'request> CibouletteBodyBuilder<'request> {
                 CibouletteResourceIdentifierPermissive,
             >,
         > = Vec::with_capacity(self.included.len());
-        for i in self.included.into_iter() {
-            included.push(i.build(&bag, &intention)?);
+        for (index, i) in self.included.into_iter().enumerate() {
+            included.push(i.build_at(
+                &bag,
+                &intention,
+                extensions,
+                &format!("/included/{}", index),
+            )?);
         }
         Self::check(&intention, &data, &included, &self.errors)?;
+        if let CibouletteBodyData::Object(data) = &data {
+            for resource in data.iter() {
+                dropped_members.extend(resource.dropped_members().iter().cloned());
+            }
+        }
+        for resource in included.iter() {
+            dropped_members.extend(resource.dropped_members().iter().cloned());
+        }
+        let extension_data = CibouletteExtensionData::parse(
+            bag,
+            extensions,
+            CibouletteExtensionLevel::Document,
+            &self.extensions,
+        )?;
         res = CibouletteBody {
             data,
             errors: self.errors,
@@ -507,6 +647,9 @@ impl<'request> CibouletteBodyBuilder<'request> {
             links: self.links,
             jsonapi: self.jsonapi,
             included,
+            extensions: self.extensions,
+            extension_data,
+            dropped_members,
         };
         Ok(res)
     }
@@ -537,7 +680,7 @@ impl<'request, B> CibouletteBody<'request, CibouletteResourceIdentifierPermissiv
         match self.data() {
             CibouletteBodyData::Object(data) => match &**data {
                 CibouletteSelector::Single(x) => {
-                    bag.get_type_if_exists(x.identifier().type_().as_ref())
+                    bag.get_public_type_if_exists(x.identifier().type_().as_ref())
                 }
                 CibouletteSelector::Multi(types) => {
                     let mut titer = types.iter();
@@ -550,7 +693,7 @@ impl<'request, B> CibouletteBody<'request, CibouletteResourceIdentifierPermissiv
                             return None;
                         }
                     }
-                    bag.get_type_if_exists(first_type.as_ref())
+                    bag.get_public_type_if_exists(first_type.as_ref())
                 }
             },
             CibouletteBodyData::Null(_) => None,
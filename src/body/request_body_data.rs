@@ -26,11 +26,45 @@ impl<'request> CibouletteBodyDataBuilder<'request> {
             MessyJsonObjectValue<'request>,
         >,
         CibouletteError,
+    > {
+        self.build_with_extensions(bag, intention, &[])
+    }
+
+    pub fn build_with_extensions(
+        self,
+        bag: &CibouletteStore,
+        intention: &CibouletteIntention,
+        extensions: &[Arc<dyn CibouletteExtension>],
+    ) -> Result<
+        CibouletteBodyData<
+            'request,
+            CibouletteResourceIdentifierPermissive<'request>,
+            MessyJsonObjectValue<'request>,
+        >,
+        CibouletteError,
+    > {
+        self.build_at(bag, intention, extensions, "")
+    }
+
+    /// Build the data, located in the document by a JSON pointer
+    pub(crate) fn build_at(
+        self,
+        bag: &CibouletteStore,
+        intention: &CibouletteIntention,
+        extensions: &[Arc<dyn CibouletteExtension>],
+        pointer: &str,
+    ) -> Result<
+        CibouletteBodyData<
+            'request,
+            CibouletteResourceIdentifierPermissive<'request>,
+            MessyJsonObjectValue<'request>,
+        >,
+        CibouletteError,
     > {
         match self {
-            CibouletteBodyDataBuilder::Object(x) => {
-                Ok(CibouletteBodyData::Object(x.build(bag, intention)?))
-            }
+            CibouletteBodyDataBuilder::Object(x) => Ok(CibouletteBodyData::Object(
+                x.build_at(bag, intention, extensions, pointer)?,
+            )),
             CibouletteBodyDataBuilder::Null(x) => Ok(CibouletteBodyData::Null(x)),
         }
     }
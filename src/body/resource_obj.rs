@@ -17,6 +17,11 @@ pub struct CibouletteResourceBuilder<'request> {
     links: Option<CibouletteLink<'request>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     meta: Option<Value>,
+    #[serde(flatten)]
+    extensions: BTreeMap<Cow<'request, str>, Value>,
+    /// The members not defined by the specification nor by an extension
+    #[serde(skip_serializing)]
+    unknown_members: Vec<Cow<'request, str>>,
 }
 
 /// ## A `json:api` [resource](https://jsonapi.org/format/#document-resource-objects) object
@@ -35,6 +40,18 @@ pub struct CibouletteResource<'request, B, T> {
     pub type_: Arc<CibouletteResourceType>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub meta: Option<Value>,
+    /// The extension members (`namespace:member`)
+    #[serde(flatten)]
+    pub extensions: BTreeMap<Cow<'request, str>, Value>,
+    /// The typed values of the members of the registered extensions
+    #[serde(skip_serializing)]
+    pub extension_data: CibouletteExtensionData,
+    /// The unknown members dropped while building the resource, as JSON pointers
+    #[serde(skip_serializing)]
+    pub dropped_members: Vec<String>,
+    /// The JSON pointer locating the resource in the request body
+    #[serde(skip_serializing)]
+    pub pointer: String,
 }
 
 impl<'request, 'store, B>
@@ -53,6 +70,10 @@ impl<'request, 'store, B>
             links,
             type_,
             meta,
+            extensions,
+            extension_data,
+            dropped_members,
+            pointer,
         } = value;
 
         Ok(
@@ -63,6 +84,10 @@ impl<'request, 'store, B>
                 links,
                 type_,
                 meta,
+                extensions,
+                extension_data,
+                dropped_members,
+                pointer,
             },
         )
     }
@@ -82,6 +107,10 @@ impl<'request, 'store, B>
             links,
             type_,
             meta,
+            extensions,
+            extension_data,
+            dropped_members,
+            pointer,
         } = value;
 
         CibouletteResource::<'request, B, CibouletteResourceIdentifierPermissive<'request>> {
@@ -91,6 +120,10 @@ impl<'request, 'store, B>
             links,
             type_,
             meta,
+            extensions,
+            extension_data,
+            dropped_members,
+            pointer,
         }
     }
 }
@@ -108,19 +141,38 @@ impl<'de> Deserialize<'de> for CibouletteResourceBuilder<'de> {
 #[derive(Clone, Copy, Debug)]
 pub struct CibouletteResourceBuilderVisitor;
 
-enum CibouletteResourceField {
+enum CibouletteResourceField<'de> {
     Id,
     Type,
     Meta,
     Attributes,
     Relationships,
     Links,
+    AtMember,
+    Extension(Cow<'de, str>),
+    Unknown(Cow<'de, str>),
     Ignore,
 }
 
+impl<'de> CibouletteResourceField<'de> {
+    /// Classify the members that are not defined by the specification
+    #[inline]
+    fn other(value: Cow<'de, str>) -> Self {
+        match CibouletteMemberKind::new(value.as_ref()) {
+            CibouletteMemberKind::At(_) => CibouletteResourceField::AtMember,
+            CibouletteMemberKind::Extension(_, _)
+                if check_member_name_version(value.as_ref(), CibouletteSpecVersion::V1_1) =>
+            {
+                CibouletteResourceField::Extension(value)
+            }
+            _ => CibouletteResourceField::Unknown(value),
+        }
+    }
+}
+
 struct CibouletteResourceFieldVisitor;
 impl<'de> Visitor<'de> for CibouletteResourceFieldVisitor {
-    type Value = CibouletteResourceField;
+    type Value = CibouletteResourceField<'de>;
 
     #[inline]
     fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
@@ -139,7 +191,25 @@ impl<'de> Visitor<'de> for CibouletteResourceFieldVisitor {
             "attributes" => Ok(CibouletteResourceField::Attributes),
             "relationships" => Ok(CibouletteResourceField::Relationships),
             "links" => Ok(CibouletteResourceField::Links),
-            _ => Ok(CibouletteResourceField::Ignore),
+            _ => Ok(CibouletteResourceField::other(Cow::Owned(
+                value.to_string(),
+            ))),
+        }
+    }
+
+    #[inline]
+    fn visit_borrowed_str<E>(self, value: &'de str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        match self.visit_str(value)? {
+            CibouletteResourceField::Extension(_) => {
+                Ok(CibouletteResourceField::Extension(Cow::Borrowed(value)))
+            }
+            CibouletteResourceField::Unknown(_) => {
+                Ok(CibouletteResourceField::Unknown(Cow::Borrowed(value)))
+            }
+            x => Ok(x),
         }
     }
 
@@ -155,12 +225,17 @@ impl<'de> Visitor<'de> for CibouletteResourceFieldVisitor {
             b"attributes" => Ok(CibouletteResourceField::Attributes),
             b"relationships" => Ok(CibouletteResourceField::Relationships),
             b"links" => Ok(CibouletteResourceField::Links),
-            _ => Ok(CibouletteResourceField::Ignore),
+            _ => match std::str::from_utf8(value) {
+                Ok(value) => Ok(CibouletteResourceField::other(Cow::Owned(
+                    value.to_string(),
+                ))),
+                Err(_) => Ok(CibouletteResourceField::Ignore),
+            },
         }
     }
 }
 
-impl<'de> serde::Deserialize<'de> for CibouletteResourceField {
+impl<'de> serde::Deserialize<'de> for CibouletteResourceField<'de> {
     #[inline]
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -186,12 +261,14 @@ impl<'de> serde::de::Visitor<'de> for CibouletteResourceBuilderVisitor {
         let mut id: Option<Cow<'de, str>> = None;
         let mut type_: Option<Cow<'de, str>> = None;
         let mut meta: Option<Value> = None;
-        let mut attributes: CibouletteOptionalData<MessyJsonValueRaw<'de>> =
+        let mut attributes: CibouletteOptionalData<CibouletteAttributesRaw<'de>> =
             CibouletteOptionalData::Null(false);
         let mut relationships: Option<
             BTreeMap<Cow<'de, str>, CibouletteRelationshipObjectBuilder<'de>>,
         > = None;
         let mut links: Option<CibouletteLink<'de>> = None;
+        let mut extensions: BTreeMap<Cow<'de, str>, Value> = BTreeMap::new();
+        let mut unknown_members: Vec<Cow<'de, str>> = Vec::new();
         while let Some(key) =
             match serde::de::MapAccess::next_key::<CibouletteResourceField>(&mut map) {
                 Ok(val) => val,
@@ -229,7 +306,19 @@ impl<'de> serde::de::Visitor<'de> for CibouletteResourceBuilderVisitor {
                         &mut links, &mut map, "links",
                     )?
                 }
-                _ => {
+                CibouletteResourceField::Extension(name) => {
+                    crate::serde_utils::handle_extension_member_in_map(
+                        &mut extensions,
+                        &mut map,
+                        name,
+                    )?
+                }
+                CibouletteResourceField::Unknown(name) => {
+                    serde::de::MapAccess::next_value::<serde::de::IgnoredAny>(&mut map)?;
+                    unknown_members.push(name);
+                }
+                // `@`-members are ignored, as required by the specification
+                CibouletteResourceField::AtMember | CibouletteResourceField::Ignore => {
                     let _ =
                         match serde::de::MapAccess::next_value::<serde::de::IgnoredAny>(&mut map) {
                             Ok(val) => val,
@@ -244,16 +333,91 @@ impl<'de> serde::de::Visitor<'de> for CibouletteResourceBuilderVisitor {
         let id = id;
         let type_ = type_.ok_or_else(|| <A::Error as serde::de::Error>::missing_field("type"))?;
         let relationships = relationships.unwrap_or_default();
+        let attributes = match attributes {
+            CibouletteOptionalData::Object(CibouletteAttributesRaw(attributes)) => {
+                CibouletteOptionalData::Object(attributes)
+            }
+            CibouletteOptionalData::Null(x) => CibouletteOptionalData::Null(x),
+        };
         Ok(CibouletteResourceBuilder {
             identifier: CibouletteResourceIdentifierBuilder::new(id, type_),
             attributes,
             relationships,
             links,
             meta,
+            extensions,
+            unknown_members,
         })
     }
 }
 
+impl<'request, T> CibouletteResource<'request, MessyJsonObjectValue<'request>, T> {
+    /// Get the state of an attribute sent by the client, by its schema name
+    pub fn attribute(&self, name: &str) -> CibouletteAttributeValue<'_, 'request> {
+        CibouletteAttributeValue::from(self.attributes.as_ref().and_then(|x| x.get(name)))
+    }
+
+    /// Check that every mandatory attribute the client can set was sent, as for a creation
+    pub(crate) fn check_mandatory_attributes(&self) -> Result<(), CibouletteError> {
+        for name in self.type_.mandatory_attributes().iter() {
+            if !self
+                .type_
+                .attribute_policy(name.as_str())
+                .is_writable(&CibouletteIntention::Create)
+            {
+                continue;
+            }
+            if !matches!(
+                self.attribute(name.as_str()),
+                CibouletteAttributeValue::Set(_)
+            ) {
+                return Err(CibouletteError::MissingAttribute(
+                    self.type_.name().to_string(),
+                    self.type_.member_to_wire(name.as_str()).to_string(),
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Raw `attributes` object, without its `@`-members
+struct CibouletteAttributesRaw<'de>(MessyJsonValueRaw<'de>);
+
+struct CibouletteAttributesRawVisitor;
+
+impl<'de> Visitor<'de> for CibouletteAttributesRawVisitor {
+    type Value = CibouletteAttributesRaw<'de>;
+
+    #[inline]
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        Formatter::write_str(formatter, "an attributes object")
+    }
+
+    #[inline]
+    fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::MapAccess<'de>,
+    {
+        Ok(CibouletteAttributesRaw(
+            MessyJsonValueRawVisitor::visit_map(
+                MessyJsonValueRawVisitor::default(),
+                crate::serde_utils::CibouletteAtMemberFilter::new(map),
+            )?,
+        ))
+    }
+}
+
+impl<'de> Deserialize<'de> for CibouletteAttributesRaw<'de> {
+    #[inline]
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(CibouletteAttributesRawVisitor)
+    }
+}
+
 impl<'de> DeserializeSeed<'de> for CibouletteResourceBuilderVisitor {
     type Value = CibouletteResourceBuilder<'de>;
 
@@ -283,21 +447,102 @@ impl<'request> CibouletteResourceBuilder<'request> {
             CibouletteResourceIdentifierPermissive<'request>,
         >,
         CibouletteError,
+    > {
+        self.build_with_extensions(bag, intention, &[])
+    }
+
+    /// ## build the [CibouletteResource](CibouletteResource) from the builder, accepting the members of the negotiated extensions
+    pub fn build_with_extensions<'store>(
+        self,
+        bag: &'store CibouletteStore,
+        intention: &CibouletteIntention,
+        extensions: &[Arc<dyn CibouletteExtension>],
+    ) -> Result<
+        CibouletteResource<
+            'request,
+            MessyJsonObjectValue<'request>,
+            CibouletteResourceIdentifierPermissive<'request>,
+        >,
+        CibouletteError,
+    > {
+        self.build_at(bag, intention, extensions, "")
+    }
+
+    /// Build the resource, located in the document by a JSON pointer
+    pub(crate) fn build_at<'store>(
+        self,
+        bag: &'store CibouletteStore,
+        intention: &CibouletteIntention,
+        extensions: &[Arc<dyn CibouletteExtension>],
+        pointer: &str,
+    ) -> Result<
+        CibouletteResource<
+            'request,
+            MessyJsonObjectValue<'request>,
+            CibouletteResourceIdentifierPermissive<'request>,
+        >,
+        CibouletteError,
     > {
         let current_type: &Arc<CibouletteResourceType> =
-            bag.get_type(self.identifier().type_().as_ref())?;
-        let resource_type: Arc<CibouletteResourceType> =
-            bag.get_type(self.identifier().type_().as_ref())?.clone();
+            bag.get_public_type(self.identifier().type_().as_ref())?;
+        let resource_type: Arc<CibouletteResourceType> = current_type.clone();
+        let unknown_members_policy = resource_type
+            .unknown_members_policy()
+            .unwrap_or_else(|| bag.config().unknown_members_policy());
+        let mut dropped_members: Vec<String> = Vec::new();
+        for name in self.unknown_members.iter() {
+            unknown_members_policy.handle(
+                format!("{}/{}", pointer, escape_pointer_token(name)),
+                &mut dropped_members,
+            )?;
+        }
         let attributes: Option<MessyJsonObjectValue<'request>> = match self.attributes {
-            CibouletteOptionalData::Object(attributes) => {
-                let deserializer_settings = matches!(intention, CibouletteIntention::Update);
-                let container_builder = resource_type.schema().builder(MessyJsonSettings {
+            CibouletteOptionalData::Object(mut attributes) => {
+                // The completeness of the created resources is checked by the create requests
+                let deserializer_settings = matches!(
+                    intention,
+                    CibouletteIntention::Create | CibouletteIntention::Update
+                );
+                let schema = resource_type
+                    .wire_schema()
+                    .as_ref()
+                    .unwrap_or_else(|| resource_type.schema());
+                if let MessyJsonValueRaw::Obj(obj) = &mut attributes {
+                    let unknown_attributes: Vec<String> = obj
+                        .keys()
+                        .filter(|name| !schema.properties().contains_key(name.as_ref()))
+                        .map(|name| name.to_string())
+                        .collect();
+                    for name in unknown_attributes {
+                        unknown_members_policy.handle(
+                            format!("{}/attributes/{}", pointer, escape_pointer_token(&name)),
+                            &mut dropped_members,
+                        )?;
+                        obj.remove(name.as_str());
+                    }
+                }
+                let container_builder = schema.builder(MessyJsonSettings {
                     all_optional: deserializer_settings,
                     preserve_mandatory: deserializer_settings,
                 });
-                let container = container_builder.deserialize(attributes)?;
+                let track = serde_path_to_error::Track::new();
+                let container = container_builder
+                    .deserialize(serde_path_to_error::Deserializer::new(attributes, &track))
+                    .map_err(|err| {
+                        CibouletteError::InvalidMember(
+                            format!("{}/attributes{}", pointer, path_to_pointer(&track.path())),
+                            err.to_string(),
+                        )
+                    })?;
                 match container.take() {
-                    MessyJsonValue::Obj(obj) => Some(obj),
+                    MessyJsonValue::Obj(obj) => {
+                        let obj = resource_type.check_attributes_policy(
+                            intention,
+                            resource_type.attributes_from_wire(obj),
+                        )?;
+                        resource_type.check_constraints(&obj)?;
+                        Some(obj)
+                    }
                     _ => return Err(CibouletteError::AttributesIsNotAnObject),
                 }
             }
@@ -306,10 +551,18 @@ impl<'request> CibouletteResourceBuilder<'request> {
         let mut relationships: BTreeMap<ArcStr, CibouletteRelationshipObject<'request>> =
             BTreeMap::new();
         for (k, v) in self.relationships {
-            let (rel_alias, rel_type) =
-                current_type.get_relationship_with_alias(&bag, k.as_ref())?;
+            let alias = current_type.member_from_wire(k.as_ref()).ok_or_else(|| {
+                CibouletteError::UnknownRelationship(current_type.name().to_string(), k.to_string())
+            })?;
+            let (rel_alias, rel_type) = current_type.get_relationship_with_alias(&bag, alias)?;
             relationships.insert(rel_alias, v.build(&rel_type)?);
         }
+        let extension_data = CibouletteExtensionData::parse(
+            bag,
+            extensions,
+            CibouletteExtensionLevel::Resource,
+            &self.extensions,
+        )?;
         Ok(CibouletteResource {
             identifier: self.identifier.build_permissive(&current_type)?,
             attributes,
@@ -317,6 +570,10 @@ impl<'request> CibouletteResourceBuilder<'request> {
             relationships,
             type_: current_type.clone(),
             meta: self.meta,
+            extensions: self.extensions,
+            extension_data,
+            dropped_members,
+            pointer: pointer.to_string(),
         })
     }
 }
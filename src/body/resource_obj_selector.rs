@@ -92,10 +92,45 @@ impl<'request> CibouletteResourceSelectorBuilder<'request> {
             CibouletteResourceIdentifierPermissive<'request>,
         >,
         CibouletteError,
+    > {
+        self.build_with_extensions(bag, intention, &[])
+    }
+
+    /// Build the resource selector from the builder, accepting the members of the negotiated extensions
+    pub fn build_with_extensions(
+        self,
+        bag: &CibouletteStore,
+        intention: &CibouletteIntention,
+        extensions: &[Arc<dyn CibouletteExtension>],
+    ) -> Result<
+        CibouletteResourceSelector<
+            'request,
+            MessyJsonObjectValue<'request>,
+            CibouletteResourceIdentifierPermissive<'request>,
+        >,
+        CibouletteError,
+    > {
+        self.build_at(bag, intention, extensions, "")
+    }
+
+    /// Build the resource selector, located in the document by a JSON pointer
+    pub(crate) fn build_at(
+        self,
+        bag: &CibouletteStore,
+        intention: &CibouletteIntention,
+        extensions: &[Arc<dyn CibouletteExtension>],
+        pointer: &str,
+    ) -> Result<
+        CibouletteResourceSelector<
+            'request,
+            MessyJsonObjectValue<'request>,
+            CibouletteResourceIdentifierPermissive<'request>,
+        >,
+        CibouletteError,
     > {
         match self.take() {
             CibouletteSelector::Single(element) => Ok(CibouletteResourceSelector::new(
-                CibouletteSelector::Single(element.build(bag, &intention)?),
+                CibouletteSelector::Single(element.build_at(bag, &intention, extensions, pointer)?),
             )),
             CibouletteSelector::Multi(elements) => {
                 let mut res: Vec<
@@ -105,8 +140,13 @@ impl<'request> CibouletteResourceSelectorBuilder<'request> {
                     >,
                 > = Vec::with_capacity(elements.len());
 
-                for el in elements.into_iter() {
-                    res.push(el.build(bag, &intention)?);
+                for (i, el) in elements.into_iter().enumerate() {
+                    res.push(el.build_at(
+                        bag,
+                        &intention,
+                        extensions,
+                        &format!("{}/{}", pointer, i),
+                    )?);
                 }
                 Ok(CibouletteResourceSelector::new(CibouletteSelector::Multi(
                     res,
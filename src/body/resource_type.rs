@@ -1,4 +1,5 @@
 use super::*;
+use std::ops::Deref;
 
 /// ## Describe a `json:api` type attribute schema and list its relationships
 #[allow(clippy::derive_hash_xor_eq)]
@@ -6,10 +7,41 @@ use super::*;
 #[getset(get = "pub", get_mut = "pub")]
 pub struct CibouletteResourceType {
     relationships: BTreeMap<ArcStr, petgraph::graph::EdgeIndex<u16>>,
-    relationships_type_to_alias: BTreeMap<ArcStr, ArcStr>,
+    /// The aliases of the relationships, by related type name
+    relationships_type_to_alias: BTreeMap<ArcStr, BTreeSet<ArcStr>>,
     schema: MessyJsonObject,
     ids: CibouletteIdTypeSelector,
     name: ArcStr,
+    /// The name used in requests and responses, defaults to `name`
+    public_name: ArcStr,
+    /// Other public names accepted in requests
+    public_aliases: BTreeSet<ArcStr>,
+    /// The member names exchanged with the clients mapped to their schema names,
+    /// when they differ
+    wire_members: BTreeMap<ArcStr, ArcStr>,
+    /// The schema member names mapped to the names exchanged with the clients,
+    /// when they differ
+    schema_members: BTreeMap<ArcStr, ArcStr>,
+    /// The schema using the member names exchanged with the clients,
+    /// if any attribute name differs, nested ones included
+    wire_schema: Option<MessyJsonObject>,
+    /// The naming policy converting the member names of the nested attributes
+    naming_policy: CibouletteNamingPolicy,
+    /// The custom query parameters accepted for this type, mapped by their name
+    query_parameters: BTreeMap<ArcStr, CibouletteCustomQueryParameter>,
+    /// The access policies of the attributes, when not read-write
+    attribute_policies: BTreeMap<ArcStr, CibouletteAttributePolicy>,
+    /// The attributes that must be sent when creating a resource, by schema name
+    mandatory_attributes: BTreeSet<ArcStr>,
+    /// The constraints on the values of the attributes, by schema name
+    constraints: BTreeMap<ArcStr, Vec<CibouletteAttributeConstraint>>,
+    /// The operations allowed on the resources of this type
+    capabilities: CibouletteTypeCapabilities,
+    /// The operations allowed on the relationships, by alias, when restricted
+    relationships_capabilities: BTreeMap<ArcStr, CibouletteRelationshipCapabilities>,
+    /// The policy applied to the unknown members of the resources of this type,
+    /// overriding the one of the store
+    unknown_members_policy: Option<CibouletteUnknownMembersPolicy>,
 }
 
 /// ## Relationships metadata for [CibouletteResourceType](CibouletteResourceType)
@@ -33,12 +65,375 @@ impl CibouletteResourceType {
             relationships_type_to_alias: BTreeMap::new(),
             schema,
             ids,
+            public_name: name.clone(),
+            public_aliases: BTreeSet::new(),
+            wire_members: BTreeMap::new(),
+            schema_members: BTreeMap::new(),
+            wire_schema: None,
+            naming_policy: CibouletteNamingPolicy::default(),
+            query_parameters: BTreeMap::new(),
+            attribute_policies: BTreeMap::new(),
+            mandatory_attributes: BTreeSet::new(),
+            constraints: BTreeMap::new(),
+            capabilities: CibouletteTypeCapabilities::default(),
+            relationships_capabilities: BTreeMap::new(),
+            unknown_members_policy: None,
             name,
         }
     }
 
-    /// Get a the alias of a type related to this type
+    /// Compute the member names exchanged with the clients, following a naming policy.
+    ///
+    /// The policy also applies to the members of the nested objects, in arrays or not.
+    /// Every converted name is checked against the `JSON:API` member name rules.
+    pub(crate) fn apply_naming_policy(
+        &mut self,
+        policy: CibouletteNamingPolicy,
+    ) -> Result<(), CibouletteError> {
+        let mut wire_members: BTreeMap<ArcStr, ArcStr> = BTreeMap::new();
+        let mut schema_members: BTreeMap<ArcStr, ArcStr> = BTreeMap::new();
+        let mut wire_properties: BTreeMap<ArcStr, MessyJson> = BTreeMap::new();
+        let mut wire_names: BTreeMap<ArcStr, ArcStr> = BTreeMap::new();
+        let mut renamed_attribute = false;
+
+        let members = self
+            .schema
+            .properties()
+            .keys()
+            .chain(self.relationships.keys());
+        for name in members {
+            let wire_name = ArcStr::from(policy.convert(name.as_str()));
+            if !crate::member_name::check_member_name(wire_name.as_str()) {
+                return Err(CibouletteError::InvalidMemberName(wire_name.to_string()));
+            }
+            match wire_names.insert(wire_name.clone(), name.clone()) {
+                Some(other) if other != *name => {
+                    return Err(CibouletteError::MemberNameClash(
+                        self.name.to_string(),
+                        wire_name.to_string(),
+                    ));
+                }
+                _ => (),
+            }
+            if wire_name != *name {
+                wire_members.insert(wire_name.clone(), name.clone());
+                schema_members.insert(name.clone(), wire_name);
+            }
+        }
+        for (name, value) in self.schema.properties().iter() {
+            let wire_name = match schema_members.get(name) {
+                Some(wire_name) => {
+                    renamed_attribute = true;
+                    wire_name.clone()
+                }
+                None => name.clone(),
+            };
+            wire_properties.insert(
+                wire_name,
+                self.wire_value(policy, value, &mut renamed_attribute)?,
+            );
+        }
+        self.wire_schema = match renamed_attribute {
+            true => Some(MessyJsonObject::from(MessyJsonObjectInner::new(
+                wire_properties,
+                false,
+            ))),
+            false => None,
+        };
+        self.wire_members = wire_members;
+        self.schema_members = schema_members;
+        self.naming_policy = policy;
+        Ok(())
+    }
+
+    /// Build the schema of an attribute using the member names exchanged with the clients.
+    ///
+    /// `renamed` is set if a nested member name differs from its schema name
+    fn wire_value(
+        &self,
+        policy: CibouletteNamingPolicy,
+        value: &MessyJson,
+        renamed: &mut bool,
+    ) -> Result<MessyJson, CibouletteError> {
+        Ok(match value.deref() {
+            MessyJsonInner::Obj(obj) => {
+                let mut properties: BTreeMap<ArcStr, MessyJson> = BTreeMap::new();
+                for (name, value) in obj.properties().iter() {
+                    let wire_name = ArcStr::from(policy.convert(name.as_str()));
+                    if !crate::member_name::check_member_name(wire_name.as_str()) {
+                        return Err(CibouletteError::InvalidMemberName(wire_name.to_string()));
+                    }
+                    if wire_name != *name {
+                        *renamed = true;
+                    }
+                    let wire_value = self.wire_value(policy, value, renamed)?;
+                    if properties.insert(wire_name.clone(), wire_value).is_some() {
+                        return Err(CibouletteError::MemberNameClash(
+                            self.name.to_string(),
+                            wire_name.to_string(),
+                        ));
+                    }
+                }
+                MessyJson::from(MessyJsonInner::Obj(MessyJsonObject::from(
+                    MessyJsonObjectInner::new(properties, obj.optional()),
+                )))
+            }
+            MessyJsonInner::Array(arr) => MessyJson::from(MessyJsonInner::Array(
+                MessyJsonArray::from(MessyJsonArrayInner::new(
+                    self.wire_value(policy, arr.items(), renamed)?,
+                    arr.optional(),
+                )),
+            )),
+            _ => value.clone(),
+        })
+    }
+
+    /// Translate a member name received from a client to its schema name.
+    ///
+    /// Returns `None` if the name is a schema name that the clients know under another name
+    pub fn member_from_wire<'a>(&'a self, name: &'a str) -> Option<&'a str> {
+        match self.wire_members.get(name) {
+            Some(x) => Some(x.as_str()),
+            None => match self.schema_members.contains_key(name) {
+                true => None,
+                false => Some(name),
+            },
+        }
+    }
+
+    /// Translate a schema member name to the name exchanged with the clients
+    pub fn member_to_wire<'a>(&'a self, name: &'a str) -> &'a str {
+        self.schema_members
+            .get(name)
+            .map(ArcStr::as_str)
+            .unwrap_or(name)
+    }
+
+    /// Rename the attributes received from a client to their schema names
+    pub(crate) fn attributes_from_wire<'request>(
+        &self,
+        attributes: MessyJsonObjectValue<'request>,
+    ) -> MessyJsonObjectValue<'request> {
+        if self.wire_schema.is_none() {
+            return attributes;
+        }
+        MessyJsonObjectValue::new(
+            attributes
+                .take()
+                .into_iter()
+                .map(|(k, v)| {
+                    let name = match self.wire_members.get(k.as_str()) {
+                        Some(schema_name) => schema_name.clone(),
+                        None => k,
+                    };
+                    match self.schema.properties().get(&name) {
+                        Some(schema) => {
+                            let value = self.value_from_wire(schema, v);
+                            (name, value)
+                        }
+                        None => (name, v),
+                    }
+                })
+                .collect(),
+        )
+    }
+
+    /// Rename the members of a nested attribute received from a client to their schema names
+    fn value_from_wire<'request>(
+        &self,
+        schema: &MessyJson,
+        value: MessyJsonValue<'request>,
+    ) -> MessyJsonValue<'request> {
+        match (schema.deref(), value) {
+            (MessyJsonInner::Obj(obj), MessyJsonValue::Obj(value)) => {
+                let schema_names: BTreeMap<ArcStr, (&ArcStr, &MessyJson)> = obj
+                    .properties()
+                    .iter()
+                    .map(|(k, v)| (ArcStr::from(self.naming_policy.convert(k.as_str())), (k, v)))
+                    .collect();
+                MessyJsonValue::Obj(MessyJsonObjectValue::new(
+                    value
+                        .take()
+                        .into_iter()
+                        .map(|(k, v)| match schema_names.get(&k) {
+                            Some((name, schema)) => {
+                                ((*name).clone(), self.value_from_wire(schema, v))
+                            }
+                            None => (k, v),
+                        })
+                        .collect(),
+                ))
+            }
+            (MessyJsonInner::Array(arr), MessyJsonValue::Array(value)) => MessyJsonValue::Array(
+                value
+                    .take()
+                    .into_iter()
+                    .map(|v| self.value_from_wire(arr.items(), v))
+                    .collect::<Vec<MessyJsonValue<'request>>>()
+                    .into(),
+            ),
+            (_, value) => value,
+        }
+    }
+
+    /// Rename the members of a nested attribute sent to a client to the names exchanged
+    /// with the clients
+    pub(crate) fn value_to_wire(&self, schema: &MessyJson, value: Value) -> Value {
+        match (schema.deref(), value) {
+            (MessyJsonInner::Obj(obj), Value::Object(value)) => Value::Object(
+                value
+                    .into_iter()
+                    .map(|(k, v)| match obj.properties().get(k.as_str()) {
+                        Some(schema) => (
+                            self.naming_policy.convert(k.as_str()).into_owned(),
+                            self.value_to_wire(schema, v),
+                        ),
+                        None => (k, v),
+                    })
+                    .collect(),
+            ),
+            (MessyJsonInner::Array(arr), Value::Array(value)) => Value::Array(
+                value
+                    .into_iter()
+                    .map(|v| self.value_to_wire(arr.items(), v))
+                    .collect(),
+            ),
+            (_, value) => value,
+        }
+    }
+
+    /// Get the access policy of an attribute, by its schema name
+    pub fn attribute_policy(&self, name: &str) -> CibouletteAttributePolicy {
+        self.attribute_policies
+            .get(name)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Check if some attributes are never sent back to the clients
+    pub fn has_write_only_attributes(&self) -> bool {
+        self.attribute_policies.values().any(|x| !x.is_readable())
+    }
+
+    /// Check that the attributes received from a client can be set for a given intention
+    pub(crate) fn check_attributes_policy<'request>(
+        &self,
+        intention: &CibouletteIntention,
+        attributes: MessyJsonObjectValue<'request>,
+    ) -> Result<MessyJsonObjectValue<'request>, CibouletteError> {
+        if self.attribute_policies.is_empty() {
+            return Ok(attributes);
+        }
+        let attributes = attributes.take();
+        for (name, value) in attributes.iter() {
+            // The mandatory attributes not sent by the client are kept as absent placeholders
+            if !CibouletteAttributeValue::from(Some(value)).is_present() {
+                continue;
+            }
+            if !self.attribute_policy(name.as_str()).is_writable(intention) {
+                return Err(CibouletteError::ForbiddenAttribute(
+                    self.name.to_string(),
+                    self.member_to_wire(name.as_str()).to_string(),
+                ));
+            }
+        }
+        Ok(MessyJsonObjectValue::new(attributes))
+    }
+
+    /// Get the operations allowed on a relationship, by alias
+    pub fn relationship_capabilities(&self, alias: &str) -> CibouletteRelationshipCapabilities {
+        self.relationships_capabilities
+            .get(alias)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Check that an intention is allowed on the resources of this type
+    pub(crate) fn check_capability(
+        &self,
+        intention: CibouletteIntention,
+    ) -> Result<(), CibouletteError> {
+        match self.capabilities.allows(&intention) {
+            true => Ok(()),
+            false => Err(CibouletteError::ForbiddenOperation(
+                self.name.to_string(),
+                intention,
+            )),
+        }
+    }
+
+    /// Check that an intention is allowed on a relationship of this type, by alias
+    pub(crate) fn check_relationship_capability(
+        &self,
+        alias: &str,
+        intention: CibouletteIntention,
+    ) -> Result<(), CibouletteError> {
+        match self.relationship_capabilities(alias).allows(&intention) {
+            true => Ok(()),
+            false => Err(CibouletteError::ForbiddenRelationshipOperation(
+                self.name.to_string(),
+                alias.to_string(),
+                intention,
+            )),
+        }
+    }
+
+    /// Check the attributes received from a client against the constraints of this type,
+    /// collecting every violation
+    pub(crate) fn check_constraints(
+        &self,
+        attributes: &MessyJsonObjectValue<'_>,
+    ) -> Result<(), CibouletteError> {
+        let mut violations: Vec<CibouletteConstraintViolation> = Vec::new();
+
+        for (name, constraints) in self.constraints.iter() {
+            let value = match CibouletteAttributeValue::from(attributes.get(name.as_str())) {
+                CibouletteAttributeValue::Set(value) => value,
+                _ => continue,
+            };
+            for constraint in constraints.iter() {
+                if let Err(reason) = constraint.validate(value) {
+                    violations.push(CibouletteConstraintViolation::new(
+                        ArcStr::from(self.member_to_wire(name.as_str())),
+                        reason,
+                    ));
+                }
+            }
+        }
+        match violations.is_empty() {
+            true => Ok(()),
+            false => Err(CibouletteError::ConstraintViolations(
+                self.name.to_string(),
+                violations,
+            )),
+        }
+    }
+
+    /// Check if a name used in a request designate this type
+    pub fn is_public_name(&self, name: &str) -> bool {
+        self.public_name.as_str() == name || self.public_aliases.contains(name)
+    }
+
+    /// Get the alias of the relationship to a related type.
+    ///
+    /// Fails if there is more than one relationship to that type,
+    /// see [get_aliases](CibouletteResourceType::get_aliases).
     pub fn get_alias(&self, name: &str) -> Result<&ArcStr, CibouletteError> {
+        let aliases = self.get_aliases(name)?;
+        match aliases.len() {
+            1 => aliases.iter().next().ok_or_else(|| {
+                CibouletteError::MissingAliasTranslation(self.name().to_string(), name.to_string())
+            }),
+            _ => Err(CibouletteError::AmbiguousAlias(
+                self.name().to_string(),
+                name.to_string(),
+                aliases.iter().map(ArcStr::to_string).collect(),
+            )),
+        }
+    }
+
+    /// Get the aliases of every relationship to a related type, sorted
+    pub fn get_aliases(&self, name: &str) -> Result<&BTreeSet<ArcStr>, CibouletteError> {
         self.relationships_type_to_alias().get(name).ok_or_else(|| {
             CibouletteError::MissingAliasTranslation(self.name().to_string(), name.to_string())
         })
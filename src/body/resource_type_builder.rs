@@ -7,7 +7,8 @@ use super::*;
 #[getset(get = "pub", get_mut = "pub")]
 pub struct CibouletteResourceTypeBuilder {
     relationships: BTreeMap<ArcStr, petgraph::graph::EdgeIndex<u16>>,
-    relationships_type_to_alias: BTreeMap<ArcStr, ArcStr>,
+    /// The aliases of the relationships, by related type name
+    relationships_type_to_alias: BTreeMap<ArcStr, BTreeSet<ArcStr>>,
     schema: MessyJsonObject,
     ids: CibouletteIdTypeSelector,
     name: ArcStr,
@@ -51,16 +52,39 @@ impl CibouletteResourceTypeBuilder {
         }
     }
 
+    /// List the mandatory attributes of a schema, the ones that aren't optional
+    fn mandatory_attributes(schema: &MessyJsonObject) -> BTreeSet<ArcStr> {
+        schema
+            .properties()
+            .iter()
+            .filter(|(_, v)| !Self::is_optional(v))
+            .map(|(k, _)| k.clone())
+            .collect()
+    }
+
+    /// Check if an attribute is optional
+    fn is_optional(value: &MessyJson) -> bool {
+        #[allow(unreachable_patterns)]
+        match value.deref() {
+            MessyJsonInner::Obj(x) => x.optional(),
+            MessyJsonInner::Array(x) => x.optional(),
+            MessyJsonInner::String(x) | MessyJsonInner::Bool(x) | MessyJsonInner::Uuid(x) => {
+                x.optional()
+            }
+            MessyJsonInner::Number(x) => x.optional(),
+            _ => true,
+        }
+    }
+
     /// Build the resource type, checking once the member names
     pub fn build(self) -> Result<CibouletteResourceType, CibouletteError> {
         if let Some(x) = Self::check_member_name_obj(self.schema()) {
             return Err(CibouletteError::InvalidMemberName(x));
         }
-        Ok(CibouletteResourceType::new(
-            self.name,
-            self.ids,
-            self.schema,
-        ))
+        let mandatory_attributes = Self::mandatory_attributes(self.schema());
+        let mut res = CibouletteResourceType::new(self.name, self.ids, self.schema);
+        *res.mandatory_attributes_mut() = mandatory_attributes;
+        Ok(res)
     }
 }
 
@@ -6,6 +6,7 @@ mod normal_multi_docs;
 mod normal_single_doc;
 mod parsing_id;
 mod request_data;
+mod special_members;
 mod top_level_quick_checks;
 
 use crate::test_helper::*;
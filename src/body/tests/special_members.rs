@@ -0,0 +1,107 @@
+use super::*;
+
+#[test]
+fn extension_members() {
+    let bag = gen_bag();
+    const VAL: &str = r#"
+	{
+		"data":
+		{
+			"id": "6720877a-e27e-4e9e-9ac0-3fff4deb55f2",
+			"type": "comments",
+			"attributes":
+			{
+				"body": "world"
+			},
+			"version:id": "42"
+		},
+		"atomic:operations": []
+	}
+	"#;
+    let mut deserializer = serde_json::Deserializer::from_str(VAL);
+    let doc = CibouletteBodyBuilder::deserialize(&mut deserializer)
+        .expect("to parse the json:api document")
+        .build(&bag, &CibouletteIntention::Create)
+        .expect("to build the document");
+    assert_eq!(
+        doc.extensions().get("atomic:operations"),
+        Some(&serde_json::json!([]))
+    );
+    let data = match doc.data() {
+        CibouletteBodyData::Object(x) => x,
+        _ => panic!("data should've been present"),
+    };
+    let data = check_single(&data);
+    assert_eq!(
+        data.extensions().get("version:id"),
+        Some(&serde_json::json!("42"))
+    );
+    let serialized = serde_json::to_value(&doc).unwrap();
+    assert_eq!(serialized["atomic:operations"], serde_json::json!([]));
+    assert_eq!(serialized["data"]["version:id"], serde_json::json!("42"));
+}
+
+#[test]
+fn at_members() {
+    let bag = gen_bag();
+    const VAL: &str = r#"
+	{
+		"@context": "http://schema.org",
+		"data":
+		{
+			"@type": "Comment",
+			"id": "6720877a-e27e-4e9e-9ac0-3fff4deb55f2",
+			"type": "comments",
+			"attributes":
+			{
+				"@id": "comment-1",
+				"body": "world"
+			}
+		}
+	}
+	"#;
+    let mut deserializer = serde_json::Deserializer::from_str(VAL);
+    let doc = CibouletteBodyBuilder::deserialize(&mut deserializer)
+        .expect("to parse the json:api document")
+        .build(&bag, &CibouletteIntention::Create)
+        .expect("to build the document");
+    assert_eq!(doc.extensions().is_empty(), true);
+    let data = match doc.data() {
+        CibouletteBodyData::Object(x) => x,
+        _ => panic!("data should've been present"),
+    };
+    let data = check_single(&data);
+    assert_eq!(data.extensions().is_empty(), true);
+    assert_eq!(
+        data.attributes()
+            .clone()
+            .unwrap()
+            .eq(&parse_attribute_comments()),
+        true
+    );
+}
+
+#[test]
+fn invalid_extension_member() {
+    let bag = gen_bag();
+    const VAL: &str = r#"
+	{
+		"data":
+		{
+			"id": "6720877a-e27e-4e9e-9ac0-3fff4deb55f2",
+			"type": "comments",
+			"attributes":
+			{
+				"body": "world"
+			}
+		},
+		"ver-sion:id": "42"
+	}
+	"#;
+    let mut deserializer = serde_json::Deserializer::from_str(VAL);
+    let doc = CibouletteBodyBuilder::deserialize(&mut deserializer)
+        .expect("to parse the json:api document")
+        .build(&bag, &CibouletteIntention::Create)
+        .expect("to build the document");
+    assert_eq!(doc.extensions().is_empty(), true);
+}
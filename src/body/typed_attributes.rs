@@ -0,0 +1,122 @@
+use super::*;
+use serde::de::{DeserializeOwned, Deserializer};
+
+/// ## State of an attribute of a partial update, deserialized as `T`
+///
+/// Meant to be used on the fields of the types the attributes are deserialized to, with
+/// `#[serde(default)]` so the attributes the client didn't send are left [Absent](CibouletteAttributePatch::Absent).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CibouletteAttributePatch<T> {
+    /// The attribute wasn't sent
+    Absent,
+    /// The attribute was explicitly set to `null`
+    Null,
+    /// The attribute was set to a value
+    Set(T),
+}
+
+impl<T> Default for CibouletteAttributePatch<T> {
+    fn default() -> Self {
+        CibouletteAttributePatch::Absent
+    }
+}
+
+impl<T> CibouletteAttributePatch<T> {
+    /// Check if the attribute was sent by the client
+    pub fn is_present(&self) -> bool {
+        !matches!(self, CibouletteAttributePatch::Absent)
+    }
+
+    /// Get the value of the attribute, if set
+    pub fn as_ref(&self) -> CibouletteAttributePatch<&T> {
+        match self {
+            CibouletteAttributePatch::Absent => CibouletteAttributePatch::Absent,
+            CibouletteAttributePatch::Null => CibouletteAttributePatch::Null,
+            CibouletteAttributePatch::Set(x) => CibouletteAttributePatch::Set(x),
+        }
+    }
+}
+
+impl<T> From<CibouletteAttributePatch<T>> for Option<Option<T>> {
+    fn from(patch: CibouletteAttributePatch<T>) -> Self {
+        match patch {
+            CibouletteAttributePatch::Absent => None,
+            CibouletteAttributePatch::Null => Some(None),
+            CibouletteAttributePatch::Set(x) => Some(Some(x)),
+        }
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for CibouletteAttributePatch<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(match Option::<T>::deserialize(deserializer)? {
+            None => CibouletteAttributePatch::Null,
+            Some(x) => CibouletteAttributePatch::Set(x),
+        })
+    }
+}
+
+impl<'request, T> CibouletteResource<'request, MessyJsonObjectValue<'request>, T> {
+    /// ## Deserialize the attributes sent by the client as `A`
+    ///
+    /// The attributes are named as in the schema. The attributes that weren't sent are
+    /// absent from the deserialized object, while those set to `null` are present.
+    /// The failures are located by a JSON pointer in the request body.
+    pub fn attributes_as<A: DeserializeOwned>(&self) -> Result<A, CibouletteError> {
+        let mut attributes = serde_json::Map::new();
+        if let Some(obj) = self.attributes() {
+            for (name, value) in obj.iter() {
+                if !CibouletteAttributeValue::from(Some(value)).is_present() {
+                    continue;
+                }
+                attributes.insert(name.to_string(), serde_json::to_value(value)?);
+            }
+        }
+        let track = serde_path_to_error::Track::new();
+        A::deserialize(serde_path_to_error::Deserializer::new(
+            Value::Object(attributes),
+            &track,
+        ))
+        .map_err(|err| {
+            let path = track.path();
+            CibouletteError::InvalidMember(
+                format!(
+                    "{}/attributes{}",
+                    self.pointer(),
+                    path_to_pointer_with(&path, |name| self.type_().member_to_wire(name))
+                ),
+                err.to_string(),
+            )
+        })
+    }
+}
+
+impl<'request> CibouletteCreateRequest<'request> {
+    /// ## Deserialize the attributes of the created resource as `A`
+    ///
+    /// See [CibouletteResource::attributes_as](CibouletteResource::attributes_as)
+    pub fn attributes_as<A: DeserializeOwned>(&self) -> Result<A, CibouletteError> {
+        self.data().attributes_as()
+    }
+}
+
+impl<'request> CibouletteUpdateRequest<'request> {
+    /// ## Deserialize the attributes of the updated resource as `A`
+    ///
+    /// The fields of `A` would usually be [patches](CibouletteAttributePatch) to preserve
+    /// the partial update semantics. Fails if the request updates a relationship.
+    ///
+    /// See [CibouletteResource::attributes_as](CibouletteResource::attributes_as)
+    pub fn attributes_as<A: DeserializeOwned>(&self) -> Result<A, CibouletteError> {
+        match self.data() {
+            CibouletteUpdateRequestType::MainType(data) => data.attributes_as(),
+            CibouletteUpdateRequestType::Relationship(_) => Err(CibouletteError::WrongPathType(
+                CiboulettePathType::TypeIdRelationship,
+                vec![CiboulettePathType::TypeId],
+            )),
+        }
+    }
+}
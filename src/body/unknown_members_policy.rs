@@ -0,0 +1,39 @@
+use super::*;
+
+/// ## Policy applied to the members of a request body that aren't known to the store
+///
+/// It covers the top-level and resource-level members not defined by the specification,
+/// as well as the attributes missing from the schema of their type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CibouletteUnknownMembersPolicy {
+    /// Reject the request
+    Strict,
+    /// Drop the unknown members
+    Ignore,
+    /// Drop the unknown members, listing them on the request
+    IgnoreAndReport,
+}
+
+impl Default for CibouletteUnknownMembersPolicy {
+    fn default() -> Self {
+        CibouletteUnknownMembersPolicy::Ignore
+    }
+}
+
+impl CibouletteUnknownMembersPolicy {
+    /// Handle an unknown member, designated by its JSON pointer
+    pub(crate) fn handle(
+        &self,
+        pointer: String,
+        dropped_members: &mut Vec<String>,
+    ) -> Result<(), CibouletteError> {
+        match self {
+            CibouletteUnknownMembersPolicy::Strict => Err(CibouletteError::UnknownMember(pointer)),
+            CibouletteUnknownMembersPolicy::Ignore => Ok(()),
+            CibouletteUnknownMembersPolicy::IgnoreAndReport => {
+                dropped_members.push(pointer);
+                Ok(())
+            }
+        }
+    }
+}
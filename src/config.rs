@@ -16,6 +16,14 @@ pub struct CibouletteConfig {
     /// True if Ciboulette should generate relationship level self links. Default `true`
     #[getset(get_copy = "pub", get_mut = "pub")]
     gen_relationship_links: bool,
+    /// The policy applied to the query parameters not defined by the specification.
+    /// Default [Permissive](CibouletteQueryParametersPolicy::Permissive)
+    #[getset(get_copy = "pub", get_mut = "pub")]
+    query_parameters_policy: CibouletteQueryParametersPolicy,
+    /// The policy applied to the unknown members of the request bodies, unless overridden by a type.
+    /// Default [Ignore](CibouletteUnknownMembersPolicy::Ignore)
+    #[getset(get_copy = "pub", get_mut = "pub")]
+    unknown_members_policy: CibouletteUnknownMembersPolicy,
 }
 
 impl Default for CibouletteConfig {
@@ -25,6 +33,8 @@ impl Default for CibouletteConfig {
             gen_root_links: true,
             gen_relationship_links: true,
             base_url: None,
+            query_parameters_policy: CibouletteQueryParametersPolicy::default(),
+            unknown_members_policy: CibouletteUnknownMembersPolicy::default(),
         }
     }
 }
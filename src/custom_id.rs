@@ -0,0 +1,304 @@
+use super::*;
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+
+/// ## Parser and formatter for a custom resource id type
+///
+/// Implement this trait to support ids that can't be represented by the built-in
+/// [id types](CibouletteIdType) (signed integers, ULIDs, slugs, ...).
+///
+/// The parser must be registered in the [store builder](CibouletteStoreBuilder)
+/// before being used by a resource type.
+///
+/// The formatted ids are sent back by the clients, so parsing a formatted id must give
+/// back its canonical form: `parse(format(x)) == x`. The ids of the responses are
+/// provided by the backend in their canonical form and don't go through the parser.
+pub trait CibouletteIdParser: std::fmt::Debug + Send + Sync {
+    /// The name of the id type. It must be unique in a store
+    fn name(&self) -> &str;
+
+    /// Parse and validate an id received from a client, returning its canonical form
+    fn parse<'request>(
+        &self,
+        id: Cow<'request, str>,
+    ) -> Result<Cow<'request, str>, CibouletteError>;
+
+    /// Format an id in its canonical form to its `JSON:API` representation.
+    ///
+    /// Its result must be accepted by [parse](CibouletteIdParser::parse)
+    fn format<'a>(&self, id: &'a str) -> Cow<'a, str> {
+        Cow::Borrowed(id)
+    }
+}
+
+/// ## Handle to a registered [custom id parser](CibouletteIdParser)
+///
+/// Handles are compared by the name of the parser they hold.
+#[derive(Debug, Clone)]
+pub struct CibouletteCustomIdType(Arc<dyn CibouletteIdParser>);
+
+impl CibouletteCustomIdType {
+    /// Create a new handle from a parser
+    pub fn new(parser: Arc<dyn CibouletteIdParser>) -> Self {
+        CibouletteCustomIdType(parser)
+    }
+
+    /// Get the name of the custom id type
+    pub fn name(&self) -> &str {
+        self.0.name()
+    }
+
+    /// Get the inner parser
+    pub fn parser(&self) -> &Arc<dyn CibouletteIdParser> {
+        &self.0
+    }
+
+    /// Parse an id using the inner parser
+    pub fn parse<'request>(
+        &self,
+        id: Cow<'request, str>,
+    ) -> Result<CibouletteCustomId<'request>, CibouletteError> {
+        Ok(CibouletteCustomId {
+            type_: self.clone(),
+            value: self.0.parse(id)?,
+        })
+    }
+
+    /// Build an id from its canonical form, without going through the inner parser
+    pub fn from_canonical<'request>(
+        &self,
+        value: Cow<'request, str>,
+    ) -> CibouletteCustomId<'request> {
+        CibouletteCustomId {
+            type_: self.clone(),
+            value,
+        }
+    }
+}
+
+impl PartialEq for CibouletteCustomIdType {
+    fn eq(&self, other: &Self) -> bool {
+        self.name() == other.name()
+    }
+}
+
+impl Eq for CibouletteCustomIdType {}
+
+impl Ord for CibouletteCustomIdType {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.name().cmp(other.name())
+    }
+}
+
+impl PartialOrd for CibouletteCustomIdType {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Hash for CibouletteCustomIdType {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name().hash(state)
+    }
+}
+
+/// ## An id parsed by a [custom id parser](CibouletteIdParser)
+#[derive(Debug, Clone, Getters)]
+#[getset(get = "pub")]
+pub struct CibouletteCustomId<'request> {
+    /// The custom type of this id
+    type_: CibouletteCustomIdType,
+    /// The canonical value of the id
+    value: Cow<'request, str>,
+}
+
+impl<'request> PartialEq for CibouletteCustomId<'request> {
+    fn eq(&self, other: &Self) -> bool {
+        self.type_ == other.type_ && self.value == other.value
+    }
+}
+
+impl<'request> Eq for CibouletteCustomId<'request> {}
+
+impl<'request> Ord for CibouletteCustomId<'request> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match self.type_.cmp(&other.type_) {
+            Ordering::Equal => self.value.cmp(&other.value),
+            x => x,
+        }
+    }
+}
+
+impl<'request> PartialOrd for CibouletteCustomId<'request> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'request> Hash for CibouletteCustomId<'request> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.type_.hash(state);
+        self.value.hash(state);
+    }
+}
+
+impl<'request> std::fmt::Display for CibouletteCustomId<'request> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.type_.parser().format(self.value.as_ref()))
+    }
+}
+
+impl<'request> Serialize for CibouletteCustomId<'request> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.type_.parser().format(self.value.as_ref()).as_ref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug)]
+    struct SignedIdParser;
+
+    impl CibouletteIdParser for SignedIdParser {
+        fn name(&self) -> &str {
+            "signed"
+        }
+
+        fn parse<'request>(
+            &self,
+            id: Cow<'request, str>,
+        ) -> Result<Cow<'request, str>, CibouletteError> {
+            let val = id
+                .strip_prefix('s')
+                .and_then(|x| x.parse::<i64>().ok())
+                .ok_or_else(|| {
+                    CibouletteError::InvalidId(self.name().to_string(), id.to_string())
+                })?;
+            Ok(Cow::Owned(val.to_string()))
+        }
+
+        fn format<'a>(&self, id: &'a str) -> Cow<'a, str> {
+            Cow::Owned(format!("s{}", id))
+        }
+    }
+
+    fn gen_id_type() -> CibouletteIdTypeSelector {
+        CibouletteIdTypeSelector::new(CibouletteSelector::Single(CibouletteIdType::Custom(
+            arcstr::literal!("id"),
+            CibouletteCustomIdType::new(Arc::new(SignedIdParser)),
+        )))
+    }
+
+    #[test]
+    fn parse_ok() {
+        let id = CibouletteIdSelector::build_id(&gen_id_type(), Cow::Borrowed("s-042")).unwrap();
+
+        assert_eq!(
+            matches!(id.get(0).unwrap(), CibouletteId::Custom(x) if x.value() == "-42"),
+            true
+        );
+        assert_eq!(id.to_string(), "s-42");
+        assert_eq!(serde_json::to_string(&*id).unwrap(), "\"s-42\"");
+    }
+
+    #[test]
+    fn round_trip() {
+        for canonical in &["-42", "0", "9223372036854775807"] {
+            let formatted = SignedIdParser.format(canonical);
+            let parsed = SignedIdParser
+                .parse(Cow::Borrowed(formatted.as_ref()))
+                .unwrap();
+
+            assert_eq!(parsed.as_ref(), *canonical);
+            // The formatted ids can be sent back as they are
+            let id =
+                CibouletteIdSelector::build_id(&gen_id_type(), Cow::Owned(formatted.to_string()))
+                    .unwrap();
+            assert_eq!(id.to_string(), formatted);
+        }
+    }
+
+    #[test]
+    fn parse_err() {
+        for id in &["toto", "-42"] {
+            let err =
+                CibouletteIdSelector::build_id(&gen_id_type(), Cow::Borrowed(id)).unwrap_err();
+
+            assert_eq!(
+                matches!(err, CibouletteError::InvalidId(x, y) if x == "signed" && y == *id),
+                true
+            );
+        }
+    }
+
+    #[test]
+    fn canonical_response_id() {
+        let mut store = CibouletteStoreBuilder::default();
+
+        store.add_id_type(Arc::new(SignedIdParser)).unwrap();
+        store
+            .add_type("toto", gen_id_type(), MessyJsonObject::default())
+            .unwrap();
+        let store = store.build().unwrap();
+        // The backend provides the canonical id, that the parser would reject
+        let element: CibouletteResponseElement<()> = CibouletteResponseElement::build(
+            &store,
+            store.get_type("toto").unwrap(),
+            CibouletteResourceIdentifierBuilder::new(
+                Some(Cow::Borrowed("-42")),
+                Cow::Borrowed("toto"),
+            ),
+            None,
+            None,
+        )
+        .unwrap();
+        let id = element.identifier().id();
+
+        assert_eq!(
+            matches!(id.get(0).unwrap(), CibouletteId::Custom(x) if x.value() == "-42"),
+            true
+        );
+        assert_eq!(id.to_string(), "s-42");
+    }
+
+    #[test]
+    fn unregistered_type() {
+        let mut store = CibouletteStoreBuilder::default();
+
+        let err = store
+            .add_type("toto", gen_id_type(), MessyJsonObject::default())
+            .unwrap_err();
+        assert_eq!(
+            matches!(err, CibouletteError::UnknownIdType(x) if x == "signed"),
+            true
+        );
+    }
+
+    #[test]
+    fn registered_type() {
+        let mut store = CibouletteStoreBuilder::default();
+
+        store.add_id_type(Arc::new(SignedIdParser)).unwrap();
+        store
+            .add_type(
+                "toto",
+                CibouletteIdTypeSelector::new(CibouletteSelector::Single(
+                    store
+                        .custom_id_type(arcstr::literal!("id"), "signed")
+                        .unwrap(),
+                )),
+                MessyJsonObject::default(),
+            )
+            .unwrap();
+        let err = store.add_id_type(Arc::new(SignedIdParser)).unwrap_err();
+        assert_eq!(
+            matches!(err, CibouletteError::UniqIdType(x) if x == "signed"),
+            true
+        );
+    }
+}
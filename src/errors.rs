@@ -62,6 +62,8 @@ pub enum CibouletteError {
     UnknownType(String),
     #[error("No relationship `{1}` for type `{0}`.")]
     UnknownRelationship(String, String),
+    #[error("The relationship `{1}` of type `{0}` is not a to-many relationship")]
+    ToOneRelationship(String, String),
     #[error("No field {1} for type {0}.")]
     UnknownField(String, String),
     #[error("The resource object ({0}, {1}) should be unique by `type` and `id`")]
@@ -84,6 +86,8 @@ pub enum CibouletteError {
     KeyClash(String, CibouletteClashDirection, String),
     #[error("The member name {0} doesn't respect the json:api specification")]
     InvalidMemberName(String),
+    #[error("Multiple members of type `{0}` are named `{1}` once converted")]
+    MemberNameClash(String, String),
     #[error("The `attributes` should be an object")]
     AttributesIsNotAnObject,
     #[error("The `data` object is missing")]
@@ -92,10 +96,62 @@ pub enum CibouletteError {
     MissingId,
     #[error("Unkown id type `{0}`")]
     UnknownIdType(String),
+    #[error("The id type `{0}` is already defined")]
+    UniqIdType(String),
+    #[error("The id `{1}` is not a valid `{0}` id")]
+    InvalidId(String, String),
+    #[error("The media type `{0}` is not supported")]
+    InvalidMediaType(String),
+    #[error("The extension `{0}` is already defined")]
+    UniqExtension(String),
+    #[error("Unknown extension `{0}`")]
+    UnknownExtension(String),
+    #[error("The extension `{0}` wasn't negotiated in the media type")]
+    ExtensionNotNegotiated(String),
+    #[error("Unknown extension member `{0}`")]
+    UnknownExtensionMember(String),
+    #[error("The profile `{0}` is already defined")]
+    UniqProfile(String),
+    #[error("Unknown query parameter `{0}`")]
+    UnknownQueryParameter(String),
+    #[error("Invalid value for the query parameter `{0}` : {1}")]
+    InvalidQueryParameter(String, String),
+    #[error("The query parameter `{0}` is already defined")]
+    UniqQueryParameter(String),
+    #[error("The attribute `{1}` of type `{0}` can't be set by this request")]
+    ForbiddenAttribute(String, String),
+    #[error("The `{1}` operation is forbidden for type `{0}`")]
+    ForbiddenOperation(String, CibouletteIntention),
+    #[error("The `{2}` operation is forbidden for the relationship `{1}` of type `{0}`")]
+    ForbiddenRelationshipOperation(String, String, CibouletteIntention),
+    #[error("Client-generated ids are forbidden for type `{0}`")]
+    ClientIdForbidden(String),
+    #[error("Client-generated ids are required for type `{0}`")]
+    MissingClientId(String),
+    #[error("The attribute `{1}` of type `{0}` is required")]
+    MissingAttribute(String, String),
+    #[error("The attributes of type `{0}` violate {} constraint(s)", .1.len())]
+    ConstraintViolations(String, Vec<CibouletteConstraintViolation>),
+    #[error("Unknown member at `{0}`")]
+    UnknownMember(String),
+    #[error("Invalid member at `{0}` : {1}")]
+    InvalidMember(String, String),
+    #[error("Invalid store definition at `{0}` : {1}")]
+    StoreDefinition(String, Box<CibouletteError>),
+    #[error("The relationship between `{0}` and `{1}` isn't exposed on any of its ends")]
+    UnexposedRelationship(String, String),
+    #[error("The schema can't be described by a store definition")]
+    UnsupportedSchema,
+    #[error("The store has {} lint warning(s)", .0.len())]
+    LintWarnings(Vec<CibouletteLint>),
     #[error("Compound documents are forbidden for that kind of request")]
     NoCompound,
     #[error("No alias translation for `{1}` in type `{0}`")]
     MissingAliasTranslation(String, String),
+    #[error("The type `{0}` has more than one relationship to `{1}`: {}", .2.join(", "))]
+    AmbiguousAlias(String, String, Vec<String>),
+    #[error("Both keys of the bucket `{0}` relate to `{1}`, they must be resolved by side")]
+    AmbiguousBucketKey(String, String),
     #[error("Couldn't parse the path, no type were specified")]
     MissingTypeInPath,
     #[error("Couldn't parse the path")]
@@ -130,3 +186,88 @@ pub enum CibouletteError {
     #[error(transparent)]
     Url(#[from] url::ParseError),
 }
+
+impl CibouletteError {
+    /// Get the part of the request that caused the error, if known
+    pub fn error_source(&self) -> Option<CibouletteErrorSource<'_>> {
+        match self {
+            CibouletteError::UnknownQueryParameter(name)
+            | CibouletteError::InvalidQueryParameter(name, _) => Some(CibouletteErrorSource {
+                parameter: Some(Cow::Borrowed(name.as_str())),
+                ..CibouletteErrorSource::default()
+            }),
+            CibouletteError::ForbiddenAttribute(_, attribute) => Some(CibouletteErrorSource {
+                pointer: Some(Cow::Owned(format!("/data/attributes/{}", attribute))),
+                ..CibouletteErrorSource::default()
+            }),
+            CibouletteError::MissingAttribute(_, _) => Some(CibouletteErrorSource {
+                pointer: Some(Cow::Borrowed("/data/attributes")),
+                ..CibouletteErrorSource::default()
+            }),
+            CibouletteError::ClientIdForbidden(_) => Some(CibouletteErrorSource {
+                pointer: Some(Cow::Borrowed("/data/id")),
+                ..CibouletteErrorSource::default()
+            }),
+            CibouletteError::MissingClientId(_) => Some(CibouletteErrorSource {
+                pointer: Some(Cow::Borrowed("/data")),
+                ..CibouletteErrorSource::default()
+            }),
+            CibouletteError::UnknownMember(pointer)
+            | CibouletteError::InvalidMember(pointer, _) => Some(CibouletteErrorSource {
+                pointer: Some(Cow::Borrowed(pointer.as_str())),
+                ..CibouletteErrorSource::default()
+            }),
+            _ => None,
+        }
+    }
+
+    /// Get the HTTP status code to respond with when a request fails with this error
+    pub fn status_code(&self) -> u64 {
+        match self {
+            CibouletteError::ForbiddenAttribute(_, _)
+            | CibouletteError::ForbiddenOperation(_, _)
+            | CibouletteError::ForbiddenRelationshipOperation(_, _, _)
+            | CibouletteError::ClientIdForbidden(_)
+            | CibouletteError::MissingClientId(_) => 403,
+            CibouletteError::InvalidMediaType(_) | CibouletteError::UnknownExtension(_) => 415,
+            CibouletteError::ConstraintViolations(_, _) => 422,
+            _ => 400,
+        }
+    }
+
+    /// Build the `JSON:API` error objects describing this error.
+    ///
+    /// Every constraint violation is described by its own error object
+    pub fn error_objs(&self) -> Vec<CibouletteErrorObj<'_>> {
+        match self {
+            CibouletteError::ConstraintViolations(_, violations) => violations
+                .iter()
+                .map(|violation| CibouletteErrorObj {
+                    status: self.status_code(),
+                    title: Some(Cow::Borrowed("Invalid attribute")),
+                    detail: Some(Cow::Borrowed(violation.reason().as_str())),
+                    source: Some(CibouletteErrorSource {
+                        pointer: Some(Cow::Owned(format!(
+                            "/data/attributes/{}",
+                            violation.attribute()
+                        ))),
+                        ..CibouletteErrorSource::default()
+                    }),
+                    ..CibouletteErrorObj::default()
+                })
+                .collect(),
+            _ => vec![CibouletteErrorObj::from(self)],
+        }
+    }
+}
+
+impl<'a> From<&'a CibouletteError> for CibouletteErrorObj<'a> {
+    fn from(err: &'a CibouletteError) -> Self {
+        CibouletteErrorObj {
+            status: err.status_code(),
+            detail: Some(Cow::Owned(err.to_string())),
+            source: err.error_source(),
+            ..CibouletteErrorObj::default()
+        }
+    }
+}
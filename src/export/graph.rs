@@ -0,0 +1,303 @@
+use super::*;
+use std::fmt::Write;
+
+/// A relationship edge of the store graph, as drawn in the diagrams
+struct CibouletteGraphEdge<'a> {
+    from: &'a CibouletteResourceType,
+    to: &'a CibouletteResourceType,
+    /// The name of the relationship on the source type, if exposed
+    alias: Option<&'a str>,
+    option: &'a CibouletteRelationshipOption,
+}
+
+impl<'a> CibouletteGraphEdge<'a> {
+    /// The kind of the relationship, as seen from its source type
+    fn kind(&self) -> &'static str {
+        match self.option {
+            CibouletteRelationshipOption::OneToMany(_) => "OneToMany",
+            CibouletteRelationshipOption::ManyToOne(_) => "ManyToOne",
+            CibouletteRelationshipOption::ManyToMany(_) => "ManyToMany",
+        }
+    }
+
+    /// True if the edge links a type to the bucket of a Many-to-Many relationship
+    fn is_bucket(&self) -> bool {
+        match self.option {
+            CibouletteRelationshipOption::OneToMany(opt)
+            | CibouletteRelationshipOption::ManyToOne(opt) => opt.part_of_many_to_many().is_some(),
+            CibouletteRelationshipOption::ManyToMany(_) => false,
+        }
+    }
+
+    /// True if the relationship is optional
+    fn optional(&self) -> bool {
+        match self.option {
+            CibouletteRelationshipOption::OneToMany(opt)
+            | CibouletteRelationshipOption::ManyToOne(opt) => *opt.optional(),
+            CibouletteRelationshipOption::ManyToMany(_) => true,
+        }
+    }
+
+    /// Describe the relationship, one detail per line
+    fn details(&self) -> Vec<String> {
+        let mut res = vec![
+            self.alias.unwrap_or("(not exposed)").to_string(),
+            self.kind().to_string(),
+        ];
+        match self.option {
+            CibouletteRelationshipOption::OneToMany(opt)
+            | CibouletteRelationshipOption::ManyToOne(opt) => {
+                res.push(format!(
+                    "keys: {}.{} <- {}.{}",
+                    opt.one_resource().name(),
+                    opt.one_resource_key(),
+                    opt.many_resource().name(),
+                    opt.many_resource_key()
+                ));
+                if *opt.optional() {
+                    res.push("optional".to_string());
+                }
+                if opt.part_of_many_to_many().is_some() {
+                    res.push("bucket link".to_string());
+                }
+            }
+            CibouletteRelationshipOption::ManyToMany(opt) => {
+                res.push(format!(
+                    "keys: {}.{}, {}.{}",
+                    opt.bucket_resource().name(),
+                    opt.source_key(),
+                    opt.bucket_resource().name(),
+                    opt.target_key()
+                ));
+                res.push(format!("bucket: {}", opt.bucket_resource().name()));
+            }
+        }
+        res
+    }
+}
+
+/// Describe the kind of an id
+fn id_kind(id: &CibouletteIdType) -> &str {
+    match id {
+        CibouletteIdType::Number(_) => "number",
+        CibouletteIdType::Text(_) => "text",
+        CibouletteIdType::Uuid(_) => "uuid",
+        CibouletteIdType::Custom(_, type_) => type_.name(),
+    }
+}
+
+/// Describe the kind of an attribute
+fn attribute_kind(attribute: &CibouletteAttributeDefinition) -> String {
+    match attribute {
+        CibouletteAttributeDefinition::String { .. } => "string".to_string(),
+        CibouletteAttributeDefinition::Number { format, .. } => format.to_string(),
+        CibouletteAttributeDefinition::Bool { .. } => "bool".to_string(),
+        CibouletteAttributeDefinition::Uuid { .. } => "uuid".to_string(),
+        CibouletteAttributeDefinition::Array { items, .. } => {
+            format!("{}[]", attribute_kind(items))
+        }
+        CibouletteAttributeDefinition::Object { .. } => "object".to_string(),
+    }
+}
+
+/// Check if an attribute is optional
+fn attribute_optional(attribute: &CibouletteAttributeDefinition) -> bool {
+    match attribute {
+        CibouletteAttributeDefinition::String { optional }
+        | CibouletteAttributeDefinition::Number { optional, .. }
+        | CibouletteAttributeDefinition::Bool { optional }
+        | CibouletteAttributeDefinition::Uuid { optional }
+        | CibouletteAttributeDefinition::Array { optional, .. }
+        | CibouletteAttributeDefinition::Object { optional, .. } => *optional,
+    }
+}
+
+/// The alias of the relationship of a type using an edge, if any
+fn relationship_alias(
+    type_: &CibouletteResourceType,
+    edge: petgraph::graph::EdgeIndex<u16>,
+) -> Option<&str> {
+    type_
+        .relationships()
+        .iter()
+        .find(|(_, x)| **x == edge)
+        .map(|(alias, _)| alias.as_str())
+}
+
+/// Escape a text to be used in a `DOT` record label
+fn escape_record(text: &str) -> String {
+    let mut res = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '{' | '}' | '|' | '<' | '>' | '"' | '\\') {
+            res.push('\\');
+        }
+        res.push(c);
+    }
+    res
+}
+
+/// Escape a text to be used in a `DOT` quoted string
+fn escape_quoted(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+impl CibouletteStore {
+    /// List the relationship edges of the graph, by source type then alias
+    fn graph_edges(&self) -> Vec<CibouletteGraphEdge<'_>> {
+        let mut res = Vec::with_capacity(self.graph().edge_count());
+        for edge in self.graph().edge_indices() {
+            let (from_i, to_i, option) = match (
+                self.graph().edge_endpoints(edge),
+                self.graph().edge_weight(edge),
+            ) {
+                (Some((from_i, to_i)), Some(option)) => (from_i, to_i, option),
+                _ => continue,
+            };
+            let (from, to) = match (
+                self.graph().node_weight(from_i),
+                self.graph().node_weight(to_i),
+            ) {
+                (Some(from), Some(to)) => (from.as_ref(), to.as_ref()),
+                _ => continue,
+            };
+            // The Many-to-Many edges are held by the type they point to,
+            // they're drawn from that type
+            let (from, to, alias) =
+                match (relationship_alias(from, edge), relationship_alias(to, edge)) {
+                    (Some(alias), _) => (from, to, Some(alias)),
+                    (None, Some(alias)) => (to, from, Some(alias)),
+                    (None, None) => (from, to, None),
+                };
+            res.push(CibouletteGraphEdge {
+                from,
+                to,
+                alias,
+                option,
+            });
+        }
+        res.sort_by(|a, b| (a.from.name(), a.alias).cmp(&(b.from.name(), b.alias)));
+        res
+    }
+
+    /// List the types of the graph with their attributes, by name
+    fn graph_types(
+        &self,
+    ) -> Result<
+        Vec<(
+            &CibouletteResourceType,
+            BTreeMap<String, CibouletteAttributeDefinition>,
+        )>,
+        CibouletteError,
+    > {
+        let mut res = Vec::with_capacity(self.map().len());
+        for name in self.map().keys() {
+            let type_ = self.get_type(name)?;
+            let attributes = CibouletteAttributeDefinition::properties_from_messy_json(
+                type_.schema(),
+                &format!("types.{}.attributes", name),
+            )?;
+            res.push((type_.as_ref(), attributes));
+        }
+        Ok(res)
+    }
+
+    /// ## Draw the store graph as a `Graphviz` `DOT` digraph
+    ///
+    /// The types are records listing their ids and attributes, every relationship edge
+    /// being labelled with its alias, kind, keys, optionality and bucket. The edges linking
+    /// a type to the bucket of a Many-to-Many relationship are dashed.
+    pub fn to_dot(&self) -> Result<String, CibouletteError> {
+        let mut res = String::from("digraph ciboulette {\n    node [shape=record];\n");
+
+        for (type_, attributes) in self.graph_types()? {
+            let mut fields: Vec<String> = type_
+                .ids()
+                .iter()
+                .map(|id| escape_record(&format!("{}: {} (id)", id.name(), id_kind(id))))
+                .collect();
+            fields.extend(attributes.iter().map(|(name, attribute)| {
+                escape_record(&format!(
+                    "{}{}: {}",
+                    name,
+                    if attribute_optional(attribute) {
+                        "?"
+                    } else {
+                        ""
+                    },
+                    attribute_kind(attribute)
+                ))
+            }));
+            let _ = writeln!(
+                res,
+                "    \"{}\" [label=\"{{{}|{}\\l}}\"];",
+                escape_quoted(type_.name()),
+                escape_record(type_.name()),
+                fields.join("\\l")
+            );
+        }
+        for edge in self.graph_edges() {
+            let _ = writeln!(
+                res,
+                "    \"{}\" -> \"{}\" [label=\"{}\"{}];",
+                escape_quoted(edge.from.name()),
+                escape_quoted(edge.to.name()),
+                escape_quoted(&edge.details().join("\n")).replace('\n', "\\n"),
+                if edge.is_bucket() {
+                    ", style=dashed"
+                } else {
+                    ""
+                }
+            );
+        }
+        res.push_str("}\n");
+        Ok(res)
+    }
+
+    /// ## Draw the store graph as a `Mermaid` entity-relationship diagram
+    ///
+    /// The entities list their ids and attributes, every relationship edge being labelled
+    /// with its alias, kind, keys, optionality and bucket. The edges linking a type to the
+    /// bucket of a Many-to-Many relationship are non-identifying (dotted).
+    pub fn to_mermaid(&self) -> Result<String, CibouletteError> {
+        let mut res = String::from("erDiagram\n");
+
+        for (type_, attributes) in self.graph_types()? {
+            let _ = writeln!(res, "    {} {{", type_.name());
+            for id in type_.ids().iter() {
+                let _ = writeln!(res, "        {} {} PK", id_kind(id), id.name());
+            }
+            for (name, attribute) in attributes.iter() {
+                let _ = match attribute_optional(attribute) {
+                    true => writeln!(
+                        res,
+                        "        {} {} \"optional\"",
+                        attribute_kind(attribute),
+                        name
+                    ),
+                    false => writeln!(res, "        {} {}", attribute_kind(attribute), name),
+                };
+            }
+            res.push_str("    }\n");
+        }
+        for edge in self.graph_edges() {
+            let (from, to) = match (edge.option, edge.optional()) {
+                (CibouletteRelationshipOption::OneToMany(_), false) => ("||", "o{"),
+                (CibouletteRelationshipOption::OneToMany(_), true) => ("|o", "o{"),
+                (CibouletteRelationshipOption::ManyToOne(_), false) => ("}o", "||"),
+                (CibouletteRelationshipOption::ManyToOne(_), true) => ("}o", "o|"),
+                (CibouletteRelationshipOption::ManyToMany(_), _) => ("}o", "o{"),
+            };
+            let _ = writeln!(
+                res,
+                "    {} {}{}{} {} : \"{}\"",
+                edge.from.name(),
+                from,
+                if edge.is_bucket() { ".." } else { "--" },
+                to,
+                edge.to.name(),
+                edge.details().join(", ").replace('"', "'")
+            );
+        }
+        Ok(res)
+    }
+}
@@ -0,0 +1,78 @@
+use super::*;
+
+/// The `JSON Schema` dialect the generated schemas conform to
+const JSON_SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// Prefix of the references to the definitions of a `JSON Schema` document
+const DEFS_PREFIX: &str = "#/$defs/";
+
+/// ## `JSON Schema` documents validating the bodies exchanged for a [resource type](CibouletteResourceType)
+#[derive(Debug, Clone, Getters)]
+#[getset(get = "pub")]
+pub struct CibouletteJsonSchemas {
+    /// The body of the creation requests, if the type is creatable
+    create: Option<Value>,
+    /// The body of the update requests, if the type is updatable
+    update: Option<Value>,
+    /// The body of the relationships update requests, by relationship name as sent by the clients
+    relationships: BTreeMap<ArcStr, Value>,
+    /// The response documents holding a single resource
+    single: Value,
+    /// The response documents holding a collection of resources
+    collection: Value,
+}
+
+impl CibouletteStore {
+    /// ## Generate the `JSON Schema` documents validating the bodies exchanged for a type
+    ///
+    /// Every document embeds the definitions of the resources of the store it references.
+    pub fn json_schemas(&self, type_: &str) -> Result<CibouletteJsonSchemas, CibouletteError> {
+        let type_ = self.get_type(type_)?;
+        let generator = CibouletteSchemaGenerator::new(self, DEFS_PREFIX);
+        let definitions = Value::Object(generator.definitions());
+        let name = type_.public_name().as_str();
+        let standalone = |schema: Value| -> Value {
+            let mut res = schema;
+            res["$schema"] = Value::from(JSON_SCHEMA_DIALECT);
+            res["$defs"] = definitions.clone();
+            res
+        };
+
+        let create = match type_.capabilities().creatable() {
+            true => Some(standalone(
+                generator.document(generator.reference(&format!("{}-create", name)), false),
+            )),
+            false => None,
+        };
+        let update = match type_.capabilities().updatable() {
+            true => Some(standalone(
+                generator.document(generator.reference(&format!("{}-update", name)), false),
+            )),
+            false => None,
+        };
+        let mut relationships: BTreeMap<ArcStr, Value> = BTreeMap::new();
+        for alias in type_.relationships().keys() {
+            if !type_.relationship_capabilities(alias).mutable() {
+                continue;
+            }
+            let details = type_.get_relationship_details(self, alias)?;
+            relationships.insert(
+                ArcStr::from(type_.member_to_wire(alias.as_str())),
+                standalone(generator.document(
+                    generator.linkage(details.related_type(), details.relation_option()),
+                    false,
+                )),
+            );
+        }
+        Ok(CibouletteJsonSchemas {
+            create,
+            update,
+            relationships,
+            single: standalone(generator.document(generator.reference(name), true)),
+            collection: standalone(generator.document(
+                json!({ "type": "array", "items": generator.reference(name) }),
+                true,
+            )),
+        })
+    }
+}
@@ -0,0 +1,459 @@
+use super::*;
+use serde_json::{json, Map};
+use std::ops::Deref;
+
+mod graph;
+pub mod json_schema;
+mod openapi;
+
+/// Generator of the schemas describing the documents exchanged with the clients
+///
+/// The schemas are written using the `JSON Schema` 2020-12 dialect, referencing each
+/// other with a prefix depending on the document embedding them.
+pub(crate) struct CibouletteSchemaGenerator<'a> {
+    store: &'a CibouletteStore,
+    ref_prefix: &'static str,
+}
+
+/// The maximum number of relationships in the advertised include paths
+const MAX_INCLUDE_DEPTH: usize = 3;
+
+/// Check if a relationship, seen from its source type, is a to-many relationship
+pub(crate) fn is_to_many(option: &CibouletteRelationshipOption) -> bool {
+    !matches!(option, CibouletteRelationshipOption::ManyToOne(_))
+}
+
+impl<'a> CibouletteSchemaGenerator<'a> {
+    /// Create a new generator, prefixing the references to the named schemas
+    pub(crate) fn new(store: &'a CibouletteStore, ref_prefix: &'static str) -> Self {
+        CibouletteSchemaGenerator { store, ref_prefix }
+    }
+
+    /// Reference a named schema
+    pub(crate) fn reference(&self, name: &str) -> Value {
+        json!({ "$ref": format!("{}{}", self.ref_prefix, name) })
+    }
+
+    /// Describe a value of an attribute schema.
+    ///
+    /// The optional values also accept `null`
+    pub(crate) fn value(value: &MessyJson) -> Value {
+        #[allow(unreachable_patterns)]
+        let (mut res, optional) = match value.deref() {
+            MessyJsonInner::Obj(obj) => (
+                json!({
+                    "type": "object",
+                    "properties": obj
+                        .properties()
+                        .iter()
+                        .map(|(k, v)| (k.to_string(), Self::value(v)))
+                        .collect::<Map<String, Value>>(),
+                }),
+                obj.optional(),
+            ),
+            MessyJsonInner::Array(arr) => (
+                json!({
+                    "type": "array",
+                    "items": Self::value(arr.items()),
+                }),
+                arr.optional(),
+            ),
+            MessyJsonInner::String(x) => (json!({ "type": "string" }), x.optional()),
+            MessyJsonInner::Number(x) => (json!({ "type": "integer", "minimum": 0 }), x.optional()),
+            MessyJsonInner::Bool(x) => (json!({ "type": "boolean" }), x.optional()),
+            MessyJsonInner::Uuid(x) => {
+                (json!({ "type": "string", "format": "uuid" }), x.optional())
+            }
+            _ => return json!({}),
+        };
+        if optional {
+            res["type"] = json!([res["type"].take(), "null"]);
+        }
+        res
+    }
+
+    /// Describe a bound of a range constraint.
+    ///
+    /// The bounds not fitting in a `u64` are approximated by a float, rounded so that
+    /// the described range still includes every accepted value
+    fn bound(x: u128, lower: bool) -> Value {
+        if let Ok(x) = u64::try_from(x) {
+            return Value::from(x);
+        }
+        let mut res = x as f64;
+        match lower {
+            true if res as u128 > x => res = f64::from_bits(res.to_bits() - 1),
+            false if (res as u128) < x => res = f64::from_bits(res.to_bits() + 1),
+            _ => (),
+        }
+        Value::from(res)
+    }
+
+    /// Add the keywords matching an attribute constraint to its schema
+    fn constraint(constraint: &CibouletteAttributeConstraint, schema: &mut Map<String, Value>) {
+        let (min, max): (Option<(&str, Value)>, Option<(&str, Value)>) = match constraint {
+            CibouletteAttributeConstraint::Length { min, max } => (
+                min.map(|x| ("minLength", Value::from(x))),
+                max.map(|x| ("maxLength", Value::from(x))),
+            ),
+            CibouletteAttributeConstraint::ArrayLength { min, max } => (
+                min.map(|x| ("minItems", Value::from(x))),
+                max.map(|x| ("maxItems", Value::from(x))),
+            ),
+            CibouletteAttributeConstraint::Range { min, max } => (
+                min.map(|x| ("minimum", Self::bound(x, true))),
+                max.map(|x| ("maximum", Self::bound(x, false))),
+            ),
+            CibouletteAttributeConstraint::Pattern(pattern) => {
+                (Some(("pattern", Value::from(pattern.as_str()))), None)
+            }
+            CibouletteAttributeConstraint::OneOf(values) => (
+                Some((
+                    "enum",
+                    values.iter().map(|x| Value::from(x.as_str())).collect(),
+                )),
+                None,
+            ),
+            CibouletteAttributeConstraint::Custom(_) => (None, None),
+        };
+        for (keyword, value) in min.into_iter().chain(max) {
+            schema.insert(keyword.to_string(), value);
+        }
+    }
+
+    /// Describe the attributes of a type, as sent by the clients for an intention or,
+    /// when reading, as sent back to them
+    pub(crate) fn attributes(
+        type_: &CibouletteResourceType,
+        intention: &CibouletteIntention,
+    ) -> Value {
+        let mut properties: Map<String, Value> = Map::new();
+        let mut required: Vec<Value> = Vec::new();
+
+        for (name, value) in type_.schema().properties().iter() {
+            let policy = type_.attribute_policy(name.as_str());
+            let visible = match intention {
+                CibouletteIntention::Read => policy.is_readable(),
+                intention => policy.is_writable(intention),
+            };
+            if !visible {
+                continue;
+            }
+            let wire_name = type_.member_to_wire(name.as_str());
+            let mut schema = match Self::value(value) {
+                Value::Object(schema) => schema,
+                _ => Map::new(),
+            };
+            for constraint in type_.constraints().get(name).into_iter().flatten() {
+                Self::constraint(constraint, &mut schema);
+            }
+            // An optional attribute restricted to some values may still be `null`
+            if let (Some(Value::Array(types)), Some(Value::Array(values))) =
+                (schema.get("type").cloned(), schema.get_mut("enum"))
+            {
+                if types.contains(&Value::from("null")) {
+                    values.push(Value::Null);
+                }
+            }
+            if matches!(intention, CibouletteIntention::Create)
+                && type_.mandatory_attributes().contains(name)
+            {
+                required.push(Value::from(wire_name));
+            }
+            properties.insert(wire_name.to_string(), Value::Object(schema));
+        }
+        let mut res = json!({
+            "type": "object",
+            "properties": properties,
+        });
+        if !required.is_empty() {
+            res["required"] = Value::Array(required);
+        }
+        res
+    }
+
+    /// Describe the id of a type.
+    ///
+    /// The ids are always strings, the parts of a compound id being joined
+    pub(crate) fn id(ids: &CibouletteIdTypeSelector) -> Value {
+        match &**ids {
+            CibouletteSelector::Single(CibouletteIdType::Uuid(_)) => {
+                json!({ "type": "string", "format": "uuid" })
+            }
+            CibouletteSelector::Single(CibouletteIdType::Number(_)) => {
+                json!({ "type": "string", "pattern": "^[0-9]+$" })
+            }
+            _ => json!({ "type": "string" }),
+        }
+    }
+
+    /// Describe the identifier of a resource
+    pub(crate) fn identifier(type_: &CibouletteResourceType) -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "type": { "const": type_.public_name().as_str() },
+                "id": Self::id(type_.ids()),
+                "meta": { "type": "object" },
+            },
+            "required": ["type", "id"],
+        })
+    }
+
+    /// Describe the resource linkage of a relationship
+    pub(crate) fn linkage(
+        &self,
+        related_type: &CibouletteResourceType,
+        option: &CibouletteRelationshipOption,
+    ) -> Value {
+        let identifier = self.reference(&format!("{}-identifier", related_type.public_name()));
+        match is_to_many(option) {
+            true => json!({ "type": "array", "items": identifier }),
+            false => json!({ "oneOf": [identifier, { "type": "null" }] }),
+        }
+    }
+
+    /// Describe the relationships of a type, as sent by the clients for an intention or,
+    /// when reading, as sent back to them
+    pub(crate) fn relationships(
+        &self,
+        type_: &CibouletteResourceType,
+        intention: &CibouletteIntention,
+    ) -> Value {
+        let mut properties: Map<String, Value> = Map::new();
+
+        for alias in type_.relationships().keys() {
+            if matches!(intention, CibouletteIntention::Read)
+                && !type_.relationship_capabilities(alias).readable()
+            {
+                continue;
+            }
+            let details = match type_.get_relationship_details(self.store, alias) {
+                Ok(details) => details,
+                Err(_) => continue,
+            };
+            let mut relationship = json!({
+                "type": "object",
+                "properties": {
+                    "data": self.linkage(details.related_type(), details.relation_option()),
+                    "meta": { "type": "object" },
+                },
+            });
+            match intention {
+                CibouletteIntention::Read => {
+                    relationship["properties"]["links"] = json!({ "type": "object" })
+                }
+                _ => relationship["required"] = json!(["data"]),
+            }
+            properties.insert(
+                type_.member_to_wire(alias.as_str()).to_string(),
+                relationship,
+            );
+        }
+        json!({
+            "type": "object",
+            "properties": properties,
+        })
+    }
+
+    /// Describe a resource object, as sent by the clients for an intention or,
+    /// when reading, as sent back to them
+    pub(crate) fn resource(
+        &self,
+        type_: &CibouletteResourceType,
+        intention: &CibouletteIntention,
+    ) -> Value {
+        let mut res = json!({
+            "type": "object",
+            "properties": {
+                "type": { "const": type_.public_name().as_str() },
+                "id": Self::id(type_.ids()),
+                "attributes": Self::attributes(type_, intention),
+                "relationships": self.relationships(type_, intention),
+                "meta": { "type": "object" },
+            },
+        });
+        let required = match (intention, type_.capabilities().client_ids()) {
+            (CibouletteIntention::Create, CibouletteClientIdPolicy::Forbidden) => {
+                if let Some(properties) = res["properties"].as_object_mut() {
+                    properties.remove("id");
+                }
+                json!(["type"])
+            }
+            (CibouletteIntention::Create, CibouletteClientIdPolicy::Allowed) => json!(["type"]),
+            _ => json!(["type", "id"]),
+        };
+        if matches!(intention, CibouletteIntention::Read) {
+            res["properties"]["links"] = json!({ "type": "object" });
+        }
+        res["required"] = required;
+        res
+    }
+
+    /// Describe a document whose primary data is described by a named schema
+    pub(crate) fn document(&self, data: Value, with_included: bool) -> Value {
+        let mut res = json!({
+            "type": "object",
+            "properties": {
+                "data": data,
+                "meta": { "type": "object" },
+                "links": { "type": "object" },
+                "jsonapi": { "type": "object" },
+            },
+            "required": ["data"],
+        });
+        if with_included {
+            let included: Vec<Value> = self
+                .types()
+                .map(|type_| self.reference(type_.public_name()))
+                .collect();
+            res["properties"]["included"] = json!({
+                "type": "array",
+                "items": { "oneOf": included },
+            });
+        }
+        res
+    }
+
+    /// Describe an error document
+    pub(crate) fn errors() -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "errors": {
+                    "type": "array",
+                    "items": {
+                        "type": "object",
+                        "properties": {
+                            "id": { "type": "string" },
+                            "status": { "type": "string" },
+                            "code": { "type": "string" },
+                            "title": { "type": "string" },
+                            "detail": { "type": "string" },
+                            "source": {
+                                "type": "object",
+                                "properties": {
+                                    "pointer": { "type": "string" },
+                                    "parameter": { "type": "string" },
+                                    "header": { "type": "string" },
+                                },
+                            },
+                            "links": { "type": "object" },
+                            "meta": { "type": "object" },
+                        },
+                    },
+                },
+                "meta": { "type": "object" },
+            },
+            "required": ["errors"],
+        })
+    }
+
+    /// Iterate over the types of the store, ordered by name
+    pub(crate) fn types(&self) -> impl Iterator<Item = &'a Arc<CibouletteResourceType>> {
+        let store = self.store;
+        store
+            .map()
+            .values()
+            .filter_map(move |index| store.graph().node_weight(*index))
+    }
+
+    /// Build the named schemas of every type
+    pub(crate) fn definitions(&self) -> Map<String, Value> {
+        let mut res: Map<String, Value> = Map::new();
+
+        for type_ in self.types() {
+            let name = type_.public_name();
+            res.insert(
+                name.to_string(),
+                self.resource(type_, &CibouletteIntention::Read),
+            );
+            res.insert(format!("{}-identifier", name), Self::identifier(type_));
+            if type_.capabilities().creatable() {
+                res.insert(
+                    format!("{}-create", name),
+                    self.resource(type_, &CibouletteIntention::Create),
+                );
+            }
+            if type_.capabilities().updatable() {
+                res.insert(
+                    format!("{}-update", name),
+                    self.resource(type_, &CibouletteIntention::Update),
+                );
+            }
+        }
+        res
+    }
+
+    /// List the relationship paths that can be included from a type.
+    ///
+    /// A path never goes through the same relationship twice and is at most
+    /// [MAX_INCLUDE_DEPTH](MAX_INCLUDE_DEPTH) relationships long
+    pub(crate) fn includable_paths(&self, type_: &Arc<CibouletteResourceType>) -> Vec<String> {
+        let mut res: Vec<String> = Vec::new();
+        let mut visited: Vec<(ArcStr, ArcStr)> = Vec::new();
+
+        self.includable_paths_from(type_, "", &mut visited, &mut res);
+        res
+    }
+
+    fn includable_paths_from(
+        &self,
+        type_: &Arc<CibouletteResourceType>,
+        prefix: &str,
+        visited: &mut Vec<(ArcStr, ArcStr)>,
+        res: &mut Vec<String>,
+    ) {
+        if visited.len() >= MAX_INCLUDE_DEPTH {
+            return;
+        }
+        for alias in type_.relationships().keys() {
+            if !type_.relationship_capabilities(alias).readable() {
+                continue;
+            }
+            let related_type = match type_.get_relationship(self.store, alias) {
+                Ok(related_type) => related_type,
+                Err(_) => continue,
+            };
+            let edge = (type_.name().clone(), alias.clone());
+            if visited.contains(&edge) {
+                continue;
+            }
+            let path = format!("{}{}", prefix, type_.member_to_wire(alias.as_str()));
+            res.push(path.clone());
+            visited.push(edge);
+            self.includable_paths_from(&related_type, &format!("{}.", path), visited, res);
+            visited.pop();
+        }
+    }
+
+    /// List the fields the resources of a type can be sorted by, their own attributes
+    /// and the attributes of their to-one relationships
+    pub(crate) fn sortable_fields(&self, type_: &CibouletteResourceType) -> Vec<String> {
+        let readable_attributes = |type_: &CibouletteResourceType| -> Vec<String> {
+            type_
+                .schema()
+                .properties()
+                .keys()
+                .filter(|name| type_.attribute_policy(name.as_str()).is_readable())
+                .map(|name| type_.member_to_wire(name.as_str()).to_string())
+                .collect()
+        };
+        let mut res = readable_attributes(type_);
+
+        for alias in type_.relationships().keys() {
+            let details = match type_.get_relationship_details(self.store, alias) {
+                Ok(details) => details,
+                Err(_) => continue,
+            };
+            if is_to_many(details.relation_option()) {
+                continue;
+            }
+            let alias = type_.member_to_wire(alias.as_str());
+            for field in readable_attributes(details.related_type()) {
+                res.push(format!("{}.{}", alias, field));
+            }
+        }
+        res
+    }
+}
@@ -0,0 +1,414 @@
+use super::*;
+
+/// The version of the `OpenAPI` specification the generated documents conform to
+const OPENAPI_VERSION: &str = "3.1.0";
+
+/// Prefix of the references to the schemas of an `OpenAPI` document
+const SCHEMAS_PREFIX: &str = "#/components/schemas/";
+
+/// Reference a reusable component of an `OpenAPI` document
+fn component(kind: &str, name: &str) -> Value {
+    json!({ "$ref": format!("#/components/{}/{}", kind, name) })
+}
+
+/// Wrap a schema in a `JSON:API` content
+fn content(schema: Value) -> Value {
+    json!({ CIBOULETTE_MEDIA_TYPE: { "schema": schema } })
+}
+
+/// Describe a successful response
+fn response(description: &str, schema: Option<Value>) -> Value {
+    let mut res = json!({ "description": description });
+    if let Some(schema) = schema {
+        res["content"] = content(schema);
+    }
+    res
+}
+
+/// Describe the responses of an operation, the errors included
+fn responses(status: &str, response: Value) -> Value {
+    let mut res = Map::new();
+    res.insert(status.to_string(), response);
+    res.insert("default".to_string(), component("responses", "error"));
+    Value::Object(res)
+}
+
+/// Describe an operation
+fn operation(
+    operation_id: String,
+    tag: &str,
+    parameters: Vec<Value>,
+    request_body: Option<Value>,
+    responses: Value,
+) -> Value {
+    let mut res = json!({
+        "operationId": operation_id,
+        "tags": [tag],
+        "responses": responses,
+    });
+    if !parameters.is_empty() {
+        res["parameters"] = Value::Array(parameters);
+    }
+    if let Some(schema) = request_body {
+        res["requestBody"] = json!({
+            "required": true,
+            "content": content(schema),
+        });
+    }
+    res
+}
+
+/// Describe a query parameter whose value is a comma-separated list of values
+fn list_parameter(name: &str, description: &str, values: Vec<String>) -> Value {
+    json!({
+        "name": name,
+        "in": "query",
+        "description": description,
+        "style": "form",
+        "explode": false,
+        "schema": {
+            "type": "array",
+            "items": { "type": "string", "enum": values },
+        },
+    })
+}
+
+/// Describe a query parameter family (i.e. `page[size]`)
+fn family_parameter(name: &str, description: &str, properties: Map<String, Value>) -> Value {
+    let mut schema = json!({ "type": "object" });
+    match properties.is_empty() {
+        true => schema["additionalProperties"] = json!({ "type": "string" }),
+        false => schema["properties"] = Value::Object(properties),
+    }
+    json!({
+        "name": name,
+        "in": "query",
+        "description": description,
+        "style": "deepObject",
+        "explode": true,
+        "schema": schema,
+    })
+}
+
+/// Describe a custom query parameter
+fn custom_parameter(parameter: &CibouletteCustomQueryParameter) -> Value {
+    json!({
+        "name": parameter.name().as_str(),
+        "in": "query",
+        "schema": { "type": "string" },
+    })
+}
+
+impl CibouletteStore {
+    /// ## Describe the API served from this store as an `OpenAPI` 3.1 document
+    ///
+    /// Every type gets its collection, resource, related resources and relationships
+    /// paths, restricted to the operations its capabilities allow.
+    pub fn openapi(&self, title: &str, version: &str) -> Value {
+        let generator = CibouletteSchemaGenerator::new(self, SCHEMAS_PREFIX);
+        let mut schemas = generator.definitions();
+        let mut parameters = Map::new();
+        let mut paths = Map::new();
+        let mut tags: Vec<Value> = Vec::new();
+
+        schemas.insert("errors".to_string(), CibouletteSchemaGenerator::errors());
+        parameters.insert(
+            "id".to_string(),
+            json!({
+                "name": "id",
+                "in": "path",
+                "required": true,
+                "schema": { "type": "string" },
+            }),
+        );
+        parameters.insert(
+            "fields".to_string(),
+            family_parameter(
+                "fields",
+                "The fields to include in the response, by type",
+                generator
+                    .types()
+                    .map(|type_| (type_.public_name().to_string(), json!({ "type": "string" })))
+                    .collect(),
+            ),
+        );
+        parameters.insert(
+            "page".to_string(),
+            family_parameter(
+                "page",
+                "The pagination of the primary data",
+                ["number", "size", "offset", "limit", "cursor"]
+                    .iter()
+                    .map(|x| (x.to_string(), json!({ "type": "string" })))
+                    .collect(),
+            ),
+        );
+        parameters.insert(
+            "filter".to_string(),
+            family_parameter("filter", "The filtering of the primary data", Map::new()),
+        );
+        for parameter in self.query_parameters().values() {
+            parameters.insert(parameter.name().to_string(), custom_parameter(parameter));
+        }
+
+        for type_ in generator.types() {
+            let name = type_.public_name().as_str();
+            let capabilities = type_.capabilities();
+            let mut read_parameters: Vec<Value> = vec![
+                component("parameters", "fields"),
+                component("parameters", "page"),
+                component("parameters", "filter"),
+            ];
+            let includable_paths = generator.includable_paths(type_);
+            if !includable_paths.is_empty() {
+                parameters.insert(
+                    format!("{}-include", name),
+                    list_parameter(
+                        "include",
+                        "The related resources to include in the response",
+                        includable_paths,
+                    ),
+                );
+                read_parameters.push(component("parameters", &format!("{}-include", name)));
+            }
+            let sortable_fields: Vec<String> = generator
+                .sortable_fields(type_)
+                .into_iter()
+                .flat_map(|field| vec![format!("-{}", field), field])
+                .collect();
+            if !sortable_fields.is_empty() {
+                parameters.insert(
+                    format!("{}-sort", name),
+                    list_parameter("sort", "The sorting of the primary data", sortable_fields),
+                );
+                read_parameters.push(component("parameters", &format!("{}-sort", name)));
+            }
+            for parameter in self.query_parameters().keys() {
+                read_parameters.push(component("parameters", parameter));
+            }
+            for parameter in type_.query_parameters().values() {
+                read_parameters.push(custom_parameter(parameter));
+            }
+            let single_document = generator.document(generator.reference(name), true);
+            let collection_document = generator.document(
+                json!({ "type": "array", "items": generator.reference(name) }),
+                true,
+            );
+
+            // The collection
+            let mut collection = Map::new();
+            collection.insert(
+                "get".to_string(),
+                operation(
+                    format!("list-{}", name),
+                    name,
+                    read_parameters.clone(),
+                    None,
+                    responses("200", response("The resources", Some(collection_document))),
+                ),
+            );
+            if capabilities.creatable() {
+                collection.insert(
+                    "post".to_string(),
+                    operation(
+                        format!("create-{}", name),
+                        name,
+                        Vec::new(),
+                        Some(
+                            generator
+                                .document(generator.reference(&format!("{}-create", name)), false),
+                        ),
+                        responses(
+                            "201",
+                            response("The created resource", Some(single_document.clone())),
+                        ),
+                    ),
+                );
+            }
+            paths.insert(format!("/{}", name), Value::Object(collection));
+
+            // The resource
+            let mut resource = Map::new();
+            resource.insert(
+                "parameters".to_string(),
+                json!([component("parameters", "id")]),
+            );
+            resource.insert(
+                "get".to_string(),
+                operation(
+                    format!("read-{}", name),
+                    name,
+                    read_parameters.clone(),
+                    None,
+                    responses(
+                        "200",
+                        response("The resource", Some(single_document.clone())),
+                    ),
+                ),
+            );
+            if capabilities.updatable() {
+                resource.insert(
+                    "patch".to_string(),
+                    operation(
+                        format!("update-{}", name),
+                        name,
+                        Vec::new(),
+                        Some(
+                            generator
+                                .document(generator.reference(&format!("{}-update", name)), false),
+                        ),
+                        responses(
+                            "200",
+                            response("The updated resource", Some(single_document)),
+                        ),
+                    ),
+                );
+            }
+            if capabilities.deletable() {
+                resource.insert(
+                    "delete".to_string(),
+                    operation(
+                        format!("delete-{}", name),
+                        name,
+                        Vec::new(),
+                        None,
+                        responses("204", response("The resource was deleted", None)),
+                    ),
+                );
+            }
+            paths.insert(format!("/{}/{{id}}", name), Value::Object(resource));
+
+            // The relationships
+            for alias in type_.relationships().keys() {
+                let relationship_capabilities = type_.relationship_capabilities(alias);
+                if !relationship_capabilities.readable() {
+                    continue;
+                }
+                let details = match type_.get_relationship_details(self, alias) {
+                    Ok(details) => details,
+                    Err(_) => continue,
+                };
+                let related_name = details.related_type().public_name().as_str();
+                let wire_alias = type_.member_to_wire(alias.as_str());
+                let to_many = is_to_many(details.relation_option());
+                let related_data = match to_many {
+                    true => json!({ "type": "array", "items": generator.reference(related_name) }),
+                    false => {
+                        json!({ "oneOf": [generator.reference(related_name), { "type": "null" }] })
+                    }
+                };
+                let linkage_document = generator.document(
+                    generator.linkage(details.related_type(), details.relation_option()),
+                    false,
+                );
+
+                let mut related = Map::new();
+                related.insert(
+                    "parameters".to_string(),
+                    json!([component("parameters", "id")]),
+                );
+                related.insert(
+                    "get".to_string(),
+                    operation(
+                        format!("read-{}-{}", name, wire_alias),
+                        name,
+                        vec![
+                            component("parameters", "fields"),
+                            component("parameters", "page"),
+                        ],
+                        None,
+                        responses(
+                            "200",
+                            response(
+                                "The related resources",
+                                Some(generator.document(related_data, true)),
+                            ),
+                        ),
+                    ),
+                );
+                paths.insert(
+                    format!("/{}/{{id}}/{}", name, wire_alias),
+                    Value::Object(related),
+                );
+
+                let mut relationship = Map::new();
+                relationship.insert(
+                    "parameters".to_string(),
+                    json!([component("parameters", "id")]),
+                );
+                relationship.insert(
+                    "get".to_string(),
+                    operation(
+                        format!("read-{}-relationships-{}", name, wire_alias),
+                        name,
+                        Vec::new(),
+                        None,
+                        responses(
+                            "200",
+                            response("The relationship linkage", Some(linkage_document.clone())),
+                        ),
+                    ),
+                );
+                if relationship_capabilities.mutable() {
+                    relationship.insert(
+                        "patch".to_string(),
+                        operation(
+                            format!("update-{}-relationships-{}", name, wire_alias),
+                            name,
+                            Vec::new(),
+                            Some(linkage_document.clone()),
+                            responses(
+                                "200",
+                                response(
+                                    "The updated relationship linkage",
+                                    Some(linkage_document),
+                                ),
+                            ),
+                        ),
+                    );
+                    if to_many {
+                        relationship.insert(
+                            "delete".to_string(),
+                            operation(
+                                format!("delete-{}-relationships-{}", name, wire_alias),
+                                name,
+                                Vec::new(),
+                                None,
+                                responses(
+                                    "204",
+                                    response("The relationship linkage was removed", None),
+                                ),
+                            ),
+                        );
+                    }
+                }
+                paths.insert(
+                    format!("/{}/{{id}}/relationships/{}", name, wire_alias),
+                    Value::Object(relationship),
+                );
+            }
+            tags.push(json!({ "name": name }));
+        }
+
+        let mut res = json!({
+            "openapi": OPENAPI_VERSION,
+            "info": {
+                "title": title,
+                "version": version,
+            },
+            "tags": tags,
+            "paths": paths,
+            "components": {
+                "schemas": schemas,
+                "parameters": parameters,
+                "responses": {
+                    "error": response("An error", Some(generator.reference("errors"))),
+                },
+            },
+        });
+        if let Some(base_url) = self.config().base_url() {
+            res["servers"] = json!([{ "url": base_url }]);
+        }
+        res
+    }
+}
@@ -0,0 +1,118 @@
+use super::*;
+use std::any::Any;
+
+/// ## Typed value of an extension member
+pub type CibouletteExtensionValue = Arc<dyn Any + Send + Sync>;
+
+/// ## A `JSON:API` [extension](https://jsonapi.org/format/1.1/#extensions)
+///
+/// Implement this trait to declare an extension and the members it adds to the
+/// documents. The extension must be registered in the [store builder](CibouletteStoreBuilder)
+/// and negotiated by the client through the `ext` media type parameter for its
+/// members to be accepted.
+pub trait CibouletteExtension: std::fmt::Debug + Send + Sync {
+    /// The URI identifying the extension. It must be unique in a store
+    fn uri(&self) -> &str;
+
+    /// The namespace prefixing the members of the extension. It must be unique in a store
+    fn namespace(&self) -> &str;
+
+    /// The members (without namespace) the extension adds to the top-level document
+    fn document_members(&self) -> &[&str] {
+        &[]
+    }
+
+    /// The members (without namespace) the extension adds to the resource objects
+    fn resource_members(&self) -> &[&str] {
+        &[]
+    }
+
+    /// Deserialize the value of a member (without namespace) of the extension.
+    ///
+    /// By default, the raw `JSON` value is kept
+    fn deserialize_member(
+        &self,
+        member: &str,
+        value: &Value,
+    ) -> Result<CibouletteExtensionValue, CibouletteError> {
+        let _ = member;
+        Ok(Arc::new(value.clone()))
+    }
+}
+
+/// ## Where an extension member was found
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CibouletteExtensionLevel {
+    /// In the top-level document
+    Document,
+    /// In a resource object
+    Resource,
+}
+
+/// ## Typed values of the extension members of an object
+///
+/// The values are mapped by their full member name (`namespace:member`)
+#[derive(Debug, Clone, Default, Getters)]
+#[getset(get = "pub")]
+pub struct CibouletteExtensionData {
+    values: BTreeMap<ArcStr, CibouletteExtensionValue>,
+}
+
+impl CibouletteExtensionData {
+    /// Get the typed value of an extension member, if present and of the expected type
+    pub fn get<T: Any + Send + Sync>(&self, member: &str) -> Option<&T> {
+        self.values.get(member).and_then(|x| x.downcast_ref::<T>())
+    }
+
+    /// Check if an extension member is present
+    pub fn contains(&self, member: &str) -> bool {
+        self.values.contains_key(member)
+    }
+
+    /// Check if there are no extension members
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Parse the extension members of an object.
+    ///
+    /// Members belonging to a registered extension must be part of the negotiated
+    /// extensions and declared by the extension for that level. The members of unregistered
+    /// namespaces are left untyped.
+    pub(crate) fn parse(
+        bag: &CibouletteStore,
+        active: &[Arc<dyn CibouletteExtension>],
+        level: CibouletteExtensionLevel,
+        members: &BTreeMap<Cow<'_, str>, Value>,
+    ) -> Result<Self, CibouletteError> {
+        let mut res = CibouletteExtensionData::default();
+
+        for (name, value) in members {
+            let (namespace, member) = match CibouletteMemberKind::new(name.as_ref()) {
+                CibouletteMemberKind::Extension(namespace, member) => (namespace, member),
+                _ => continue,
+            };
+            let extension = match bag.get_extension_by_namespace(namespace) {
+                Some(extension) => extension,
+                None => continue,
+            };
+            if !active.iter().any(|x| x.uri() == extension.uri()) {
+                return Err(CibouletteError::ExtensionNotNegotiated(
+                    extension.uri().to_string(),
+                ));
+            }
+            let declared = match level {
+                CibouletteExtensionLevel::Document => extension.document_members(),
+                CibouletteExtensionLevel::Resource => extension.resource_members(),
+            };
+            if !declared.contains(&member) {
+                return Err(CibouletteError::UnknownExtensionMember(name.to_string()));
+            }
+            res.values.insert(
+                ArcStr::from(name.as_ref()),
+                extension.deserialize_member(member, value)?,
+            );
+        }
+        Ok(res)
+    }
+}
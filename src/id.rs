@@ -23,6 +23,8 @@ pub enum CibouletteId<'request> {
     Uuid(Uuid),
     /// Text id
     Text(Cow<'request, str>),
+    /// Id parsed by a custom parser
+    Custom(CibouletteCustomId<'request>),
 }
 
 /// ## Resource id type selector
@@ -35,21 +37,30 @@ impl<'request> CibouletteIdSelector<'request> {
     pub fn build_id(
         id_selector: &CibouletteIdTypeSelector,
         id_str: Cow<'request, str>,
+    ) -> Result<CibouletteIdSelector<'request>, CibouletteError> {
+        Self::build(id_selector, id_str, false)
+    }
+
+    /// Build an id provided by the backend.
+    ///
+    /// The custom ids are taken in their canonical form, without going through their parser.
+    /// The other id types are parsed like [build_id](CibouletteIdSelector::build_id) does.
+    pub fn build_canonical_id(
+        id_selector: &CibouletteIdTypeSelector,
+        id_str: Cow<'request, str>,
+    ) -> Result<CibouletteIdSelector<'request>, CibouletteError> {
+        Self::build(id_selector, id_str, true)
+    }
+
+    fn build(
+        id_selector: &CibouletteIdTypeSelector,
+        id_str: Cow<'request, str>,
+        canonical: bool,
     ) -> Result<CibouletteIdSelector<'request>, CibouletteError> {
         let res = match &**id_selector {
-            CibouletteSelector::Single(x) => {
-                CibouletteIdSelector(CibouletteSelector::Single(match x {
-                    CibouletteIdType::Text(_) => CibouletteId::Text(Cow::Owned(String::from_utf8(
-                        base64::decode_config(id_str.as_ref(), *BASE64_CONFIG)?,
-                    )?)),
-                    CibouletteIdType::Number(_) => {
-                        CibouletteId::Number(u64::from_str(id_str.as_ref())?)
-                    }
-                    CibouletteIdType::Uuid(_) => {
-                        CibouletteId::Uuid(Uuid::from_str(id_str.as_ref())?)
-                    }
-                }))
-            }
+            CibouletteSelector::Single(x) => CibouletteIdSelector(CibouletteSelector::Single(
+                Self::build_single_id(x, id_str, canonical)?,
+            )),
             CibouletteSelector::Multi(x) => {
                 let mut res = Vec::with_capacity(2);
 
@@ -57,19 +68,36 @@ impl<'request> CibouletteIdSelector<'request> {
                     let id_type = x
                         .get(i)
                         .ok_or_else(|| CibouletteError::WrongIdNumber(i, x.len()))?;
-                    res.push(match id_type {
-                        CibouletteIdType::Text(_) => CibouletteId::Text(Cow::Owned(
-                            String::from_utf8(base64::decode_config(id, *BASE64_CONFIG)?)?,
-                        )),
-                        CibouletteIdType::Number(_) => CibouletteId::Number(u64::from_str(id)?),
-                        CibouletteIdType::Uuid(_) => CibouletteId::Uuid(Uuid::from_str(id)?),
-                    });
+                    res.push(Self::build_single_id(
+                        id_type,
+                        Cow::Owned(id.to_string()),
+                        canonical,
+                    )?);
                 }
                 CibouletteIdSelector(CibouletteSelector::Multi(res))
             }
         };
         Ok(res)
     }
+
+    /// Parse a single id using its id type
+    fn build_single_id(
+        id_type: &CibouletteIdType,
+        id: Cow<'request, str>,
+        canonical: bool,
+    ) -> Result<CibouletteId<'request>, CibouletteError> {
+        Ok(match id_type {
+            CibouletteIdType::Text(_) => CibouletteId::Text(Cow::Owned(String::from_utf8(
+                base64::decode_config(id.as_ref(), *BASE64_CONFIG)?,
+            )?)),
+            CibouletteIdType::Number(_) => CibouletteId::Number(u64::from_str(id.as_ref())?),
+            CibouletteIdType::Uuid(_) => CibouletteId::Uuid(Uuid::from_str(id.as_ref())?),
+            CibouletteIdType::Custom(_, parser) => CibouletteId::Custom(match canonical {
+                true => parser.from_canonical(id),
+                false => parser.parse(id)?,
+            }),
+        })
+    }
 }
 
 impl<'request> std::fmt::Display for CibouletteIdSelector<'request> {
@@ -87,6 +115,7 @@ impl<'request> std::fmt::Display for CibouletteId<'request> {
             CibouletteId::Number(x) => write!(f, "{}", x),
             CibouletteId::Uuid(x) => write!(f, "{}", x),
             CibouletteId::Text(x) => write!(f, "{}", x),
+            CibouletteId::Custom(x) => write!(f, "{}", x),
         }
     }
 }
@@ -138,6 +167,20 @@ pub enum CibouletteIdType {
     Number(ArcStr),
     Text(ArcStr),
     Uuid(ArcStr),
+    /// An id handled by a [custom parser](CibouletteIdParser) registered in the store
+    Custom(ArcStr, CibouletteCustomIdType),
+}
+
+impl CibouletteIdType {
+    /// Get the name of the field holding the id
+    pub fn name(&self) -> &ArcStr {
+        match self {
+            CibouletteIdType::Number(x)
+            | CibouletteIdType::Text(x)
+            | CibouletteIdType::Uuid(x)
+            | CibouletteIdType::Custom(x, _) => x,
+        }
+    }
 }
 
 impl<'request> std::fmt::Display for CibouletteIdType {
@@ -146,6 +189,7 @@ impl<'request> std::fmt::Display for CibouletteIdType {
             CibouletteIdType::Number(x) => write!(f, "{} (number)", x),
             CibouletteIdType::Text(x) => write!(f, "{} (text)", x),
             CibouletteIdType::Uuid(x) => write!(f, "{} (uuid)", x),
+            CibouletteIdType::Custom(x, type_) => write!(f, "{} ({})", x, type_.name()),
         }
     }
 }
@@ -120,11 +120,16 @@
 mod macros;
 mod body;
 mod config;
+mod custom_id;
 mod error_request;
 mod errors;
+mod export;
+mod extension;
 mod id;
 mod intention;
+mod media_type;
 mod member_name;
+mod naming;
 mod path;
 mod query;
 mod request_selector;
@@ -149,7 +154,17 @@ use std::sync::Arc;
 use url::Url;
 use uuid::Uuid;
 
+use body::pointer::{escape_pointer_token, path_to_pointer, path_to_pointer_with};
+
+pub use body::attribute_policy::CibouletteAttributePolicy;
+pub use body::attribute_value::CibouletteAttributeValue;
 pub use body::body_optional_data::CibouletteOptionalData;
+pub use body::capabilities::{
+    CibouletteClientIdPolicy, CibouletteRelationshipCapabilities, CibouletteTypeCapabilities,
+};
+pub use body::constraint::{
+    CibouletteAttributeConstraint, CibouletteAttributeValidator, CibouletteConstraintViolation,
+};
 pub use body::errors_obj::{CibouletteErrorLink, CibouletteErrorObj, CibouletteErrorSource};
 pub use body::link::{
     CibouletteBodyLink, CibouletteBodyPagination, CibouletteLink, CibouletteLinkObj,
@@ -170,14 +185,27 @@ pub use body::resource_obj_selector::{
 };
 pub use body::resource_type::{CibouletteResourceRelationshipDetails, CibouletteResourceType};
 pub use body::resource_type_builder::CibouletteResourceTypeBuilder;
+pub use body::typed_attributes::CibouletteAttributePatch;
+pub use body::unknown_members_policy::CibouletteUnknownMembersPolicy;
+#[cfg(feature = "derive")]
+pub use ciboulette_derive::{CibouletteAttributes, CibouletteResource};
+pub use custom_id::{CibouletteCustomId, CibouletteCustomIdType, CibouletteIdParser};
+pub use export::json_schema::CibouletteJsonSchemas;
+pub use extension::{
+    CibouletteExtension, CibouletteExtensionData, CibouletteExtensionLevel,
+    CibouletteExtensionValue,
+};
 pub use id::{CibouletteId, CibouletteIdSelector, CibouletteIdType, CibouletteIdTypeSelector};
 pub use intention::CibouletteIntention;
+pub use media_type::{CibouletteMediaType, CIBOULETTE_MEDIA_TYPE};
 pub use responses::request::CibouletteResponse;
 pub use responses::status::CibouletteResponseStatus;
 
 pub use query::{
-    CiboulettePageType, CibouletteQueryParameters, CibouletteQueryParametersBuilder,
-    CibouletteSortingDirection, CibouletteSortingElement,
+    is_custom_query_parameter_name, CibouletteCustomQueryParameter, CibouletteIncludeParameters,
+    CiboulettePageType, CibouletteQueryFamily, CibouletteQueryParameterValidator,
+    CibouletteQueryParameters, CibouletteQueryParametersBuilder, CibouletteQueryParametersPolicy,
+    CibouletteSortingDirection, CibouletteSortingElement, CibouletteSparseFields,
 };
 pub use request_selector::CibouletteRequestSelector;
 pub use requests::create::CibouletteCreateRequest;
@@ -201,7 +229,10 @@ pub use responses::request_data_builder::CibouletteResponseDataBuilder;
 pub use config::CibouletteConfig;
 pub use error_request::CibouletteErrorRequest;
 pub use errors::{CibouletteClashDirection, CibouletteError, CiboulettePathType};
-pub use member_name::check_member_name;
+pub use member_name::{
+    check_member_name, check_member_name_version, CibouletteMemberKind, CibouletteSpecVersion,
+};
+pub use naming::CibouletteNamingPolicy;
 pub use requests::request::{
     CibouletteRequest, CibouletteRequestBuilder, CibouletteRequestCommons,
 };
@@ -210,10 +241,13 @@ pub use responses::type_::{CibouletteResponseQuantity, CibouletteResponseRequire
 pub use path::path_container::{CiboulettePath, CiboulettePathBuilder};
 pub use selector::{CibouletteSelector, CibouletteSelectorError, CibouletteSelectorIterator};
 pub use store::{
+    CibouletteAttributeDefinition, CibouletteAttributeModel, CibouletteIdDefinition,
+    CibouletteIdModel, CibouletteLint, CibouletteLintReport, CibouletteNumberFormat,
+    CibouletteRelationshipDefinition, CibouletteRelationshipEndDefinition,
     CibouletteRelationshipManyToManyOption, CibouletteRelationshipManyToManyOptionBuilder,
     CibouletteRelationshipOneToManyOption, CibouletteRelationshipOneToManyOptionBuilder,
-    CibouletteRelationshipOption, CibouletteRelationshipOptionBuilder, CibouletteStore,
-    CibouletteStoreBuilder,
+    CibouletteRelationshipOption, CibouletteRelationshipOptionBuilder, CibouletteResourceModel,
+    CibouletteStore, CibouletteStoreBuilder, CibouletteStoreDefinition, CibouletteTypeDefinition,
 };
 
 #[cfg(test)]
@@ -0,0 +1,107 @@
+use super::*;
+
+/// The `JSON:API` media type
+pub const CIBOULETTE_MEDIA_TYPE: &str = "application/vnd.api+json";
+
+/// ## Parsed `JSON:API` media type
+///
+/// Hold the parameters of the `Content-Type` or `Accept` media type of a request, as described by the
+/// [specification](https://jsonapi.org/format/1.1/#media-type-parameters).
+#[derive(Debug, Clone, Default, Getters)]
+#[getset(get = "pub")]
+pub struct CibouletteMediaType<'request> {
+    /// The URIs of the extensions applied to the request
+    ext: Vec<&'request str>,
+    /// The URIs of the profiles applied to the request
+    profile: Vec<&'request str>,
+}
+
+impl<'request> CibouletteMediaType<'request> {
+    /// Parse a media type, rejecting unknown media types and parameters
+    pub fn parse(media_type: &'request str) -> Result<Self, CibouletteError> {
+        let mut parts = media_type.split(';');
+        let essence = parts.next().unwrap_or_default().trim();
+        if !essence.eq_ignore_ascii_case(CIBOULETTE_MEDIA_TYPE) {
+            return Err(CibouletteError::InvalidMediaType(media_type.to_string()));
+        }
+        let mut res = CibouletteMediaType::default();
+        for param in parts {
+            let param = param.trim();
+            if param.is_empty() {
+                continue;
+            }
+            let (name, value) = match param.find('=') {
+                Some(idx) => (param[..idx].trim(), param[idx + 1..].trim()),
+                None => return Err(CibouletteError::InvalidMediaType(media_type.to_string())),
+            };
+            let value = value
+                .strip_prefix('"')
+                .and_then(|x| x.strip_suffix('"'))
+                .unwrap_or(value);
+            match name {
+                "ext" => res.ext.extend(value.split_whitespace()),
+                "profile" => res.profile.extend(value.split_whitespace()),
+                _ => return Err(CibouletteError::InvalidMediaType(media_type.to_string())),
+            }
+        }
+        Ok(res)
+    }
+
+    /// Parse an `Accept` header, keeping the first valid `JSON:API` media type it lists.
+    ///
+    /// Other media types are ignored. If none is valid, the default media type is used
+    pub fn parse_accept(accept: &'request str) -> Self {
+        accept
+            .split(',')
+            .find_map(|x| CibouletteMediaType::parse(x.trim()).ok())
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plain() {
+        let media_type = CibouletteMediaType::parse("application/vnd.api+json").unwrap();
+        assert_eq!(media_type.ext().is_empty(), true);
+        assert_eq!(media_type.profile().is_empty(), true);
+    }
+
+    #[test]
+    fn parameters() {
+        let media_type = CibouletteMediaType::parse(
+            r#"application/vnd.api+json; ext="https://a.com/ext https://b.com/ext"; profile=https://c.com/profile"#,
+        )
+        .unwrap();
+        assert_eq!(
+            media_type.ext(),
+            &vec!["https://a.com/ext", "https://b.com/ext"]
+        );
+        assert_eq!(media_type.profile(), &vec!["https://c.com/profile"]);
+    }
+
+    #[test]
+    fn unknown_parameter() {
+        let err =
+            CibouletteMediaType::parse("application/vnd.api+json; charset=utf-8").unwrap_err();
+        assert_eq!(matches!(err, CibouletteError::InvalidMediaType(_)), true);
+    }
+
+    #[test]
+    fn accept() {
+        let media_type = CibouletteMediaType::parse_accept(
+            r#"text/html, application/vnd.api+json; charset=utf-8, application/vnd.api+json; profile="https://c.com/profile", */*"#,
+        );
+        assert_eq!(media_type.profile(), &vec!["https://c.com/profile"]);
+        let media_type = CibouletteMediaType::parse_accept("*/*");
+        assert_eq!(media_type.profile().is_empty(), true);
+    }
+
+    #[test]
+    fn wrong_media_type() {
+        let err = CibouletteMediaType::parse("application/json").unwrap_err();
+        assert_eq!(matches!(err, CibouletteError::InvalidMediaType(_)), true);
+    }
+}
@@ -80,10 +80,133 @@ pub fn check_member_name(s: &str) -> bool {
     }
 }
 
+/// ## Version of the `JSON:API` specification
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CibouletteSpecVersion {
+    /// `JSON:API` 1.0
+    V1_0,
+    /// `JSON:API` 1.1
+    V1_1,
+}
+
+impl Default for CibouletteSpecVersion {
+    fn default() -> Self {
+        CibouletteSpecVersion::V1_0
+    }
+}
+
+/// ## Kind of a member, as described by the `JSON:API` 1.1 specification
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CibouletteMemberKind<'a> {
+    /// A member defined by the specification or the implementation
+    Regular,
+    /// An `@`-member, to be ignored by `JSON:API` processors
+    At(&'a str),
+    /// A member namespaced by an extension (`namespace:member`)
+    Extension(&'a str, &'a str),
+}
+
+impl<'a> CibouletteMemberKind<'a> {
+    /// Find the kind of a member from its name, without checking its validity
+    pub fn new(s: &'a str) -> Self {
+        if let Some(name) = s.strip_prefix('@') {
+            return CibouletteMemberKind::At(name);
+        }
+        match s.find(':') {
+            Some(idx) => CibouletteMemberKind::Extension(&s[..idx], &s[idx + 1..]),
+            None => CibouletteMemberKind::Regular,
+        }
+    }
+}
+
+/// Check that an extension namespace only contains ASCII alphanumeric characters
+#[inline]
+pub fn check_extension_namespace(s: &str) -> bool {
+    !s.is_empty() && s.chars().all(|c| c.is_ascii_alphanumeric())
+}
+
+/// Check a member name against the rules of a given version of the specification.
+///
+/// Starting with `JSON:API` 1.1, `@`-members and extension members (`namespace:member`)
+/// are accepted.
+#[inline]
+pub fn check_member_name_version(s: &str, version: CibouletteSpecVersion) -> bool {
+    match version {
+        CibouletteSpecVersion::V1_0 => check_member_name(s),
+        CibouletteSpecVersion::V1_1 => match CibouletteMemberKind::new(s) {
+            CibouletteMemberKind::Regular => check_member_name(s),
+            CibouletteMemberKind::At(name) => check_member_name(name),
+            CibouletteMemberKind::Extension(namespace, name) => {
+                check_extension_namespace(namespace) && check_member_name(name)
+            }
+        },
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn version_1_1() {
+        assert_eq!(
+            check_member_name_version("@context", CibouletteSpecVersion::V1_1),
+            true
+        );
+        assert_eq!(
+            check_member_name_version("version:id", CibouletteSpecVersion::V1_1),
+            true
+        );
+        assert_eq!(
+            check_member_name_version("fields", CibouletteSpecVersion::V1_1),
+            true
+        );
+        assert_eq!(
+            check_member_name_version("@", CibouletteSpecVersion::V1_1),
+            false
+        );
+        assert_eq!(
+            check_member_name_version("ver-sion:id", CibouletteSpecVersion::V1_1),
+            false
+        );
+        assert_eq!(
+            check_member_name_version(":id", CibouletteSpecVersion::V1_1),
+            false
+        );
+        assert_eq!(
+            check_member_name_version("version:id:other", CibouletteSpecVersion::V1_1),
+            false
+        );
+    }
+
+    #[test]
+    fn version_1_0() {
+        assert_eq!(
+            check_member_name_version("@context", CibouletteSpecVersion::V1_0),
+            false
+        );
+        assert_eq!(
+            check_member_name_version("version:id", CibouletteSpecVersion::V1_0),
+            false
+        );
+    }
+
+    #[test]
+    fn member_kind() {
+        assert_eq!(
+            CibouletteMemberKind::new("@context"),
+            CibouletteMemberKind::At("context")
+        );
+        assert_eq!(
+            CibouletteMemberKind::new("version:id"),
+            CibouletteMemberKind::Extension("version", "id")
+        );
+        assert_eq!(
+            CibouletteMemberKind::new("data"),
+            CibouletteMemberKind::Regular
+        );
+    }
+
     #[test]
     fn ok_simple() {
         assert_eq!(check_member_name("fields"), true, "should've match");
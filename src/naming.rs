@@ -0,0 +1,139 @@
+use super::*;
+
+/// ## Case convention applied to member names
+///
+/// The attributes and relationships names of the schemas are converted to this
+/// convention when exchanged with clients.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Ord, PartialOrd)]
+pub enum CibouletteNamingPolicy {
+    /// Member names are exchanged as they are in the schema
+    Preserve,
+    /// `camelCase` member names
+    CamelCase,
+    /// `PascalCase` member names
+    PascalCase,
+    /// `kebab-case` member names
+    KebabCase,
+    /// `snake_case` member names
+    SnakeCase,
+}
+
+impl Default for CibouletteNamingPolicy {
+    fn default() -> Self {
+        CibouletteNamingPolicy::Preserve
+    }
+}
+
+impl CibouletteNamingPolicy {
+    /// Split a member name into its lowercase words.
+    ///
+    /// Words are separated by `_`, `-`, spaces or a lowercase to uppercase transition
+    fn split_words(name: &str) -> Vec<String> {
+        let mut res: Vec<String> = Vec::new();
+        let mut current = String::new();
+        let mut prev_lower = false;
+
+        for c in name.chars() {
+            match c {
+                '_' | '-' | ' ' => {
+                    if !current.is_empty() {
+                        res.push(std::mem::take(&mut current));
+                    }
+                    prev_lower = false;
+                }
+                c if c.is_uppercase() => {
+                    if prev_lower && !current.is_empty() {
+                        res.push(std::mem::take(&mut current));
+                    }
+                    current.extend(c.to_lowercase());
+                    prev_lower = false;
+                }
+                c => {
+                    current.push(c);
+                    prev_lower = c.is_lowercase() || c.is_numeric();
+                }
+            }
+        }
+        if !current.is_empty() {
+            res.push(current);
+        }
+        res
+    }
+
+    /// Uppercase the first letter of a word
+    fn capitalize(word: &str) -> String {
+        let mut chars = word.chars();
+        match chars.next() {
+            Some(first) => first.to_uppercase().chain(chars).collect(),
+            None => String::new(),
+        }
+    }
+
+    /// Convert a member name from the schema to the name exchanged with the clients
+    pub fn convert<'a>(&self, name: &'a str) -> Cow<'a, str> {
+        match self {
+            CibouletteNamingPolicy::Preserve => Cow::Borrowed(name),
+            CibouletteNamingPolicy::CamelCase => {
+                let words = Self::split_words(name);
+                let mut res = String::with_capacity(name.len());
+                for (i, word) in words.iter().enumerate() {
+                    match i {
+                        0 => res.push_str(word),
+                        _ => res.push_str(&Self::capitalize(word)),
+                    }
+                }
+                Cow::Owned(res)
+            }
+            CibouletteNamingPolicy::PascalCase => Cow::Owned(
+                Self::split_words(name)
+                    .iter()
+                    .map(|x| Self::capitalize(x))
+                    .collect(),
+            ),
+            CibouletteNamingPolicy::KebabCase => Cow::Owned(Self::split_words(name).join("-")),
+            CibouletteNamingPolicy::SnakeCase => Cow::Owned(Self::split_words(name).join("_")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn camel_case() {
+        let policy = CibouletteNamingPolicy::CamelCase;
+        assert_eq!(policy.convert("first_name"), "firstName");
+        assert_eq!(policy.convert("last-name"), "lastName");
+        assert_eq!(policy.convert("age"), "age");
+        assert_eq!(policy.convert("favoriteColor"), "favoriteColor");
+    }
+
+    #[test]
+    fn pascal_case() {
+        let policy = CibouletteNamingPolicy::PascalCase;
+        assert_eq!(policy.convert("first_name"), "FirstName");
+        assert_eq!(policy.convert("favoriteColor"), "FavoriteColor");
+    }
+
+    #[test]
+    fn kebab_case() {
+        let policy = CibouletteNamingPolicy::KebabCase;
+        assert_eq!(policy.convert("first_name"), "first-name");
+        assert_eq!(policy.convert("favoriteColor"), "favorite-color");
+        assert_eq!(policy.convert("people_id2"), "people-id2");
+    }
+
+    #[test]
+    fn snake_case() {
+        let policy = CibouletteNamingPolicy::SnakeCase;
+        assert_eq!(policy.convert("first-name"), "first_name");
+        assert_eq!(policy.convert("FavoriteColor"), "favorite_color");
+    }
+
+    #[test]
+    fn preserve() {
+        let policy = CibouletteNamingPolicy::Preserve;
+        assert_eq!(policy.convert("first_Name"), "first_Name");
+    }
+}
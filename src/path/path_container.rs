@@ -115,8 +115,11 @@ impl<'request> CiboulettePathBuilder<'request> {
         ),
         CibouletteError,
     > {
-        let nftype = store.get_type(ftype.as_ref())?;
-        let nstype_rel = nftype.get_relationship_details(store, stype.as_ref())?;
+        let nftype = store.get_public_type(ftype.as_ref())?;
+        let alias = nftype.member_from_wire(stype.as_ref()).ok_or_else(|| {
+            CibouletteError::UnknownRelationship(nftype.name().to_string(), stype.to_string())
+        })?;
+        let nstype_rel = nftype.get_relationship_details(store, alias)?;
         Ok((nftype.clone(), nstype_rel))
     }
 
@@ -127,11 +130,11 @@ impl<'request> CiboulettePathBuilder<'request> {
     ) -> Result<CiboulettePath<'request>, CibouletteError> {
         match self {
             CiboulettePathBuilder::Type(type_) => {
-                let ftype = store.get_type(type_.as_ref())?;
+                let ftype = store.get_public_type(type_.as_ref())?;
                 Ok(CiboulettePath::Type(ftype.clone()))
             }
             CiboulettePathBuilder::TypeId(type_, id) => {
-                let ftype = store.get_type(type_.as_ref())?;
+                let ftype = store.get_public_type(type_.as_ref())?;
                 Ok(CiboulettePath::TypeId(
                     ftype.clone(),
                     CibouletteIdSelector::build_id(ftype.ids(), id)?,
@@ -0,0 +1,111 @@
+use super::*;
+use std::hash::{Hash, Hasher};
+
+/// ## Validator for the value of a custom query parameter
+pub trait CibouletteQueryParameterValidator: std::fmt::Debug + Send + Sync {
+    /// Check the value of the parameter, returning the reason of its rejection if invalid
+    fn validate(&self, value: &str) -> Result<(), String>;
+}
+
+/// ## An implementation-specific query parameter
+///
+/// Parameter families (i.e. `myFilter[...]`) are registered by their base name.
+#[derive(Debug, Clone, Getters)]
+#[getset(get = "pub")]
+pub struct CibouletteCustomQueryParameter {
+    /// The name of the parameter
+    name: ArcStr,
+    /// The validator of the parameter values, if any
+    validator: Option<Arc<dyn CibouletteQueryParameterValidator>>,
+}
+
+impl CibouletteCustomQueryParameter {
+    /// Create a new custom query parameter, checking its name
+    pub fn new(
+        name: &str,
+        validator: Option<Arc<dyn CibouletteQueryParameterValidator>>,
+    ) -> Result<Self, CibouletteError> {
+        if !is_custom_query_parameter_name(name) {
+            return Err(CibouletteError::InvalidMemberName(name.to_string()));
+        }
+        Ok(CibouletteCustomQueryParameter {
+            name: ArcStr::from(name),
+            validator,
+        })
+    }
+
+    /// Validate a value of the parameter, if a validator was provided
+    pub fn validate(&self, name: &str, value: &str) -> Result<(), CibouletteError> {
+        match &self.validator {
+            Some(validator) => validator
+                .validate(value)
+                .map_err(|reason| CibouletteError::InvalidQueryParameter(name.to_string(), reason)),
+            None => Ok(()),
+        }
+    }
+}
+
+impl PartialEq for CibouletteCustomQueryParameter {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+    }
+}
+
+impl Eq for CibouletteCustomQueryParameter {}
+
+impl Hash for CibouletteCustomQueryParameter {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name.hash(state)
+    }
+}
+
+/// ## Policy applied to the query parameters not defined by the specification
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CibouletteQueryParametersPolicy {
+    /// Every unknown parameter is accepted
+    Permissive,
+    /// Unknown parameters are rejected if their name is reserved by the specification
+    /// (lowercase only) or isn't a valid member name
+    Strict,
+    /// Only the registered custom parameters are accepted
+    Registered,
+}
+
+impl Default for CibouletteQueryParametersPolicy {
+    fn default() -> Self {
+        CibouletteQueryParametersPolicy::Permissive
+    }
+}
+
+/// Get the base name of a query parameter (i.e. `myFilter` for `myFilter[name]`)
+#[inline]
+pub(crate) fn query_parameter_base_name(name: &str) -> &str {
+    match name.find('[') {
+        Some(idx) => &name[..idx],
+        None => name,
+    }
+}
+
+/// Check that a query parameter name can be used for an implementation-specific parameter.
+///
+/// Its base name should be a valid member name with at least one character outside of `a-z`
+#[inline]
+pub fn is_custom_query_parameter_name(name: &str) -> bool {
+    let base = query_parameter_base_name(name);
+    check_member_name(base) && base.chars().any(|c| !c.is_ascii_lowercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn custom_names() {
+        assert_eq!(is_custom_query_parameter_name("camelCase"), true);
+        assert_eq!(is_custom_query_parameter_name("with_underscore"), true);
+        assert_eq!(is_custom_query_parameter_name("myFilter[name]"), true);
+        assert_eq!(is_custom_query_parameter_name("lowercase"), false);
+        assert_eq!(is_custom_query_parameter_name("lowercase[Name]"), false);
+        assert_eq!(is_custom_query_parameter_name(""), false);
+    }
+}
@@ -0,0 +1,67 @@
+use super::*;
+
+/// ## Tree of the values of a nested query parameter family
+///
+/// i.e. `filter[author][name]=x&filter[author][age]=y` is stored as
+/// `author -> { name: x, age: y }`
+#[derive(Debug, Clone, Default, Getters, PartialEq)]
+#[getset(get = "pub")]
+pub struct CibouletteQueryFamily<'request> {
+    /// The value set at this level, if any
+    value: Option<Cow<'request, str>>,
+    /// The nested members, mapped by their key
+    members: BTreeMap<Cow<'request, str>, CibouletteQueryFamily<'request>>,
+}
+
+impl<'request> CibouletteQueryFamily<'request> {
+    /// Insert a value at the given path, returning `false` if a value was already set
+    pub fn insert(&mut self, path: Vec<Cow<'request, str>>, value: Cow<'request, str>) -> bool {
+        let mut node = self;
+        for key in path {
+            node = node.members.entry(key).or_default();
+        }
+        match node.value {
+            Some(_) => false,
+            None => {
+                node.value = Some(value);
+                true
+            }
+        }
+    }
+
+    /// Get the node at the given path
+    pub fn get(&self, path: &[&str]) -> Option<&Self> {
+        let mut node = self;
+        for key in path {
+            node = node.members.get(*key)?;
+        }
+        Some(node)
+    }
+
+    /// Check if the tree holds no values
+    pub fn is_empty(&self) -> bool {
+        self.value.is_none() && self.members.is_empty()
+    }
+
+    /// Consume the tree, listing every value with its path
+    pub fn into_leaves(self) -> Vec<(Vec<Cow<'request, str>>, Cow<'request, str>)> {
+        let mut res = Vec::new();
+        self.collect_leaves(&mut Vec::new(), &mut res);
+        res
+    }
+
+    fn collect_leaves(
+        self,
+        path: &mut Vec<Cow<'request, str>>,
+        res: &mut Vec<(Vec<Cow<'request, str>>, Cow<'request, str>)>,
+    ) {
+        if let Some(value) = self.value {
+            res.push((path.clone(), value));
+        }
+        for (key, member) in self.members {
+            path.push(key);
+            member.collect_leaves(path, res);
+            path.pop();
+        }
+    }
+}
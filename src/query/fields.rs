@@ -13,12 +13,18 @@ pub enum CibouletteQueryParametersField<'request> {
     Sparse(Cow<'request, str>),
     /// The `sort` parameter
     Sorting,
+    /// The `sort[<relationship path>]` parameter with the relationship path as argument
+    SortingNested(Cow<'request, str>),
     /// The page[<type>] parameter, parsing the inner type
     Page(CiboulettePageType<'request>),
+    /// The nested `page[<relationship>]...[<type>]` parameter with the keys as argument
+    PageNested(Vec<Cow<'request, str>>),
     /// The simple `filter` parameter
     Filter,
     /// The typed `filter[<type>]` parameter with the type as argument
     FilterTyped(Cow<'request, str>),
+    /// The nested `filter[<relationship>]...[<field>]` parameter with the keys as argument
+    FilterNested(Vec<Cow<'request, str>>),
     /// Any other parameter
     Meta(Cow<'request, str>),
 }
@@ -34,6 +40,31 @@ pub enum CiboulettePageType<'request> {
     Other(Cow<'request, str>),
 }
 
+impl<'request> From<Cow<'request, str>> for CiboulettePageType<'request> {
+    fn from(page_type: Cow<'request, str>) -> Self {
+        match page_type.as_ref() {
+            "limit" => CiboulettePageType::Limit,
+            "size" => CiboulettePageType::Size,
+            "offset" => CiboulettePageType::Offset,
+            "number" => CiboulettePageType::Number,
+            "cursor" => CiboulettePageType::Cursor,
+            _ => CiboulettePageType::Other(page_type),
+        }
+    }
+}
+
+/// Parse the keys of a nested query parameter family, if there is more than one
+#[inline]
+fn parse_nested_keys(value: &str) -> Option<Vec<Cow<'static, str>>> {
+    typed_param::parse_query_param_family(value)
+        .filter(|keys| keys.len() > 1)
+        .map(|keys| {
+            keys.into_iter()
+                .map(|x| Cow::Owned(x.into_owned()))
+                .collect()
+        })
+}
+
 impl CibouletteQueryParametersFieldVisitor {
     #[inline]
     fn parse_str(value: Cow<'_, str>) -> CibouletteQueryParametersField<'_> {
@@ -55,29 +86,23 @@ impl CibouletteQueryParametersFieldVisitor {
                 let type_ = &value[0..type_end_index];
                 match type_ {
                     "page" => {
+                        if let Some(keys) = parse_nested_keys(&value[type_end_index..]) {
+                            return CibouletteQueryParametersField::PageNested(keys);
+                        }
                         let page_type =
                             typed_param::parse_typed_query_param(&value[type_end_index..]) // Parse inner parameter
                                 .unwrap_or_default();
-                        match page_type.as_ref() {
-                            "limit" => {
-                                CibouletteQueryParametersField::Page(CiboulettePageType::Limit)
-                            }
-                            "size" => {
-                                CibouletteQueryParametersField::Page(CiboulettePageType::Size)
-                            }
-                            "offset" => {
-                                CibouletteQueryParametersField::Page(CiboulettePageType::Offset)
-                            }
-                            "number" => {
-                                CibouletteQueryParametersField::Page(CiboulettePageType::Number)
-                            }
-                            "cursor" => {
-                                CibouletteQueryParametersField::Page(CiboulettePageType::Cursor)
-                            }
-                            _ => CibouletteQueryParametersField::Page(CiboulettePageType::Other(
-                                Cow::Owned(page_type.into_owned()),
-                            )),
-                        }
+                        CibouletteQueryParametersField::Page(CiboulettePageType::from(Cow::Owned(
+                            page_type.into_owned(),
+                        )))
+                    }
+                    "sort" => {
+                        let rel_path =
+                            typed_param::parse_typed_query_param(&value[type_end_index..])
+                                .unwrap_or_default();
+                        CibouletteQueryParametersField::SortingNested(Cow::Owned(
+                            rel_path.into_owned(),
+                        ))
                     }
                     "fields" => {
                         let type_ = typed_param::parse_typed_query_param(&value[type_end_index..])
@@ -86,6 +111,9 @@ impl CibouletteQueryParametersFieldVisitor {
                         // FIXME
                     }
                     "filter" => {
+                        if let Some(keys) = parse_nested_keys(&value[type_end_index..]) {
+                            return CibouletteQueryParametersField::FilterNested(keys);
+                        }
                         let type_ = typed_param::parse_typed_query_param(&value[type_end_index..])
                             .unwrap_or_default();
                         CibouletteQueryParametersField::FilterTyped(Cow::Owned(type_.into_owned()))
@@ -1,5 +1,7 @@
 use super::*;
 
+mod custom_param;
+mod family;
 mod fields;
 mod parsing;
 mod sorting;
@@ -9,10 +11,16 @@ mod visitor;
 #[cfg(test)]
 mod tests;
 
+pub use custom_param::{
+    is_custom_query_parameter_name, CibouletteCustomQueryParameter,
+    CibouletteQueryParameterValidator, CibouletteQueryParametersPolicy,
+};
+pub use family::CibouletteQueryFamily;
 pub use fields::CiboulettePageType;
 use fields::CibouletteQueryParametersField;
 pub use parsing::{
-    CibouletteQueryParameters, CibouletteQueryParametersBuilder, CibouletteSortingElement,
+    CibouletteIncludeParameters, CibouletteQueryParameters, CibouletteQueryParametersBuilder,
+    CibouletteSortingElement, CibouletteSparseFields,
 };
 use visitor::CibouletteQueryParametersBuilderVisitor;
 
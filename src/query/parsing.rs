@@ -27,6 +27,38 @@ impl CibouletteSortingElement {
     }
 }
 
+/// ## Pagination and sorting of an included to-many relationship
+#[derive(Debug, Getters, Default, Clone)]
+#[getset(get = "pub")]
+pub struct CibouletteIncludeParameters<'request> {
+    /// Paginate the related resources, per resource of the parent type
+    pub page: BTreeMap<CiboulettePageType<'request>, Cow<'request, str>>,
+    /// Directive to sort the related resources
+    pub sorting: Vec<CibouletteSortingElement>,
+}
+
+/// ## Sparse fieldset of a type, split between its attributes and its relationships
+#[derive(Debug, Getters, Default, Clone, PartialEq, Eq)]
+#[getset(get = "pub")]
+pub struct CibouletteSparseFields {
+    /// The selected attributes
+    pub attributes: Vec<ArcStr>,
+    /// The selected relationships, by alias
+    pub relationships: Vec<ArcStr>,
+}
+
+impl CibouletteSparseFields {
+    /// Check if no field was selected
+    pub fn is_empty(&self) -> bool {
+        self.attributes.is_empty() && self.relationships.is_empty()
+    }
+
+    /// Check if a relationship was selected, by alias
+    pub fn has_relationship(&self, alias: &str) -> bool {
+        self.relationships.iter().any(|x| x.as_str() == alias)
+    }
+}
+
 /// ## Builder object for [CibouletteQueryParameters](CibouletteQueryParameters)
 #[derive(Debug, Getters)]
 #[getset(get = "pub")]
@@ -37,12 +69,19 @@ pub struct CibouletteQueryParametersBuilder<'request> {
     pub(super) sparse: BTreeMap<Cow<'request, str>, Vec<Cow<'request, str>>>,
     /// Directive to sort the main type
     pub(super) sorting: Vec<(CibouletteSortingDirection, Cow<'request, str>)>,
+    /// Directive to sort the related resources of a relationship path
+    pub(super) sorting_nested:
+        BTreeMap<Cow<'request, str>, Vec<(CibouletteSortingDirection, Cow<'request, str>)>>,
     /// Paginate the response
     pub(super) page: BTreeMap<CiboulettePageType<'request>, Cow<'request, str>>,
     /// TBD
     pub(super) filter: Option<Cow<'request, str>>,
     /// TBD
     pub(super) filter_typed: BTreeMap<Cow<'request, str>, Cow<'request, str>>,
+    /// The nested `page[<relationship>]...[<type>]` parameters
+    pub(super) page_family: CibouletteQueryFamily<'request>,
+    /// The nested `filter[<relationship>]...[<field>]` parameters
+    pub(super) filter_family: CibouletteQueryFamily<'request>,
     /// The other query parameters
     pub(super) meta: BTreeMap<Cow<'request, str>, Cow<'request, str>>,
 }
@@ -52,11 +91,23 @@ pub struct CibouletteQueryParametersBuilder<'request> {
 #[getset(get = "pub")]
 pub struct CibouletteQueryParameters<'request> {
     pub include: Vec<Vec<CibouletteResourceRelationshipDetails>>,
-    pub sparse: BTreeMap<Arc<CibouletteResourceType>, Vec<ArcStr>>,
+    /// The pagination and sorting of the to-many relationships, by relationship chain
+    pub include_parameters:
+        BTreeMap<Vec<CibouletteResourceRelationshipDetails>, CibouletteIncludeParameters<'request>>,
+    /// The fields selected for some types
+    pub sparse: BTreeMap<Arc<CibouletteResourceType>, CibouletteSparseFields>,
     pub sorting: Vec<CibouletteSortingElement>,
     pub page: BTreeMap<CiboulettePageType<'request>, Cow<'request, str>>,
     pub filter: Option<Cow<'request, str>>,
     pub filter_typed: BTreeMap<Cow<'request, str>, Cow<'request, str>>,
+    /// The pagination scoped to a chain of relationships
+    pub page_nested: BTreeMap<
+        Vec<CibouletteResourceRelationshipDetails>,
+        BTreeMap<CiboulettePageType<'request>, Cow<'request, str>>,
+    >,
+    /// The filters on the fields of the types at the end of a chain of relationships
+    pub filter_nested:
+        BTreeMap<Vec<CibouletteResourceRelationshipDetails>, BTreeMap<ArcStr, Cow<'request, str>>>,
     pub meta: BTreeMap<Cow<'request, str>, Cow<'request, str>>,
 }
 
@@ -80,23 +131,41 @@ impl<'request> CibouletteQueryParametersBuilder<'request> {
     ///
     /// but "comments.email" may not make sense
     /// if there is no relationship between those two resources.
+    ///
+    /// The chain may be prefixed by the name of the main type.
     #[inline]
     pub(super) fn check_relationship_exists(
         store: &CibouletteStore,
         main_type: &Arc<CibouletteResourceType>,
         rel_list: &[Cow<'request, str>],
+    ) -> Result<Vec<CibouletteResourceRelationshipDetails>, CibouletteError> {
+        let rel_list = match rel_list.split_first() {
+            Some((first, rest)) if main_type.is_public_name(first.as_ref()) => rest,
+            _ => rel_list,
+        };
+        Self::check_relationship_path(store, main_type, rel_list)
+    }
+
+    /// Check that every element of a chain is a relationship, starting from the main type.
+    ///
+    /// Unlike [check_relationship_exists](Self::check_relationship_exists), the name of
+    /// the main type isn't accepted as a prefix, so the chain can't end up empty.
+    #[inline]
+    fn check_relationship_path(
+        store: &CibouletteStore,
+        main_type: &Arc<CibouletteResourceType>,
+        rel_list: &[Cow<'request, str>],
     ) -> Result<Vec<CibouletteResourceRelationshipDetails>, CibouletteError> {
         let mut current_type = main_type.clone();
         let mut res: Vec<CibouletteResourceRelationshipDetails> = Vec::new();
-        let mut first = true;
         for rel in rel_list {
-            if first {
-                first = false;
-                if current_type.name().as_str() == rel.as_ref() {
-                    continue;
-                }
-            }
-            let tmp = current_type.get_relationship_details(store, rel)?.clone();
+            let alias = current_type.member_from_wire(rel.as_ref()).ok_or_else(|| {
+                CibouletteError::UnknownRelationship(
+                    current_type.name().to_string(),
+                    rel.to_string(),
+                )
+            })?;
+            let tmp = current_type.get_relationship_details(store, alias)?;
             current_type = tmp.related_type().clone();
             res.push(tmp);
         }
@@ -115,7 +184,10 @@ impl<'request> CibouletteQueryParametersBuilder<'request> {
         type_: &Arc<CibouletteResourceType>,
         field: &str,
     ) -> Result<ArcStr, CibouletteError> {
-        match type_.schema().properties().get_key_value(field) {
+        match type_
+            .member_from_wire(field)
+            .and_then(|x| type_.schema().properties().get_key_value(x))
+        {
             Some((k, _)) => Ok(k.clone()),
             None => Err(CibouletteError::UnknownField(
                 type_.name().to_string(),
@@ -124,38 +196,107 @@ impl<'request> CibouletteQueryParametersBuilder<'request> {
         }
     }
 
-    /// Checks that fields exists in a give resource type
+    /// Checks that fields exists in a give resource type, splitting the attributes
+    /// from the relationships
     #[inline]
     pub(super) fn check_fields_exists(
         type_: &CibouletteResourceType,
         field_list: Vec<Cow<'request, str>>,
-    ) -> Result<Vec<ArcStr>, CibouletteError> {
-        let curr_obj: &MessyJsonObject = type_.schema();
-        let mut res: Vec<ArcStr> = Vec::with_capacity(field_list.len());
-        let mut iter = field_list.iter().peekable();
-
-        while let Some(field) = iter.next() {
-            let (k, _) = curr_obj
-                .properties()
-                .get_key_value(field.as_ref())
-                .ok_or_else(|| {
-                    CibouletteError::UnknownField(type_.name().to_string(), field.to_string())
-                })?;
-            res.push(k.clone());
-            match iter.peek().is_some() {
-                true => continue,
-                false => return Ok(res),
+    ) -> Result<CibouletteSparseFields, CibouletteError> {
+        let mut res = CibouletteSparseFields::default();
+
+        for field in field_list.iter() {
+            let member = type_.member_from_wire(field.as_ref());
+            if let Some((k, _)) = member.and_then(|x| type_.schema().properties().get_key_value(x))
+            {
+                res.attributes.push(k.clone());
+            } else if let Some((k, _)) = member.and_then(|x| type_.relationships().get_key_value(x))
+            {
+                res.relationships.push(k.clone());
+            } else {
+                return Err(CibouletteError::UnknownField(
+                    type_.name().to_string(),
+                    field.to_string(),
+                ));
             }
         }
-        match field_list.len() {
-            0 => Err(CibouletteError::UnknownField(
-                type_.name().to_string(),
-                "<empty>".to_string(),
-            )),
-            _ => Err(CibouletteError::UnknownField(
-                type_.name().to_string(),
-                field_list.join("."),
-            )),
+        Ok(res)
+    }
+
+    /// Check the query parameters not defined by the specification against the
+    /// registered custom parameters and the store's policy
+    #[inline]
+    pub(super) fn check_custom_parameters(
+        store: &CibouletteStore,
+        main_type: &CibouletteResourceType,
+        meta: &BTreeMap<Cow<'request, str>, Cow<'request, str>>,
+    ) -> Result<(), CibouletteError> {
+        for (name, value) in meta {
+            let base_name = custom_param::query_parameter_base_name(name.as_ref());
+            let param = main_type
+                .query_parameters()
+                .get(name.as_ref())
+                .or_else(|| main_type.query_parameters().get(base_name))
+                .or_else(|| store.query_parameters().get(name.as_ref()))
+                .or_else(|| store.query_parameters().get(base_name));
+            let custom_name = is_custom_query_parameter_name(name.as_ref());
+            match (param, store.config().query_parameters_policy()) {
+                (Some(param), _) => param.validate(name.as_ref(), value.as_ref())?,
+                (None, CibouletteQueryParametersPolicy::Permissive) => (),
+                (None, CibouletteQueryParametersPolicy::Strict) if custom_name => (),
+                (None, _) => return Err(CibouletteError::UnknownQueryParameter(name.to_string())),
+            }
+        }
+        Ok(())
+    }
+
+    /// Split the path of a nested parameter into its chain of relationships and its last key,
+    /// checking the relationships exist
+    #[inline]
+    fn split_family_path(
+        store: &CibouletteStore,
+        main_type: &Arc<CibouletteResourceType>,
+        mut path: Vec<Cow<'request, str>>,
+    ) -> Result<
+        (
+            Vec<CibouletteResourceRelationshipDetails>,
+            Cow<'request, str>,
+        ),
+        CibouletteError,
+    > {
+        let last = path.pop().unwrap_or_default();
+        let rel_path: Vec<Cow<'request, str>> = path
+            .iter()
+            .flat_map(|x| x.split('.'))
+            .map(|x| Cow::Owned(x.to_string()))
+            .collect();
+        let rel_chain = Self::check_relationship_path(store, main_type, rel_path.as_slice())?;
+        Ok((rel_chain, last))
+    }
+
+    /// Check that a chain of relationships ends with a to-many relationship
+    #[inline]
+    fn check_to_many(
+        main_type: &Arc<CibouletteResourceType>,
+        rel_chain: &[CibouletteResourceRelationshipDetails],
+    ) -> Result<(), CibouletteError> {
+        let rel = match rel_chain.last() {
+            Some(rel) => rel,
+            None => return Ok(()),
+        };
+        match rel.relation_option() {
+            CibouletteRelationshipOption::OneToMany(_)
+            | CibouletteRelationshipOption::ManyToMany(_) => Ok(()),
+            CibouletteRelationshipOption::ManyToOne(_) => {
+                let from_type = match rel_chain.len() {
+                    1 => main_type,
+                    len => rel_chain[len - 2].related_type(),
+                };
+                Err(CibouletteError::ToOneRelationship(
+                    from_type.name().to_string(),
+                    rel.relation_alias().to_string(),
+                ))
+            }
         }
     }
 
@@ -165,7 +306,8 @@ impl<'request> CibouletteQueryParametersBuilder<'request> {
         bag: &CibouletteStore,
         main_type: Arc<CibouletteResourceType>,
     ) -> Result<CibouletteQueryParameters<'request>, CibouletteError> {
-        let mut sparse: BTreeMap<Arc<CibouletteResourceType>, Vec<ArcStr>> = BTreeMap::new();
+        let mut sparse: BTreeMap<Arc<CibouletteResourceType>, CibouletteSparseFields> =
+            BTreeMap::new();
         let mut sorting: Vec<CibouletteSortingElement> = Vec::with_capacity(self.sorting.len());
 
         // Check for include relationships and build the array
@@ -186,11 +328,8 @@ impl<'request> CibouletteQueryParametersBuilder<'request> {
 
         // Check for sparse fields, checking that fields exists
         for (type_, fields) in self.sparse.into_iter() {
-            let rel = bag.get_type(type_.as_ref())?;
-            let fields = match fields.is_empty() {
-                true => vec![],
-                false => Self::check_fields_exists(&rel, fields)?,
-            };
+            let rel = bag.get_public_type(type_.as_ref())?;
+            let fields = Self::check_fields_exists(&rel, fields)?;
             sparse.insert(rel.clone(), fields);
         }
 
@@ -205,12 +344,79 @@ impl<'request> CibouletteQueryParametersBuilder<'request> {
                 )?)
             }
         }
+        Self::check_custom_parameters(bag, &main_type, &self.meta)?;
+
+        // Check the nested parameters, following the relationships chains
+        let mut include_parameters: BTreeMap<
+            Vec<CibouletteResourceRelationshipDetails>,
+            CibouletteIncludeParameters<'request>,
+        > = BTreeMap::new();
+        let mut page_nested: BTreeMap<
+            Vec<CibouletteResourceRelationshipDetails>,
+            BTreeMap<CiboulettePageType<'request>, Cow<'request, str>>,
+        > = BTreeMap::new();
+        for (path, value) in self.page_family.into_leaves() {
+            let (rel_chain, page_type) = Self::split_family_path(bag, &main_type, path)?;
+            Self::check_to_many(&main_type, &rel_chain)?;
+            page_nested
+                .entry(rel_chain)
+                .or_default()
+                .insert(CiboulettePageType::from(page_type), value);
+        }
+        for (rel_chain, page) in page_nested.iter() {
+            include_parameters
+                .entry(rel_chain.clone())
+                .or_default()
+                .page = page.clone();
+        }
+        for (rel_path, elements) in self.sorting_nested.into_iter() {
+            let rel_path: Vec<Cow<'request, str>> = rel_path
+                .split('.')
+                .map(|x| Cow::Owned(x.to_string()))
+                .collect();
+            let rel_chain = Self::check_relationship_path(bag, &main_type, &rel_path)?;
+            Self::check_to_many(&main_type, &rel_chain)?;
+            let related_type = rel_chain
+                .last()
+                .map(|x| x.related_type())
+                .unwrap_or(&main_type)
+                .clone();
+            let mut sorting: Vec<CibouletteSortingElement> = Vec::with_capacity(elements.len());
+            for (direction, field) in elements.into_iter() {
+                sorting.push(sorting::extract_type(
+                    &bag,
+                    related_type.clone(),
+                    direction,
+                    field,
+                )?)
+            }
+            include_parameters.entry(rel_chain).or_default().sorting = sorting;
+        }
+        let mut filter_nested: BTreeMap<
+            Vec<CibouletteResourceRelationshipDetails>,
+            BTreeMap<ArcStr, Cow<'request, str>>,
+        > = BTreeMap::new();
+        for (path, value) in self.filter_family.into_leaves() {
+            let (rel_chain, field) = Self::split_family_path(bag, &main_type, path)?;
+            let related_type = rel_chain
+                .last()
+                .map(|x| x.related_type())
+                .unwrap_or(&main_type);
+            let field = Self::check_field_exists(related_type, field.as_ref())?;
+            filter_nested
+                .entry(rel_chain)
+                .or_default()
+                .insert(field, value);
+        }
         let res = CibouletteQueryParameters {
             include,
             page: self.page,
             meta: self.meta,
             filter: self.filter,
             filter_typed: self.filter_typed,
+            include_parameters,
+            page_nested,
+            filter_nested,
             sparse,
             sorting,
         };
@@ -0,0 +1,110 @@
+use super::*;
+use std::sync::Arc;
+
+#[derive(Debug)]
+struct NumericValidator;
+
+impl CibouletteQueryParameterValidator for NumericValidator {
+    fn validate(&self, value: &str) -> Result<(), String> {
+        match value.chars().all(|c| c.is_ascii_digit()) {
+            true => Ok(()),
+            false => Err("expected a number".to_string()),
+        }
+    }
+}
+
+fn gen_bag_policy(policy: CibouletteQueryParametersPolicy) -> CibouletteStore {
+    let mut bag = gen_bag_builder();
+    *bag.config_mut().query_parameters_policy_mut() = policy;
+    bag.add_query_parameter("maxDepth", Some(Arc::new(NumericValidator)))
+        .unwrap();
+    bag.add_type_query_parameter("peoples", "nameFilter", None)
+        .unwrap();
+    bag.build().unwrap()
+}
+
+fn build<'request>(
+    bag: &CibouletteStore,
+    input: &'request str,
+) -> Result<CibouletteQueryParameters<'request>, CibouletteError> {
+    let builder: CibouletteQueryParametersBuilder =
+        serde_urlencoded::from_str(input).expect("to parse");
+    builder.build(bag, bag.get_type("peoples").unwrap().clone())
+}
+
+#[test]
+fn strict_reserved_name() {
+    let bag = gen_bag_policy(CibouletteQueryParametersPolicy::Strict);
+    let err = build(&bag, "hahaha=hohoho").unwrap_err();
+
+    assert_eq!(
+        matches!(&err, CibouletteError::UnknownQueryParameter(name) if name == "hahaha"),
+        true
+    );
+    assert_eq!(
+        err.error_source().unwrap().parameter().as_deref(),
+        Some("hahaha")
+    );
+}
+
+#[test]
+fn strict_custom_name() {
+    let bag = gen_bag_policy(CibouletteQueryParametersPolicy::Strict);
+    let res = build(&bag, "hahaHa=hohoho").expect("to build correctly");
+
+    assert_eq!(res.meta().get("hahaHa").unwrap(), "hohoho");
+}
+
+#[test]
+fn registered_only() {
+    let bag = gen_bag_policy(CibouletteQueryParametersPolicy::Registered);
+    let err = build(&bag, "hahaHa=hohoho").unwrap_err();
+
+    assert_eq!(
+        matches!(err, CibouletteError::UnknownQueryParameter(name) if name == "hahaHa"),
+        true
+    );
+    let res = build(&bag, "maxDepth=3&nameFilter[first]=hello").expect("to build correctly");
+    assert_eq!(res.meta().len(), 2);
+}
+
+#[test]
+fn type_parameter_on_other_type() {
+    let bag = gen_bag_policy(CibouletteQueryParametersPolicy::Registered);
+    let builder: CibouletteQueryParametersBuilder =
+        serde_urlencoded::from_str("nameFilter=hello").expect("to parse");
+    let err = builder
+        .build(&bag, bag.get_type("articles").unwrap().clone())
+        .unwrap_err();
+
+    assert_eq!(
+        matches!(err, CibouletteError::UnknownQueryParameter(name) if name == "nameFilter"),
+        true
+    );
+}
+
+#[test]
+fn validator() {
+    let bag = gen_bag_policy(CibouletteQueryParametersPolicy::Permissive);
+    let err = build(&bag, "maxDepth=three").unwrap_err();
+
+    assert_eq!(
+        matches!(&err, CibouletteError::InvalidQueryParameter(name, _) if name == "maxDepth"),
+        true
+    );
+    assert_eq!(
+        err.error_source().unwrap().parameter().as_deref(),
+        Some("maxDepth")
+    );
+}
+
+#[test]
+fn invalid_name() {
+    let mut bag = gen_bag_builder();
+    let err = bag.add_query_parameter("lowercase", None).unwrap_err();
+
+    assert_eq!(
+        matches!(err, CibouletteError::InvalidMemberName(name) if name == "lowercase"),
+        true
+    );
+}
@@ -0,0 +1,78 @@
+use super::*;
+
+#[test]
+fn sort_relationship() {
+    let (bag, builder) = setup(r#"include=articles&sort[articles]=-title,body"#);
+
+    let res: CibouletteQueryParameters = builder
+        .build(&bag, bag.get_type("peoples").unwrap().clone())
+        .expect("to build correctly");
+    assert_eq!(res.sorting().is_empty(), true);
+    let (rel_chain, params) = res.include_parameters().iter().next().unwrap();
+    assert_eq!(rel_chain.len(), 1);
+    assert_eq!(rel_chain[0].related_type().name(), "articles");
+    assert_eq!(params.sorting().len(), 2);
+    assert_eq!(params.sorting()[0].field().as_str(), "title");
+    assert_eq!(
+        params.sorting()[0].direction(),
+        &CibouletteSortingDirection::Desc
+    );
+    assert_eq!(params.sorting()[1].field().as_str(), "body");
+    assert_eq!(params.page().is_empty(), true);
+}
+
+#[test]
+fn page_and_sort_relationship_path() {
+    let (bag, builder) = setup(r#"page[articles.comments][size]=2&sort[articles.comments]=body"#);
+
+    let res: CibouletteQueryParameters = builder
+        .build(&bag, bag.get_type("peoples").unwrap().clone())
+        .expect("to build correctly");
+    assert_eq!(res.include_parameters().len(), 1);
+    let (rel_chain, params) = res.include_parameters().iter().next().unwrap();
+    assert_eq!(rel_chain.len(), 2);
+    assert_eq!(rel_chain[1].related_type().name(), "comments");
+    assert_eq!(params.page().get(&CiboulettePageType::Size).unwrap(), "2");
+    assert_eq!(params.sorting()[0].field().as_str(), "body");
+}
+
+#[test]
+fn sort_unknown_field() {
+    let (bag, builder) = setup(r#"sort[articles]=nope"#);
+
+    let err = builder
+        .build(&bag, bag.get_type("peoples").unwrap().clone())
+        .unwrap_err();
+    assert_eq!(
+        matches!(err, CibouletteError::UnknownField(type_, field) if type_ == "articles" && field == "nope"),
+        true
+    );
+}
+
+#[test]
+fn to_one_relationship() {
+    let (bag, builder) = setup(r#"page[author][size]=2"#);
+
+    let err = builder
+        .build(&bag, bag.get_type("comments").unwrap().clone())
+        .unwrap_err();
+    assert_eq!(
+        matches!(err, CibouletteError::ToOneRelationship(type_, rel) if type_ == "comments" && rel == "author"),
+        true
+    );
+}
+
+#[test]
+fn main_type_path() {
+    for query in &[r#"sort[peoples]=age"#, r#"page[peoples][size]=2"#] {
+        let (bag, builder) = setup(query);
+
+        let err = builder
+            .build(&bag, bag.get_type("peoples").unwrap().clone())
+            .unwrap_err();
+        assert_eq!(
+            matches!(err, CibouletteError::UnknownRelationship(type_, rel) if type_ == "peoples" && rel == "peoples"),
+            true
+        );
+    }
+}
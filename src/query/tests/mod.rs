@@ -1,10 +1,13 @@
 use crate::test_helper::*;
 use crate::*;
 
+mod custom_params;
 mod filter;
 mod filter_simple;
 mod include;
+mod include_parameters;
 mod meta;
+mod nested;
 mod page;
 mod sorting;
 mod sparse;
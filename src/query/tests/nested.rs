@@ -0,0 +1,90 @@
+use super::*;
+use std::borrow::Cow;
+
+#[test]
+fn filter_nested() {
+    let (bag, builder) = setup(r#"filter[author][first-name]=hello&filter[author][age]=42"#);
+
+    assert_eq!(
+        builder
+            .filter_family()
+            .get(&["author", "age"])
+            .and_then(|x| x.value().as_deref()),
+        Some("42")
+    );
+    let res: CibouletteQueryParameters = builder
+        .build(&bag, bag.get_type("comments").unwrap().clone())
+        .expect("to build correctly");
+    assert_eq!(res.filter_nested().len(), 1);
+    let (rel_chain, filters) = res.filter_nested().iter().next().unwrap();
+    assert_eq!(rel_chain.len(), 1);
+    assert_eq!(rel_chain[0].related_type().name(), "peoples");
+    assert_eq!(filters.get("first-name").unwrap(), "hello");
+    assert_eq!(filters.get("age").unwrap(), "42");
+    assert_eq!(res.filter_typed().is_empty(), true);
+}
+
+#[test]
+fn filter_nested_deep() {
+    let (bag, builder) = setup(r#"filter[author][favorite_color][color]=red"#);
+
+    let res: CibouletteQueryParameters = builder
+        .build(&bag, bag.get_type("comments").unwrap().clone())
+        .expect("to build correctly");
+    let (rel_chain, filters) = res.filter_nested().iter().next().unwrap();
+    assert_eq!(rel_chain.len(), 2);
+    assert_eq!(rel_chain[1].related_type().name(), "favorite_color");
+    assert_eq!(filters.get("color").unwrap(), "red");
+}
+
+#[test]
+fn filter_nested_unknown_field() {
+    let (bag, builder) = setup(r#"filter[author][nope]=hello"#);
+
+    let err = builder
+        .build(&bag, bag.get_type("comments").unwrap().clone())
+        .unwrap_err();
+    assert_eq!(
+        matches!(err, CibouletteError::UnknownField(type_, field) if type_ == "peoples" && field == "nope"),
+        true
+    );
+}
+
+#[test]
+fn filter_nested_unknown_relationship() {
+    let (bag, builder) = setup(r#"filter[nope][body]=hello"#);
+
+    let err = builder
+        .build(&bag, bag.get_type("comments").unwrap().clone())
+        .unwrap_err();
+    assert_eq!(
+        matches!(err, CibouletteError::UnknownRelationship(type_, rel) if type_ == "comments" && rel == "nope"),
+        true
+    );
+}
+
+#[test]
+fn page_nested() {
+    let (bag, builder) = setup(r#"page[comments][size]=10&page[comments][number]=2&page[size]=5"#);
+
+    let res: CibouletteQueryParameters = builder
+        .build(&bag, bag.get_type("peoples").unwrap().clone())
+        .expect("to build correctly");
+    assert_eq!(res.page().get(&CiboulettePageType::Size).unwrap(), "5");
+    let (rel_chain, page) = res.page_nested().iter().next().unwrap();
+    assert_eq!(rel_chain[0].related_type().name(), "comments");
+    assert_eq!(page.get(&CiboulettePageType::Size).unwrap(), "10");
+    assert_eq!(page.get(&CiboulettePageType::Number).unwrap(), "2");
+    assert_eq!(
+        page.get(&CiboulettePageType::Other(Cow::Borrowed("nope"))),
+        None
+    );
+}
+
+#[test]
+fn page_nested_duplicate() {
+    let res = serde_urlencoded::from_str::<CibouletteQueryParametersBuilder>(
+        r#"page[comments][size]=10&page[comments][size]=2"#,
+    );
+    assert_eq!(res.is_err(), true);
+}
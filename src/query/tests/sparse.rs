@@ -11,7 +11,8 @@ fn simple_type() {
     assert_eq!(sparse.len(), 1);
     let sparse = sparse
         .get(bag.get_type("peoples").unwrap().as_ref())
-        .unwrap();
+        .unwrap()
+        .attributes();
     assert_eq!(sparse.len(), 1);
     assert_eq!(sparse[0], "first-name");
 }
@@ -27,7 +28,8 @@ fn multiple_fields() {
     assert_eq!(sparse.len(), 1);
     let sparse = sparse
         .get(bag.get_type("peoples").unwrap().as_ref())
-        .unwrap();
+        .unwrap()
+        .attributes();
     assert_eq!(sparse.len(), 2);
     assert_eq!(sparse[0], "first-name");
     assert_eq!(sparse[1], "last-name");
@@ -44,12 +46,14 @@ fn multiple_types() {
     assert_eq!(sparse.len(), 2);
     let peoples = sparse
         .get(bag.get_type("peoples").unwrap().as_ref())
-        .unwrap();
+        .unwrap()
+        .attributes();
     assert_eq!(peoples.len(), 1);
     assert_eq!(peoples[0], "first-name");
     let articles = sparse
         .get(bag.get_type("articles").unwrap().as_ref())
-        .unwrap();
+        .unwrap()
+        .attributes();
     assert_eq!(articles.len(), 1);
     assert_eq!(articles[0], "title");
 }
@@ -104,7 +108,7 @@ fn empty_field() {
         .sparse()
         .get(bag.get_type("peoples").unwrap().as_ref())
         .unwrap();
-    assert_eq!(peoples_sparse.len(), 0);
+    assert_eq!(peoples_sparse.is_empty(), true);
 }
 
 #[test]
@@ -118,7 +122,32 @@ fn url_encoded_type() {
     assert_eq!(sparse.len(), 1);
     let sparse = sparse
         .get(bag.get_type("peoples").unwrap().as_ref())
-        .unwrap();
+        .unwrap()
+        .attributes();
     assert_eq!(sparse.len(), 1);
     assert_eq!(sparse[0], "first-name");
 }
+
+#[test]
+fn relationship_fields() {
+    let (bag, builder) = setup(r#"fields[articles]=title,author&fields[peoples]=comments"#);
+
+    let res: CibouletteQueryParameters = builder
+        .build(&bag, bag.get_type("articles").unwrap().clone())
+        .expect("to build correctly");
+    let articles = res
+        .sparse()
+        .get(bag.get_type("articles").unwrap().as_ref())
+        .unwrap();
+    assert_eq!(articles.attributes().as_slice(), &[ArcStr::from("title")]);
+    assert_eq!(
+        articles.relationships().as_slice(),
+        &[ArcStr::from("author")]
+    );
+    let peoples = res
+        .sparse()
+        .get(bag.get_type("peoples").unwrap().as_ref())
+        .unwrap();
+    assert_eq!(peoples.attributes().is_empty(), true);
+    assert_eq!(peoples.has_relationship("comments"), true);
+}
@@ -10,6 +10,29 @@ pub fn parse_typed_query_param(s: &str) -> Option<Cow<'_, str>> {
     Some(Cow::Borrowed(&s[1..s.len() - 1]))
 }
 
+/// Extract the keys of a query parameter family
+///
+/// i.e. `[author][name]` => `["author", "name"]`
+pub fn parse_query_param_family(s: &str) -> Option<Vec<Cow<'_, str>>> {
+    let mut res: Vec<Cow<'_, str>> = Vec::new();
+    let mut rest = s;
+
+    while !rest.is_empty() {
+        let inner = rest.strip_prefix('[')?;
+        let end = inner.find(']')?;
+        let key = &inner[..end];
+        if key.is_empty() || key.contains('[') {
+            return None;
+        }
+        res.push(Cow::Borrowed(key));
+        rest = &inner[end + 1..];
+    }
+    match res.is_empty() {
+        true => None,
+        false => Some(res),
+    }
+}
+
 #[cfg(test)]
 mod tests_param {
     use super::*;
@@ -74,4 +97,24 @@ mod tests_param {
         let res = res.unwrap();
         assert_eq!(res, "toto.tutu.toto.tata", "type mismatch");
     }
+
+    #[test]
+    fn family_nested() {
+        let res = parse_query_param_family("[author][name]").unwrap();
+        assert_eq!(res, vec!["author", "name"]);
+    }
+
+    #[test]
+    fn family_single() {
+        let res = parse_query_param_family("[author]").unwrap();
+        assert_eq!(res, vec!["author"]);
+    }
+
+    #[test]
+    fn family_invalid() {
+        assert_eq!(parse_query_param_family("[author][]").is_none(), true);
+        assert_eq!(parse_query_param_family("[author]name").is_none(), true);
+        assert_eq!(parse_query_param_family("[auth[or]").is_none(), true);
+        assert_eq!(parse_query_param_family("").is_none(), true);
+    }
 }
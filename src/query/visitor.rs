@@ -94,6 +94,27 @@ impl CibouletteQueryParametersBuilderVisitor {
         Ok(())
     }
 
+    /// Handle the 'sort[*]' parameter when parsing query parameters
+    #[inline]
+    fn build_sort_nested<'de, A>(
+        mut map: &mut A,
+        rel_path: Cow<'de, str>,
+        res: &mut BTreeMap<Cow<'de, str>, Vec<(CibouletteSortingDirection, Cow<'de, str>)>>,
+    ) -> Result<(), A::Error>
+    where
+        A: serde::de::MapAccess<'de>,
+    {
+        let val = super::sorting::parse_sorting(
+            &serde::de::MapAccess::next_value::<Cow<'de, str>>(&mut map)?,
+        );
+        if res.insert(rel_path, val).is_some() {
+            return Err(<A::Error as serde::de::Error>::duplicate_field(
+                "sort[<relationship>]",
+            ));
+        }
+        Ok(())
+    }
+
     /// Handle the 'page[*]' parameter when parsing query parameters
     #[inline]
     fn build_page<'de, A>(
@@ -118,6 +139,24 @@ impl CibouletteQueryParametersBuilderVisitor {
         Ok(())
     }
 
+    /// Handle the nested 'page[*][*]' and 'filter[*][*]' parameters when parsing query parameters
+    #[inline]
+    fn build_family<'de, A>(
+        mut map: &mut A,
+        keys: Vec<Cow<'de, str>>,
+        res: &mut CibouletteQueryFamily<'de>,
+        field: &'static str,
+    ) -> Result<(), A::Error>
+    where
+        A: serde::de::MapAccess<'de>,
+    {
+        let value = serde::de::MapAccess::next_value::<Cow<'de, str>>(&mut map)?;
+        if !res.insert(keys, value) {
+            return Err(<A::Error as serde::de::Error>::duplicate_field(field));
+        }
+        Ok(())
+    }
+
     /// Handle the 'filter[*]' parameter when parsing query parameters
     #[inline]
     fn build_filter_typed<'de, A>(
@@ -158,11 +197,17 @@ impl<'de> serde::de::Visitor<'de> for CibouletteQueryParametersBuilderVisitor {
     {
         let mut sparse: BTreeMap<Cow<'de, str>, Vec<Cow<'de, str>>> = BTreeMap::new();
         let mut sorting: Vec<(CibouletteSortingDirection, Cow<'de, str>)> = Vec::new();
+        let mut sorting_nested: BTreeMap<
+            Cow<'de, str>,
+            Vec<(CibouletteSortingDirection, Cow<'de, str>)>,
+        > = BTreeMap::new();
         let mut page: BTreeMap<CiboulettePageType<'de>, Cow<'de, str>> = BTreeMap::new();
         let mut filter_typed: BTreeMap<Cow<'de, str>, Cow<'de, str>> = BTreeMap::new();
         let mut meta: BTreeMap<Cow<'de, str>, Cow<'de, str>> = BTreeMap::new();
         let mut include: Option<Vec<Vec<Cow<'de, str>>>> = None;
         let mut filter: Option<Cow<'de, str>> = None;
+        let mut page_family: CibouletteQueryFamily<'de> = CibouletteQueryFamily::default();
+        let mut filter_family: CibouletteQueryFamily<'de> = CibouletteQueryFamily::default();
 
         while let Some(key) =
             match serde::de::MapAccess::next_key::<CibouletteQueryParametersField>(&mut map) {
@@ -183,9 +228,15 @@ impl<'de> serde::de::Visitor<'de> for CibouletteQueryParametersBuilderVisitor {
                 CibouletteQueryParametersField::Sorting => {
                     Self::build_sort(&mut map, &mut sorting)?
                 }
+                CibouletteQueryParametersField::SortingNested(rel_path) => {
+                    Self::build_sort_nested(&mut map, rel_path, &mut sorting_nested)?
+                }
                 CibouletteQueryParametersField::Page(type_) => {
                     Self::build_page(&mut map, type_, &mut page)?
                 }
+                CibouletteQueryParametersField::PageNested(keys) => {
+                    Self::build_family(&mut map, keys, &mut page_family, "page[<relationship>]")?
+                }
                 CibouletteQueryParametersField::Filter => {
                     crate::serde_utils::handle_ident_in_map_stateless(
                         &mut filter,
@@ -196,6 +247,12 @@ impl<'de> serde::de::Visitor<'de> for CibouletteQueryParametersBuilderVisitor {
                 CibouletteQueryParametersField::FilterTyped(type_) => {
                     Self::build_filter_typed(&mut map, type_, &mut filter_typed)?
                 }
+                CibouletteQueryParametersField::FilterNested(keys) => Self::build_family(
+                    &mut map,
+                    keys,
+                    &mut filter_family,
+                    "filter[<relationship>]",
+                )?,
                 CibouletteQueryParametersField::Meta(key) => {
                     meta.insert(
                         key,
@@ -209,9 +266,12 @@ impl<'de> serde::de::Visitor<'de> for CibouletteQueryParametersBuilderVisitor {
             include,
             sparse,
             sorting,
+            sorting_nested,
             page,
             filter,
             filter_typed,
+            page_family,
+            filter_family,
             meta,
         })
     }
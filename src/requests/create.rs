@@ -16,6 +16,14 @@ pub struct CibouletteCreateRequest<'request> {
     >,
     /// The meta data sent by the client
     pub meta: Option<Value>,
+    /// The typed values of the top-level extension members sent by the client
+    pub extensions: CibouletteExtensionData,
+    /// The registered extensions applied to the request
+    pub ext: Vec<ArcStr>,
+    /// The registered profiles applied to the request
+    pub profiles: Vec<ArcStr>,
+    /// The unknown members dropped from the body, as JSON pointers
+    pub dropped_members: Vec<String>,
     /// What response type to expect from that request.
     pub expected_response_type: CibouletteResponseRequiredType,
 }
@@ -45,6 +53,18 @@ impl<'request> CibouletteRequestCommons<'request> for CibouletteCreateRequest<'r
     fn meta(&self) -> &Option<serde_json::Value> {
         &self.meta
     }
+
+    fn extensions(&self) -> &CibouletteExtensionData {
+        &self.extensions
+    }
+
+    fn ext(&self) -> &[ArcStr] {
+        &self.ext
+    }
+
+    fn profiles(&self) -> &[ArcStr] {
+        &self.profiles
+    }
 }
 
 impl<'request> TryFrom<CibouletteRequest<'request>> for CibouletteCreateRequest<'request> {
@@ -56,6 +76,9 @@ impl<'request> TryFrom<CibouletteRequest<'request>> for CibouletteCreateRequest<
             body,
             intention,
             path,
+            ext,
+            profiles,
+            ..
         } = value;
 
         let path_type = CiboulettePathType::from(&path);
@@ -74,7 +97,15 @@ impl<'request> TryFrom<CibouletteRequest<'request>> for CibouletteCreateRequest<
             ));
         }
 
-        let CibouletteBody { data, meta, .. } = body.ok_or(CibouletteError::NoData)?;
+        path.main_type().check_capability(intention)?;
+
+        let CibouletteBody {
+            data,
+            meta,
+            extension_data,
+            dropped_members,
+            ..
+        } = body.ok_or(CibouletteError::NoData)?;
 
         let data = match data {
             CibouletteBodyData::Object(x) => x,
@@ -82,19 +113,40 @@ impl<'request> TryFrom<CibouletteRequest<'request>> for CibouletteCreateRequest<
         };
         let data = match data.take() {
             CibouletteSelector::Single(data) => {
-                if data.identifier().type_() != path.main_type().name() {
+                if data.type_() != path.main_type() {
                     return Err(CibouletteError::MainTypeClash);
                 }
                 data
             }
             _ => return Err(CibouletteError::NoCompound),
         };
+        match (
+            path.main_type().capabilities().client_ids(),
+            data.identifier().id(),
+        ) {
+            (CibouletteClientIdPolicy::Forbidden, Some(_)) => {
+                return Err(CibouletteError::ClientIdForbidden(
+                    path.main_type().name().to_string(),
+                ))
+            }
+            (CibouletteClientIdPolicy::Required, None) => {
+                return Err(CibouletteError::MissingClientId(
+                    path.main_type().name().to_string(),
+                ))
+            }
+            _ => (),
+        }
+        data.check_mandatory_attributes()?;
 
         Ok(CibouletteCreateRequest {
             path,
             query,
             data,
             meta,
+            extensions: extension_data,
+            ext,
+            profiles,
+            dropped_members,
             expected_response_type: CibouletteResponseRequiredType::Object(
                 CibouletteResponseQuantity::Single,
             ),
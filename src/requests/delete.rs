@@ -14,6 +14,12 @@ pub struct CibouletteDeleteRequest<'request> {
     pub query: CibouletteQueryParameters<'request>,
     /// The meta data sent by the client
     pub meta: Option<Value>,
+    /// The typed values of the top-level extension members sent by the client
+    pub extensions: CibouletteExtensionData,
+    /// The registered extensions applied to the request
+    pub ext: Vec<ArcStr>,
+    /// The registered profiles applied to the request
+    pub profiles: Vec<ArcStr>,
     /// The expected response type
     pub expected_response_type: CibouletteResponseRequiredType,
     /// The path used to query
@@ -46,6 +52,18 @@ impl<'request> CibouletteRequestCommons<'request> for CibouletteDeleteRequest<'r
     fn meta(&self) -> &Option<serde_json::Value> {
         &self.meta
     }
+
+    fn extensions(&self) -> &CibouletteExtensionData {
+        &self.extensions
+    }
+
+    fn ext(&self) -> &[ArcStr] {
+        &self.ext
+    }
+
+    fn profiles(&self) -> &[ArcStr] {
+        &self.profiles
+    }
 }
 
 impl<'request> TryFrom<CibouletteRequest<'request>> for CibouletteDeleteRequest<'request> {
@@ -57,6 +75,9 @@ impl<'request> TryFrom<CibouletteRequest<'request>> for CibouletteDeleteRequest<
             body,
             path,
             intention,
+            ext,
+            profiles,
+            ..
         } = value;
 
         let (resource_type, resource_id, related_type) = match &path {
@@ -79,7 +100,17 @@ impl<'request> TryFrom<CibouletteRequest<'request>> for CibouletteDeleteRequest<
             ));
         }
 
-        let CibouletteBody { meta, .. } = body.unwrap_or_default();
+        match &related_type {
+            Some(related_details) => resource_type
+                .check_relationship_capability(related_details.relation_alias(), intention)?,
+            None => resource_type.check_capability(intention)?,
+        }
+
+        let CibouletteBody {
+            meta,
+            extension_data,
+            ..
+        } = body.unwrap_or_default();
         Ok(CibouletteDeleteRequest {
             resource_type,
             resource_id: resource_id.clone(),
@@ -87,6 +118,9 @@ impl<'request> TryFrom<CibouletteRequest<'request>> for CibouletteDeleteRequest<
             path,
             query,
             meta,
+            extensions: extension_data,
+            ext,
+            profiles,
             expected_response_type: CibouletteResponseRequiredType::None,
         })
     }
@@ -16,6 +16,12 @@ pub struct CibouletteReadRequest<'request> {
     >,
     /// The meta data sent by the client
     pub meta: Option<Value>,
+    /// The typed values of the top-level extension members sent by the client
+    pub extensions: CibouletteExtensionData,
+    /// The registered extensions applied to the request
+    pub ext: Vec<ArcStr>,
+    /// The registered profiles applied to the request
+    pub profiles: Vec<ArcStr>,
     /// The expected response type
     pub expected_response_type: CibouletteResponseRequiredType,
 }
@@ -54,6 +60,18 @@ impl<'request> CibouletteRequestCommons<'request> for CibouletteReadRequest<'req
     fn meta(&self) -> &Option<serde_json::Value> {
         &self.meta
     }
+
+    fn extensions(&self) -> &CibouletteExtensionData {
+        &self.extensions
+    }
+
+    fn ext(&self) -> &[ArcStr] {
+        &self.ext
+    }
+
+    fn profiles(&self) -> &[ArcStr] {
+        &self.profiles
+    }
 }
 
 impl<'request> TryFrom<CibouletteRequest<'request>> for CibouletteReadRequest<'request> {
@@ -65,6 +83,9 @@ impl<'request> TryFrom<CibouletteRequest<'request>> for CibouletteReadRequest<'r
             body,
             intention,
             path,
+            ext,
+            profiles,
+            ..
         } = value;
         let expected_response_type: CibouletteResponseRequiredType = match path {
             CiboulettePath::TypeId(_, _) => {
@@ -84,7 +105,27 @@ impl<'request> TryFrom<CibouletteRequest<'request>> for CibouletteReadRequest<'r
             ));
         }
 
-        let CibouletteBody { data, meta, .. } = body.unwrap_or_default();
+        match &path {
+            CiboulettePath::TypeIdRelated(type_, _, rel)
+            | CiboulettePath::TypeIdRelationship(type_, _, rel) => {
+                type_.check_relationship_capability(rel.relation_alias(), intention)?
+            }
+            _ => (),
+        }
+        for rel_chain in query.include() {
+            let mut current_type = path.main_type();
+            for rel in rel_chain {
+                current_type.check_relationship_capability(rel.relation_alias(), intention)?;
+                current_type = rel.related_type();
+            }
+        }
+
+        let CibouletteBody {
+            data,
+            meta,
+            extension_data,
+            ..
+        } = body.unwrap_or_default();
 
         let data = match data {
             CibouletteBodyData::Object(obj) => obj,
@@ -99,6 +140,9 @@ impl<'request> TryFrom<CibouletteRequest<'request>> for CibouletteReadRequest<'r
             query,
             data,
             meta,
+            extensions: extension_data,
+            ext,
+            profiles,
             expected_response_type,
         })
     }
@@ -10,6 +10,10 @@ pub struct CibouletteRequestBuilder<'request> {
     intention: CibouletteIntention,
     /// The body, if any
     body: &'request Option<&'request str>,
+    /// The media type of the body (`Content-Type`), if any
+    media_type: Option<&'request str>,
+    /// The media types accepted by the client (`Accept`), if any
+    accept: Option<&'request str>,
 }
 
 /// ## Abstract representation of a `JSON:API` request
@@ -30,6 +34,12 @@ pub struct CibouletteRequest<'request> {
     >,
     /// The method used
     pub intention: CibouletteIntention,
+    /// The parsed media type of the request
+    pub media_type: CibouletteMediaType<'request>,
+    /// The registered extensions applied to the request
+    pub ext: Vec<ArcStr>,
+    /// The registered profiles applied to the request
+    pub profiles: Vec<ArcStr>,
 }
 
 /// ## `JSON:API` inbound requests
@@ -48,6 +58,12 @@ pub trait CibouletteRequestCommons<'request>: Send + Sync {
     fn anchor_type(&self) -> &Arc<CibouletteResourceType>;
     /// Meta data included by the client, if any
     fn meta(&self) -> &Option<serde_json::Value>;
+    /// Typed values of the top-level extension members included by the client
+    fn extensions(&self) -> &CibouletteExtensionData;
+    /// The URIs of the registered extensions applied to the request
+    fn ext(&self) -> &[ArcStr];
+    /// The registered profiles applied to the request. Unknown profiles are ignored
+    fn profiles(&self) -> &[ArcStr];
 }
 
 impl<'request> CibouletteRequestBuilder<'request> {
@@ -61,9 +77,25 @@ impl<'request> CibouletteRequestBuilder<'request> {
             req_url,
             intention,
             body,
+            media_type: None,
+            accept: None,
         }
     }
 
+    /// Set the media type of the body (`Content-Type`)
+    pub fn with_media_type(mut self, media_type: &'request str) -> Self {
+        self.media_type = Some(media_type);
+        self
+    }
+
+    /// Set the media types accepted by the client (`Accept`).
+    ///
+    /// The profiles applied to the request are negotiated from it
+    pub fn with_accept(mut self, accept: &'request str) -> Self {
+        self.accept = Some(accept);
+        self
+    }
+
     /// Build the inbound request, checking its validity and parsing the inner body
     ///
     /// Once built, this request can be transformed into the definitive request depending
@@ -72,6 +104,22 @@ impl<'request> CibouletteRequestBuilder<'request> {
         self,
         bag: &CibouletteStore,
     ) -> Result<CibouletteRequest<'request>, CibouletteError> {
+        let media_type = match self.media_type {
+            Some(media_type) => CibouletteMediaType::parse(media_type)?,
+            None => CibouletteMediaType::default(),
+        };
+        let accept = self
+            .accept
+            .map(CibouletteMediaType::parse_accept)
+            .unwrap_or_default();
+        let extensions = bag.get_extensions(media_type.ext().iter().copied())?;
+        let ext: Vec<ArcStr> = extensions.iter().map(|x| ArcStr::from(x.uri())).collect();
+        let profiles: Vec<ArcStr> = accept
+            .profile()
+            .iter()
+            .filter_map(|x| bag.get_profile(x))
+            .cloned()
+            .collect();
         let path: CiboulettePath<'request> =
             CiboulettePathBuilder::parse(self.req_url)?.build(&bag)?;
         let body: Option<
@@ -83,8 +131,8 @@ impl<'request> CibouletteRequestBuilder<'request> {
         > = match self.body {
             // Build body
             Some(body) => {
-                let builder: CibouletteBodyBuilder<'request> = serde_json::from_str(body)?;
-                Some(builder.build(bag, self.intention())?)
+                let builder: CibouletteBodyBuilder<'request> = CibouletteBodyBuilder::parse(body)?;
+                Some(builder.build_with_extensions(bag, self.intention(), &extensions)?)
             }
             None => None,
         };
@@ -104,6 +152,9 @@ impl<'request> CibouletteRequestBuilder<'request> {
             body,
             query: query.unwrap_or_default(),
             intention: self.intention,
+            media_type,
+            ext,
+            profiles,
         })
     }
 }
@@ -41,6 +41,14 @@ pub struct CibouletteUpdateRequest<'request> {
     pub data: CibouletteUpdateRequestType<'request>,
     /// The meta data included by the client
     pub meta: Option<Value>,
+    /// The typed values of the top-level extension members sent by the client
+    pub extensions: CibouletteExtensionData,
+    /// The registered extensions applied to the request
+    pub ext: Vec<ArcStr>,
+    /// The registered profiles applied to the request
+    pub profiles: Vec<ArcStr>,
+    /// The unknown members dropped from the body, as JSON pointers
+    pub dropped_members: Vec<String>,
     /// The expected response type for that request
     pub expected_response_type: CibouletteResponseRequiredType,
 }
@@ -71,6 +79,18 @@ impl<'request> CibouletteRequestCommons<'request> for CibouletteUpdateRequest<'r
     fn meta(&self) -> &Option<serde_json::Value> {
         &self.meta
     }
+
+    fn extensions(&self) -> &CibouletteExtensionData {
+        &self.extensions
+    }
+
+    fn ext(&self) -> &[ArcStr] {
+        &self.ext
+    }
+
+    fn profiles(&self) -> &[ArcStr] {
+        &self.profiles
+    }
 }
 
 impl<'request> TryFrom<CibouletteRequest<'request>> for CibouletteUpdateRequest<'request> {
@@ -87,6 +107,8 @@ impl<'request> TryFrom<CibouletteRequest<'request>> for CibouletteUpdateRequest<
         > = value.body;
         let intention: CibouletteIntention = value.intention;
         let path: CiboulettePath<'request> = value.path;
+        let ext: Vec<ArcStr> = value.ext;
+        let profiles: Vec<ArcStr> = value.profiles;
 
         let (resource_type, resource_id, related_type): (
             Arc<CibouletteResourceType>,
@@ -112,7 +134,19 @@ impl<'request> TryFrom<CibouletteRequest<'request>> for CibouletteUpdateRequest<
             ));
         }
 
-        let CibouletteBody { data, meta, .. } = body.unwrap_or_default();
+        match related_type {
+            Some(related_details) => resource_type
+                .check_relationship_capability(related_details.relation_alias(), intention)?,
+            None => resource_type.check_capability(intention)?,
+        }
+
+        let CibouletteBody {
+            data,
+            meta,
+            extension_data,
+            dropped_members,
+            ..
+        } = body.unwrap_or_default();
         let data = match data {
             CibouletteBodyData::Object(selector) => match related_type {
                 Some(related_details) => {
@@ -128,7 +162,7 @@ impl<'request> TryFrom<CibouletteRequest<'request>> for CibouletteUpdateRequest<
                             MessyJsonObjectValue<'request>,
                             CibouletteResourceIdentifier<'request>,
                         > = value.try_into()?;
-                        if type_.identifier().type_() != path.main_type().name() {
+                        if type_.type_() != path.main_type() {
                             return Err(CibouletteError::MainTypeClash);
                         }
                         CibouletteUpdateRequestType::MainType(type_)
@@ -153,6 +187,10 @@ impl<'request> TryFrom<CibouletteRequest<'request>> for CibouletteUpdateRequest<
             query,
             data,
             meta,
+            extensions: extension_data,
+            ext,
+            profiles,
+            dropped_members,
             path,
             expected_response_type: CibouletteResponseRequiredType::Object(
                 CibouletteResponseQuantity::Single,
@@ -102,12 +102,15 @@ impl<'response, B> CibouletteResponseDataAccumulator<'response, B> {
         let mut main_data = self.main_data;
         match settings.include_rels() {
             None => {
-                let included_data = Self::extract_included_data(
+                let mut included_data = Self::extract_included_data(
                     config,
                     inbound_request.expected_type(),
                     &mut main_data,
                     self.included_data,
                 )?;
+                for resource in main_data.values_mut().chain(included_data.values_mut()) {
+                    apply_sparse_relationships(inbound_request.query(), resource);
+                }
                 let body_data = Self::extract_main_data(config, main_data, inbound_request);
                 Ok(CibouletteOutboundRequestExtractedData {
                     main_data: body_data,
@@ -331,6 +334,22 @@ impl<'response, B> CibouletteResponseDataAccumulator<'response, B> {
     }
 }
 
+/// Removes the relationships left out of the sparse fieldset of the resource type, if any
+fn apply_sparse_relationships<'request, 'response, B>(
+    query: &CibouletteQueryParameters<'request>,
+    obj: &mut CibouletteResponseResource<'response, B>,
+) {
+    if let Some(fields) = query.sparse().get(obj.type_()) {
+        let type_ = obj.type_().clone();
+        obj.relationships_mut().retain(|alias, _| {
+            type_
+                .member_from_wire(alias.as_str())
+                .map(|x| fields.has_relationship(x))
+                .unwrap_or(false)
+        });
+    }
+}
+
 /// Inserts into an existing relationships a new entry, updating its format if necessary
 fn insert_relationships_into_existing<'store, 'response, B>(
     config: &'store CibouletteConfig,
@@ -338,7 +357,8 @@ fn insert_relationships_into_existing<'store, 'response, B>(
     alias_identifier: CibouletteResourceResponseIdentifier<'response>,
     alias_str: &ArcStr,
 ) {
-    if let Some(rel) = obj.relationships_mut().get_mut(alias_str) {
+    let alias_str = ArcStr::from(obj.type_().member_to_wire(alias_str.as_str()));
+    if let Some(rel) = obj.relationships_mut().get_mut(&alias_str) {
         let data = rel.data_mut();
         match data {
             CibouletteOptionalData::Object(CibouletteResourceResponseIdentifierSelector::One(
@@ -365,10 +385,13 @@ fn insert_relationships_into_existing<'store, 'response, B>(
             }
         }
     } else {
-        let links =
-            super::links::build_link_for_response_relationship(config, obj.identifier(), alias_str);
+        let links = super::links::build_link_for_response_relationship(
+            config,
+            obj.identifier(),
+            &alias_str,
+        );
         obj.relationships_mut().insert(
-            alias_str.clone(),
+            alias_str,
             CibouletteResponseRelationshipObject {
                 links,
                 data: CibouletteOptionalData::Object(
@@ -25,21 +25,96 @@ pub struct CibouletteResponseBody<'request, B> {
 }
 
 /// ## Resource included in a response
-#[derive(Debug, Getters, MutGetters, Clone, Serialize)]
+#[derive(Debug, Getters, MutGetters, Clone)]
 #[getset(get = "pub", get_mut = "pub")]
 pub struct CibouletteResponseResource<'request, B> {
-    #[serde(flatten)]
     pub identifier: CibouletteResourceResponseIdentifier<'request>,
-    #[serde(skip_serializing_if = "Option::is_none")]
     pub attributes: Option<B>,
-    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
     pub relationships: BTreeMap<ArcStr, CibouletteResponseRelationshipObject<'request>>,
-    #[serde(skip_serializing_if = "Option::is_none")]
     pub links: Option<CibouletteLink<'request>>,
-    #[serde(skip_serializing)]
     pub type_: Arc<CibouletteResourceType>,
 }
 
+/// Attributes of a resource, serialized using the member names exchanged with the clients
+/// and leaving out the write-only attributes
+struct CibouletteWireAttributes<'a, B> {
+    type_: &'a CibouletteResourceType,
+    attributes: &'a B,
+}
+
+impl<'a, B> Serialize for CibouletteWireAttributes<'a, B>
+where
+    B: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::{Error, SerializeMap};
+
+        if self.type_.wire_schema().is_none() && !self.type_.has_write_only_attributes() {
+            return self.attributes.serialize(serializer);
+        }
+        match serde_json::to_value(self.attributes).map_err(S::Error::custom)? {
+            Value::Object(attributes) => {
+                let mut map = serializer.serialize_map(None)?;
+                for (k, v) in attributes.into_iter() {
+                    if !self.type_.attribute_policy(k.as_str()).is_readable() {
+                        continue;
+                    }
+                    let v = match self.type_.schema().properties().get(k.as_str()) {
+                        Some(schema) => self.type_.value_to_wire(schema, v),
+                        None => v,
+                    };
+                    map.serialize_entry(self.type_.member_to_wire(k.as_str()), &v)?;
+                }
+                map.end()
+            }
+            attributes => attributes.serialize(serializer),
+        }
+    }
+}
+
+/// Serialization proxy for [CibouletteResponseResource](CibouletteResponseResource)
+#[derive(Serialize)]
+struct CibouletteResponseResourceSer<'a, 'request, B> {
+    #[serde(flatten)]
+    identifier: &'a CibouletteResourceResponseIdentifier<'request>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    attributes: Option<CibouletteWireAttributes<'a, B>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    relationships: Option<&'a BTreeMap<ArcStr, CibouletteResponseRelationshipObject<'request>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    links: Option<&'a CibouletteLink<'request>>,
+}
+
+impl<'request, B> Serialize for CibouletteResponseResource<'request, B>
+where
+    B: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        CibouletteResponseResourceSer {
+            identifier: &self.identifier,
+            attributes: self
+                .attributes
+                .as_ref()
+                .map(|attributes| CibouletteWireAttributes {
+                    type_: &self.type_,
+                    attributes,
+                }),
+            relationships: match self.relationships.is_empty() {
+                true => None,
+                false => Some(&self.relationships),
+            },
+            links: self.links.as_ref(),
+        }
+        .serialize(serializer)
+    }
+}
+
 /// ## Relationships object included in a response
 #[derive(Debug, Serialize, Getters, MutGetters, Default, Clone)]
 #[getset(get = "pub", get_mut = "pub")]
@@ -37,6 +37,10 @@ impl<'request> CibouletteResponseElementAlias<'request> {
 }
 
 impl<'request, B> CibouletteResponseElement<'request, B> {
+    /// Build a response element from the identifiers provided by the backend.
+    ///
+    /// The ids are expected in their canonical form: the [custom ids](CibouletteIdParser)
+    /// are trusted as they are and don't go through their parser.
     pub fn build(
         store: &CibouletteStore,
         main_type: &Arc<CibouletteResourceType>,
@@ -60,8 +64,8 @@ impl<'request, B> CibouletteResponseElement<'request, B> {
             }
         };
         let type_ = match &related {
-            Some(related) => store.get_type(related.element().type_())?,
-            None => store.get_type(identifier.type_())?,
+            Some(related) => store.get_public_type(related.element().type_())?,
+            None => store.get_public_type(identifier.type_())?,
         }
         .clone();
         Ok(CibouletteResponseElement {
@@ -84,9 +88,7 @@ where
 {
     let acc = CibouletteResponseDataAccumulator::from(acc_settings.clone());
     elements.into_iter().try_fold(acc, |mut acc, x| {
-        match x.related().is_none()
-            && x.identifier().type_() == acc_settings.main_type().name().as_str()
-        {
+        match x.related().is_none() && x.type_() == acc_settings.main_type() {
             true => match acc.settings().only_ids() {
                 true => fold_elements_id(&mut acc, x),
                 false => fold_elements_obj(&mut acc, x),
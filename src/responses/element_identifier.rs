@@ -64,10 +64,10 @@ impl<'request> CibouletteResourceResponseIdentifierBuilder<'request> {
         let type_ = store.get_type(&self.type_)?;
         Ok(CibouletteResourceResponseIdentifier {
             id: match self.id {
-                Some(id) => CibouletteIdSelector::build_id(type_.ids(), id)?,
+                Some(id) => CibouletteIdSelector::build_canonical_id(type_.ids(), id)?,
                 None => return Err(CibouletteError::MissingId),
             },
-            type_: type_.name().clone(),
+            type_: type_.public_name().clone(),
         })
     }
 
@@ -110,8 +110,8 @@ impl<'request> CibouletteResourceResponseIdentifierBuilder<'request> {
         Ok(CibouletteResponseElementAlias::new(
             rel_chain,
             CibouletteResourceResponseIdentifier {
-                type_: last_type.name().clone(),
-                id: CibouletteIdSelector::build_id(
+                type_: last_type.public_name().clone(),
+                id: CibouletteIdSelector::build_canonical_id(
                     &id_type,
                     self.id.ok_or(CibouletteError::MissingId)?,
                 )?,
@@ -11,7 +11,7 @@ pub fn root_link<'result, 'store, 'request>(
             &str,
         >(
             config,
-            inbound_request.expected_type().name(),
+            inbound_request.expected_type().public_name(),
             None,
             false,
             None,
@@ -12,7 +12,7 @@ pub fn root_link<'result, 'store, 'request>(
                 &str,
             >(
                 config,
-                type_.name().as_str(),
+                type_.public_name().as_str(),
                 None,
                 false,
                 None,
@@ -26,7 +26,7 @@ pub fn root_link<'result, 'store, 'request>(
                 &str,
             >(
                 config,
-                type_.name().as_str(),
+                type_.public_name().as_str(),
                 Some(id),
                 false,
                 None,
@@ -36,26 +36,26 @@ pub fn root_link<'result, 'store, 'request>(
         CiboulettePath::TypeIdRelationship(type_, id, rel_details) => CibouletteLink {
             self_: Some(CibouletteLinkSelector::Simple(Cow::Owned(create_link(
                 config,
-                type_.name().as_str(),
+                type_.public_name().as_str(),
                 Some(id),
                 false,
-                Some(rel_details.relation_alias().as_str()),
+                Some(type_.member_to_wire(rel_details.relation_alias().as_str())),
             )))),
             related: Some(CibouletteLinkSelector::Simple(Cow::Owned(create_link(
                 config,
-                type_.name().as_str(),
+                type_.public_name().as_str(),
                 Some(id),
                 true,
-                Some(rel_details.relation_alias().as_str()),
+                Some(type_.member_to_wire(rel_details.relation_alias().as_str())),
             )))),
         },
         CiboulettePath::TypeIdRelated(type_, id, rel_details) => CibouletteLink {
             self_: Some(CibouletteLinkSelector::Simple(Cow::Owned(create_link(
                 config,
-                type_.name().as_str(),
+                type_.public_name().as_str(),
                 Some(id),
                 true,
-                Some(rel_details.relation_alias().as_str()),
+                Some(type_.member_to_wire(rel_details.relation_alias().as_str())),
             )))),
             related: None,
         },
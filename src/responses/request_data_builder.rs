@@ -26,6 +26,31 @@ where
             elements,
         }
     }
+    /// Build the `jsonapi` object, echoing the extensions and profiles applied to the request.
+    ///
+    /// Extensions and profiles were introduced in version `1.1` of the specification
+    fn build_jsonapi(
+        inbound_request: &'request dyn CibouletteRequestCommons<'request>,
+    ) -> CibouletteJsonApiVersion<'response> {
+        let version = if inbound_request.ext().is_empty() && inbound_request.profiles().is_empty() {
+            "1.0"
+        } else {
+            "1.1"
+        };
+        let mut res = CibouletteJsonApiVersion::new(Cow::Borrowed(version));
+        *res.ext_mut() = inbound_request
+            .ext()
+            .iter()
+            .map(|x| Cow::Owned(x.to_string()))
+            .collect();
+        *res.profile_mut() = inbound_request
+            .profiles()
+            .iter()
+            .map(|x| Cow::Owned(x.to_string()))
+            .collect();
+        res
+    }
+
     /// Build body of the outbound request
     fn build_body<'store>(
         config: &'store CibouletteConfig,
@@ -44,7 +69,7 @@ where
             data: extracted_data.main_data,
             errors: None,
             links: body_link,
-            jsonapi: Some(CibouletteJsonApiVersion::new(Cow::Borrowed("1.0"))),
+            jsonapi: Some(Self::build_jsonapi(inbound_request)),
             included: extracted_data
                 .included_data
                 .into_iter()
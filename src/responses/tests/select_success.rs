@@ -520,3 +520,80 @@ fn with_include_nested_self() {
     assert_json_snapshot!(response);
     assert_eq!(response.status(), CibouletteResponseStatus::Ok);
 }
+
+#[test]
+fn read_public_name() {
+    let mut store = gen_bag_builder();
+    store.set_public_name("comments", "remarks").unwrap();
+    let store = store.build().unwrap();
+    let url = Url::parse("http://localhost/").unwrap();
+    let opt = url::Url::options().base_url(Some(&url));
+    const URL: &str = "/remarks/073b5936-0acb-4601-b4b7-9de607dfc2ef";
+    const INTENTION: CibouletteIntention = CibouletteIntention::Read;
+
+    let parsed_url = opt.parse(URL).unwrap();
+    let builder = CibouletteRequestBuilder::new(INTENTION, &parsed_url, &None);
+    let request = builder.build(&store).unwrap();
+    let res = CibouletteReadRequest::try_from(request).unwrap();
+    let base_type = store.get_type("comments").unwrap();
+    let response = CibouletteResponseDataBuilder::new(
+        &res,
+        vec![gen_data_row(
+            &store,
+            &base_type,
+            "comments",
+            "073b5936-0acb-4601-b4b7-9de607dfc2ef",
+            true,
+        )],
+    )
+    .build(store.config())
+    .unwrap();
+    let response = serde_json::to_value(&response).unwrap();
+    assert_eq!(response["data"]["type"], "remarks");
+}
+
+#[test]
+fn with_include_sparse_relationships() {
+    let store = gen_bag();
+    let url = Url::parse("http://localhost/").unwrap();
+    let opt = url::Url::options().base_url(Some(&url));
+    const URL: &str =
+        "/peoples/c49094ce-71ab-40d4-a642-ea200f72eac6/articles?include=author&fields[articles]=title";
+    const INTENTION: CibouletteIntention = CibouletteIntention::Read;
+
+    let parsed_url = opt.parse(URL).unwrap();
+    let builder = CibouletteRequestBuilder::new(INTENTION, &parsed_url, &None);
+    let request = builder.build(&store).unwrap();
+    let res = CibouletteReadRequest::try_from(request).unwrap();
+    let base_type = store.get_type("articles").unwrap();
+    let response = CibouletteResponseDataBuilder::new(
+        &res,
+        vec![
+            gen_data_row(
+                &store,
+                &base_type,
+                "articles",
+                "073b5936-0acb-4601-b4b7-9de607dfc2ef",
+                true,
+            ),
+            gen_data_row_related(
+                &store,
+                &base_type,
+                "author",
+                "c49094ce-71ab-40d4-a642-ea200f72eac6",
+                true,
+                "articles",
+                "073b5936-0acb-4601-b4b7-9de607dfc2ef",
+            ),
+        ],
+    )
+    .build(store.config())
+    .unwrap();
+    let response = serde_json::to_value(&response).unwrap();
+
+    assert_eq!(response["data"][0].get("relationships"), None);
+    assert_eq!(
+        response["included"][0]["id"],
+        "c49094ce-71ab-40d4-a642-ea200f72eac6"
+    );
+}
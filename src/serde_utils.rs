@@ -60,3 +60,121 @@ pub fn handle_ident_in_map_stateful<'de, A: serde::de::MapAccess<'de>, V: Deseri
     *res = Some(serde::de::MapAccess::next_value(&mut map)?);
     Ok(())
 }
+
+#[inline]
+pub fn handle_extension_member_in_map<'de, A: serde::de::MapAccess<'de>>(
+    res: &mut std::collections::BTreeMap<std::borrow::Cow<'de, str>, serde_json::Value>,
+    mut map: &mut A,
+    member_name: std::borrow::Cow<'de, str>,
+) -> Result<(), A::Error> {
+    if res.contains_key(&member_name) {
+        return Err(<A::Error as serde::de::Error>::custom(format!(
+            "duplicate field `{}`",
+            member_name
+        )));
+    }
+    let value = serde::de::MapAccess::next_value::<serde_json::Value>(&mut map)?;
+    res.insert(member_name, value);
+    Ok(())
+}
+
+/// Deserialize a map key, borrowing it from the input if possible
+struct CibouletteCowKeyVisitor;
+
+impl<'de> serde::de::Visitor<'de> for CibouletteCowKeyVisitor {
+    type Value = std::borrow::Cow<'de, str>;
+
+    #[inline]
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        std::fmt::Formatter::write_str(formatter, "a string key")
+    }
+
+    #[inline]
+    fn visit_borrowed_str<E>(self, v: &'de str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(std::borrow::Cow::Borrowed(v))
+    }
+
+    #[inline]
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(std::borrow::Cow::Owned(v.to_string()))
+    }
+
+    #[inline]
+    fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(std::borrow::Cow::Owned(v))
+    }
+}
+
+impl<'de> serde::de::DeserializeSeed<'de> for CibouletteCowKeyVisitor {
+    type Value = std::borrow::Cow<'de, str>;
+
+    #[inline]
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_str(self)
+    }
+}
+
+/// ## Map accessor skipping the `@`-members
+///
+/// `JSON:API` processors should ignore them.
+pub struct CibouletteAtMemberFilter<A> {
+    inner: A,
+}
+
+impl<A> CibouletteAtMemberFilter<A> {
+    pub fn new(inner: A) -> Self {
+        CibouletteAtMemberFilter { inner }
+    }
+}
+
+impl<'de, A: serde::de::MapAccess<'de>> serde::de::MapAccess<'de> for CibouletteAtMemberFilter<A> {
+    type Error = A::Error;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error>
+    where
+        K: serde::de::DeserializeSeed<'de>,
+    {
+        while let Some(key) = self.inner.next_key_seed(CibouletteCowKeyVisitor)? {
+            if key.starts_with('@') {
+                self.inner.next_value::<serde::de::IgnoredAny>()?;
+                continue;
+            }
+            return match key {
+                std::borrow::Cow::Borrowed(key) => seed
+                    .deserialize(serde::de::value::BorrowedStrDeserializer::<A::Error>::new(
+                        key,
+                    ))
+                    .map(Some),
+                std::borrow::Cow::Owned(key) => seed
+                    .deserialize(serde::de::IntoDeserializer::<A::Error>::into_deserializer(
+                        key,
+                    ))
+                    .map(Some),
+            };
+        }
+        Ok(None)
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Self::Error>
+    where
+        V: serde::de::DeserializeSeed<'de>,
+    {
+        self.inner.next_value_seed(seed)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.inner.size_hint()
+    }
+}
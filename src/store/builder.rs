@@ -14,8 +14,22 @@ pub struct CibouletteStoreBuilder {
     /// A map of every [CibouletteResourceType](CibouletteResourceType) in the graph
     /// mapped by their alias
     pub(crate) map: BTreeMap<String, petgraph::graph::NodeIndex<u16>>,
+    /// A map of every [CibouletteResourceType](CibouletteResourceType) in the graph
+    /// mapped by their public name and public aliases
+    pub(crate) public_map: BTreeMap<String, petgraph::graph::NodeIndex<u16>>,
+    /// The custom id types, mapped by their name
+    pub(crate) id_types: BTreeMap<ArcStr, CibouletteCustomIdType>,
+    /// The extensions, mapped by their URI
+    pub(crate) extensions: BTreeMap<ArcStr, Arc<dyn CibouletteExtension>>,
+    /// The URIs of the profiles
+    pub(crate) profiles: BTreeSet<ArcStr>,
+    /// The custom query parameters accepted for every type, mapped by their name
+    pub(crate) query_parameters: BTreeMap<ArcStr, CibouletteCustomQueryParameter>,
     /// The configuration
     pub(crate) config: CibouletteConfig,
+    /// The case convention of the member names exchanged with the clients
+    #[getset(get_mut = "pub")]
+    pub(crate) naming_policy: CibouletteNamingPolicy,
 }
 
 impl Default for CibouletteStoreBuilder {
@@ -24,7 +38,13 @@ impl Default for CibouletteStoreBuilder {
         CibouletteStoreBuilder {
             graph: petgraph::graph::Graph::with_capacity(0, 0),
             map: BTreeMap::new(),
+            public_map: BTreeMap::new(),
+            id_types: BTreeMap::new(),
+            extensions: BTreeMap::new(),
+            profiles: BTreeSet::new(),
+            query_parameters: BTreeMap::new(),
             config: CibouletteConfig::default(),
+            naming_policy: CibouletteNamingPolicy::default(),
         }
     }
 }
@@ -37,7 +57,252 @@ impl CibouletteStoreBuilder {
             config,
             graph: petgraph::graph::Graph::default(),
             map: BTreeMap::default(),
+            public_map: BTreeMap::default(),
+            id_types: BTreeMap::default(),
+            extensions: BTreeMap::default(),
+            profiles: BTreeSet::default(),
+            query_parameters: BTreeMap::default(),
+            naming_policy: CibouletteNamingPolicy::default(),
+        }
+    }
+
+    /// Register a custom id type parser
+    pub fn add_id_type(
+        &mut self,
+        parser: Arc<dyn CibouletteIdParser>,
+    ) -> Result<(), CibouletteError> {
+        let name = ArcStr::from(parser.name());
+        if self.id_types.contains_key(&name) {
+            return Err(CibouletteError::UniqIdType(name.to_string()));
         }
+        self.id_types
+            .insert(name, CibouletteCustomIdType::new(parser));
+        Ok(())
+    }
+
+    /// Build a custom [id type](CibouletteIdType) from a registered parser
+    pub fn custom_id_type(
+        &self,
+        field: ArcStr,
+        name: &str,
+    ) -> Result<CibouletteIdType, CibouletteError> {
+        let type_ = self
+            .id_types
+            .get(name)
+            .ok_or_else(|| CibouletteError::UnknownIdType(name.to_string()))?;
+        Ok(CibouletteIdType::Custom(field, type_.clone()))
+    }
+
+    /// Register a `JSON:API` extension
+    pub fn add_extension(
+        &mut self,
+        extension: Arc<dyn CibouletteExtension>,
+    ) -> Result<(), CibouletteError> {
+        if !crate::member_name::check_extension_namespace(extension.namespace()) {
+            return Err(CibouletteError::InvalidMemberName(
+                extension.namespace().to_string(),
+            ));
+        }
+        for member in extension
+            .document_members()
+            .iter()
+            .chain(extension.resource_members())
+        {
+            if !check_member_name(member) {
+                return Err(CibouletteError::InvalidMemberName(format!(
+                    "{}:{}",
+                    extension.namespace(),
+                    member
+                )));
+            }
+        }
+        if self.extensions.contains_key(extension.uri())
+            || self
+                .extensions
+                .values()
+                .any(|x| x.namespace() == extension.namespace())
+        {
+            return Err(CibouletteError::UniqExtension(extension.uri().to_string()));
+        }
+        self.extensions
+            .insert(ArcStr::from(extension.uri()), extension);
+        Ok(())
+    }
+
+    /// Register a `JSON:API` profile by its URI
+    pub fn add_profile(&mut self, uri: &str) -> Result<(), CibouletteError> {
+        if !self.profiles.insert(ArcStr::from(uri)) {
+            return Err(CibouletteError::UniqProfile(uri.to_string()));
+        }
+        Ok(())
+    }
+
+    /// Register a custom query parameter accepted for every type
+    pub fn add_query_parameter(
+        &mut self,
+        name: &str,
+        validator: Option<Arc<dyn CibouletteQueryParameterValidator>>,
+    ) -> Result<(), CibouletteError> {
+        let param = CibouletteCustomQueryParameter::new(name, validator)?;
+        if self.query_parameters.contains_key(name) {
+            return Err(CibouletteError::UniqQueryParameter(name.to_string()));
+        }
+        self.query_parameters.insert(param.name().clone(), param);
+        Ok(())
+    }
+
+    /// Register a custom query parameter accepted for a single type
+    pub fn add_type_query_parameter(
+        &mut self,
+        type_: &str,
+        name: &str,
+        validator: Option<Arc<dyn CibouletteQueryParameterValidator>>,
+    ) -> Result<(), CibouletteError> {
+        let param = CibouletteCustomQueryParameter::new(name, validator)?;
+        let index = *self
+            .map
+            .get(type_)
+            .ok_or_else(|| CibouletteError::UnknownType(type_.to_string()))?;
+        let resource_type = self
+            .graph
+            .node_weight_mut(index)
+            .ok_or_else(|| CibouletteError::TypeNotInGraph(type_.to_string()))?;
+        if resource_type.query_parameters().contains_key(name) {
+            return Err(CibouletteError::UniqQueryParameter(name.to_string()));
+        }
+        resource_type
+            .query_parameters_mut()
+            .insert(param.name().clone(), param);
+        Ok(())
+    }
+
+    /// Set the access policy of an attribute of a type
+    pub fn set_attribute_policy(
+        &mut self,
+        type_: &str,
+        attribute: &str,
+        policy: CibouletteAttributePolicy,
+    ) -> Result<(), CibouletteError> {
+        let index = *self
+            .map
+            .get(type_)
+            .ok_or_else(|| CibouletteError::UnknownType(type_.to_string()))?;
+        let resource_type = self
+            .graph
+            .node_weight_mut(index)
+            .ok_or_else(|| CibouletteError::TypeNotInGraph(type_.to_string()))?;
+        let attribute = match resource_type.schema().properties().get_key_value(attribute) {
+            Some((k, _)) => k.clone(),
+            None => {
+                return Err(CibouletteError::UnknownField(
+                    type_.to_string(),
+                    attribute.to_string(),
+                ))
+            }
+        };
+        match policy {
+            CibouletteAttributePolicy::ReadWrite => {
+                resource_type.attribute_policies_mut().remove(&attribute);
+            }
+            _ => {
+                resource_type
+                    .attribute_policies_mut()
+                    .insert(attribute, policy);
+            }
+        }
+        Ok(())
+    }
+
+    /// Add a constraint on the values of an attribute of a type
+    pub fn add_attribute_constraint(
+        &mut self,
+        type_: &str,
+        attribute: &str,
+        constraint: CibouletteAttributeConstraint,
+    ) -> Result<(), CibouletteError> {
+        let index = *self
+            .map
+            .get(type_)
+            .ok_or_else(|| CibouletteError::UnknownType(type_.to_string()))?;
+        let resource_type = self
+            .graph
+            .node_weight_mut(index)
+            .ok_or_else(|| CibouletteError::TypeNotInGraph(type_.to_string()))?;
+        let attribute = match resource_type.schema().properties().get_key_value(attribute) {
+            Some((k, _)) => k.clone(),
+            None => {
+                return Err(CibouletteError::UnknownField(
+                    type_.to_string(),
+                    attribute.to_string(),
+                ))
+            }
+        };
+        resource_type
+            .constraints_mut()
+            .entry(attribute)
+            .or_default()
+            .push(constraint);
+        Ok(())
+    }
+
+    /// Restrict the operations allowed on a relationship of a type, by alias
+    pub fn set_relationship_capabilities(
+        &mut self,
+        type_: &str,
+        alias: &str,
+        capabilities: CibouletteRelationshipCapabilities,
+    ) -> Result<(), CibouletteError> {
+        let index = *self
+            .map
+            .get(type_)
+            .ok_or_else(|| CibouletteError::UnknownType(type_.to_string()))?;
+        let resource_type = self
+            .graph
+            .node_weight_mut(index)
+            .ok_or_else(|| CibouletteError::TypeNotInGraph(type_.to_string()))?;
+        let alias = match resource_type.relationships().get_key_value(alias) {
+            Some((k, _)) => k.clone(),
+            None => {
+                return Err(CibouletteError::UnknownRelationship(
+                    type_.to_string(),
+                    alias.to_string(),
+                ))
+            }
+        };
+        resource_type
+            .relationships_capabilities_mut()
+            .insert(alias, capabilities);
+        Ok(())
+    }
+
+    /// Override the policy applied to the unknown members of the resources of a type
+    pub fn set_unknown_members_policy(
+        &mut self,
+        type_: &str,
+        policy: CibouletteUnknownMembersPolicy,
+    ) -> Result<(), CibouletteError> {
+        let index = *self
+            .map
+            .get(type_)
+            .ok_or_else(|| CibouletteError::UnknownType(type_.to_string()))?;
+        let resource_type = self
+            .graph
+            .node_weight_mut(index)
+            .ok_or_else(|| CibouletteError::TypeNotInGraph(type_.to_string()))?;
+        *resource_type.unknown_members_policy_mut() = Some(policy);
+        Ok(())
+    }
+
+    /// Check that the custom id types of a selector are registered in the store
+    fn check_id_types(&self, ids: &CibouletteIdTypeSelector) -> Result<(), CibouletteError> {
+        for id_type in ids.iter() {
+            if let CibouletteIdType::Custom(_, type_) = id_type {
+                if !matches!(self.id_types.get(type_.name()), Some(x) if x == type_) {
+                    return Err(CibouletteError::UnknownIdType(type_.name().to_string()));
+                }
+            }
+        }
+        Ok(())
     }
 
     /// Get a type index from the graph
@@ -113,16 +378,97 @@ impl CibouletteStoreBuilder {
         name: &str,
         ids: CibouletteIdTypeSelector,
         schema: MessyJsonObject,
+    ) -> Result<(), CibouletteError> {
+        self.add_type_with_capabilities(name, ids, schema, CibouletteTypeCapabilities::default())
+    }
+
+    /// Add a type to the graph, restricting the operations allowed on its resources
+    pub fn add_type_with_capabilities(
+        &mut self,
+        name: &str,
+        ids: CibouletteIdTypeSelector,
+        schema: MessyJsonObject,
+        capabilities: CibouletteTypeCapabilities,
     ) -> Result<(), CibouletteError> {
         let name = name.to_string();
-        if self.map.contains_key(name.as_str())
+        if self.map.contains_key(name.as_str()) || self.public_map.contains_key(name.as_str())
         // Check if type exists
         {
             return Err(CibouletteError::UniqType(name));
         }
-        let t = CibouletteResourceTypeBuilder::new(name.clone(), ids, schema).build()?;
+        self.check_id_types(&ids)?;
+        let mut t = CibouletteResourceTypeBuilder::new(name.clone(), ids, schema).build()?;
+        *t.capabilities_mut() = capabilities;
         let index = self.graph.add_node(t); // Add the node
-        self.map.insert(name, index); // Save the index to the map
+        self.map.insert(name.clone(), index); // Save the index to the map
+        self.public_map.insert(name, index); // By default, the public name is the type name
+        Ok(())
+    }
+
+    /// Get a type from the graph by its public name or one of its public aliases
+    pub fn get_public_type(&self, name: &str) -> Result<&CibouletteResourceType, CibouletteError> {
+        self.public_map
+            .get(name)
+            .and_then(|x| self.graph.node_weight(*x))
+            .ok_or_else(|| CibouletteError::UnknownType(name.to_string()))
+    }
+
+    /// Reserve a public name for a type, checking it's valid and not already in use
+    fn reserve_public_name(
+        &mut self,
+        index: petgraph::graph::NodeIndex<u16>,
+        public_name: &str,
+    ) -> Result<(), CibouletteError> {
+        if !crate::member_name::check_member_name(public_name) {
+            return Err(CibouletteError::InvalidMemberName(public_name.to_string()));
+        }
+        match self.public_map.get(public_name) {
+            Some(x) if *x != index => Err(CibouletteError::UniqType(public_name.to_string())),
+            _ => {
+                self.public_map.insert(public_name.to_string(), index);
+                Ok(())
+            }
+        }
+    }
+
+    /// Set the name used for a type in the requests and responses.
+    ///
+    /// The previous public name is not accepted anymore
+    pub fn set_public_name(
+        &mut self,
+        name: &str,
+        public_name: &str,
+    ) -> Result<(), CibouletteError> {
+        let index = *self
+            .map
+            .get(name)
+            .ok_or_else(|| CibouletteError::UnknownType(name.to_string()))?;
+        self.reserve_public_name(index, public_name)?;
+        let type_ = self
+            .graph
+            .node_weight_mut(index)
+            .ok_or_else(|| CibouletteError::TypeNotInGraph(name.to_string()))?;
+        let old_public_name = std::mem::replace(type_.public_name_mut(), ArcStr::from(public_name));
+        if old_public_name.as_str() != public_name
+            && !type_.public_aliases().contains(old_public_name.as_str())
+        {
+            self.public_map.remove(old_public_name.as_str());
+        }
+        Ok(())
+    }
+
+    /// Add another public name accepted in requests for a type
+    pub fn add_public_alias(&mut self, name: &str, alias: &str) -> Result<(), CibouletteError> {
+        let index = *self
+            .map
+            .get(name)
+            .ok_or_else(|| CibouletteError::UnknownType(name.to_string()))?;
+        self.reserve_public_name(index, alias)?;
+        let type_ = self
+            .graph
+            .node_weight_mut(index)
+            .ok_or_else(|| CibouletteError::TypeNotInGraph(name.to_string()))?;
+        type_.public_aliases_mut().insert(ArcStr::from(alias));
         Ok(())
     }
 
@@ -139,7 +485,9 @@ impl CibouletteStoreBuilder {
         );
 
         for node in self.graph().raw_nodes() {
-            tmp_graph.add_node(Arc::new(node.weight.clone()));
+            let mut type_ = node.weight.clone();
+            type_.apply_naming_policy(self.naming_policy)?;
+            tmp_graph.add_node(Arc::new(type_));
         }
         for edge in self.graph().raw_edges() {
             tmp_graph.add_edge(
@@ -150,6 +498,16 @@ impl CibouletteStoreBuilder {
         }
         Ok(CibouletteStore {
             config: self.config,
+            naming_policy: self.naming_policy,
+            id_types: self.id_types,
+            extensions: self.extensions,
+            profiles: self.profiles,
+            query_parameters: self.query_parameters,
+            public_map: self
+                .public_map
+                .into_iter()
+                .map(|(k, v)| (ArcStr::from(k), v))
+                .collect(),
             map: self
                 .map
                 .into_iter()
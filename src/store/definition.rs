@@ -0,0 +1,637 @@
+use super::*;
+use std::ops::Deref;
+
+/// ## Declarative definition of a [store](CibouletteStore)
+///
+/// It can be deserialized from any format supported by `serde` (i.e. `JSON`, `YAML` or `TOML`),
+/// loaded in a [store builder](CibouletteStoreBuilder::add_definition) and dumped back from a
+/// [store](CibouletteStore::to_definition).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, Getters, MutGetters)]
+#[getset(get = "pub", get_mut = "pub")]
+#[serde(deny_unknown_fields)]
+pub struct CibouletteStoreDefinition {
+    /// The types, by name
+    #[serde(default)]
+    types: BTreeMap<String, CibouletteTypeDefinition>,
+    /// The relationships between the types
+    #[serde(default)]
+    relationships: Vec<CibouletteRelationshipDefinition>,
+}
+
+/// ## Declarative definition of a [resource type](CibouletteResourceType)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, Getters, MutGetters)]
+#[getset(get = "pub", get_mut = "pub")]
+#[serde(deny_unknown_fields)]
+pub struct CibouletteTypeDefinition {
+    /// The ids of the type, more than one making a compound id
+    ids: Vec<CibouletteIdDefinition>,
+    /// The attributes of the type, by name
+    #[serde(default)]
+    attributes: BTreeMap<String, CibouletteAttributeDefinition>,
+}
+
+/// ## Declarative definition of an [id type](CibouletteIdType)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
+pub enum CibouletteIdDefinition {
+    Number {
+        name: String,
+    },
+    Text {
+        name: String,
+    },
+    Uuid {
+        name: String,
+    },
+    /// An id handled by a [custom parser](CibouletteIdParser), registered in the
+    /// [store builder](CibouletteStoreBuilder::add_id_type) before loading the definition
+    Custom {
+        name: String,
+        parser: String,
+    },
+}
+
+/// ## Declarative definition of an attribute schema
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
+pub enum CibouletteAttributeDefinition {
+    String {
+        #[serde(default)]
+        optional: bool,
+    },
+    Number {
+        #[serde(default)]
+        format: CibouletteNumberFormat,
+        #[serde(default)]
+        optional: bool,
+    },
+    Bool {
+        #[serde(default)]
+        optional: bool,
+    },
+    Uuid {
+        #[serde(default)]
+        optional: bool,
+    },
+    Array {
+        items: Box<CibouletteAttributeDefinition>,
+        #[serde(default)]
+        optional: bool,
+    },
+    Object {
+        #[serde(default)]
+        properties: BTreeMap<String, CibouletteAttributeDefinition>,
+        #[serde(default)]
+        optional: bool,
+    },
+}
+
+/// ## Format of a numeric attribute
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CibouletteNumberFormat {
+    U8,
+    U16,
+    U32,
+    U64,
+    U128,
+    I8,
+    I16,
+    I32,
+    I64,
+    I128,
+}
+
+impl Default for CibouletteNumberFormat {
+    fn default() -> Self {
+        CibouletteNumberFormat::U64
+    }
+}
+
+/// ## Declarative definition of a relationship between two types
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case", deny_unknown_fields)]
+pub enum CibouletteRelationshipDefinition {
+    /// A One-to-Many/Many-to-One relationship
+    ///
+    /// The key of the "one" end is its field pointed by the "many" resources, the key of
+    /// the "many" end is its field pointing to the "one" resource.
+    OneToMany {
+        one: CibouletteRelationshipEndDefinition,
+        many: CibouletteRelationshipEndDefinition,
+        #[serde(default)]
+        optional: bool,
+    },
+    /// A Many-to-Many relationship, going through a bucket type
+    ///
+    /// The key of each end is the field of the bucket type pointing to it.
+    ManyToMany {
+        bucket: String,
+        from: CibouletteRelationshipEndDefinition,
+        to: CibouletteRelationshipEndDefinition,
+    },
+}
+
+/// ## Declarative definition of an end of a relationship
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Getters, MutGetters, CopyGetters)]
+#[serde(deny_unknown_fields)]
+pub struct CibouletteRelationshipEndDefinition {
+    /// The type at this end
+    #[serde(rename = "type")]
+    #[getset(get = "pub", get_mut = "pub")]
+    type_: String,
+    /// The relating field
+    #[getset(get = "pub", get_mut = "pub")]
+    key: String,
+    /// The name of the relationship on the type at this end, defaulting to the builder's default alias
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[getset(get = "pub", get_mut = "pub")]
+    alias: Option<String>,
+    /// False if the relationship can't be navigated from this end
+    #[serde(default = "default_exposed", skip_serializing_if = "is_exposed")]
+    #[getset(get_copy = "pub", get_mut = "pub")]
+    exposed: bool,
+}
+
+fn default_exposed() -> bool {
+    true
+}
+
+fn is_exposed(exposed: &bool) -> bool {
+    *exposed
+}
+
+impl CibouletteRelationshipEndDefinition {
+    /// Create a new exposed relationship end
+    pub fn new(type_: String, key: String, alias: Option<String>) -> Self {
+        CibouletteRelationshipEndDefinition {
+            type_,
+            key,
+            alias,
+            exposed: true,
+        }
+    }
+
+    /// The alias of the relationship if exposed at this end
+    fn exposed_alias(&self) -> Option<Option<&str>> {
+        match self.exposed {
+            true => Some(self.alias.as_deref()),
+            false => None,
+        }
+    }
+}
+
+impl CibouletteTypeDefinition {
+    /// Create a new type definition
+    pub fn new(
+        ids: Vec<CibouletteIdDefinition>,
+        attributes: BTreeMap<String, CibouletteAttributeDefinition>,
+    ) -> Self {
+        CibouletteTypeDefinition { ids, attributes }
+    }
+}
+
+impl std::fmt::Display for CibouletteNumberFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            CibouletteNumberFormat::U8 => "u8",
+            CibouletteNumberFormat::U16 => "u16",
+            CibouletteNumberFormat::U32 => "u32",
+            CibouletteNumberFormat::U64 => "u64",
+            CibouletteNumberFormat::U128 => "u128",
+            CibouletteNumberFormat::I8 => "i8",
+            CibouletteNumberFormat::I16 => "i16",
+            CibouletteNumberFormat::I32 => "i32",
+            CibouletteNumberFormat::I64 => "i64",
+            CibouletteNumberFormat::I128 => "i128",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl CibouletteNumberFormat {
+    fn to_messy_json(self) -> MessyJsonNumberType {
+        match self {
+            CibouletteNumberFormat::U8 => MessyJsonNumberType::U8,
+            CibouletteNumberFormat::U16 => MessyJsonNumberType::U16,
+            CibouletteNumberFormat::U32 => MessyJsonNumberType::U32,
+            CibouletteNumberFormat::U64 => MessyJsonNumberType::U64,
+            CibouletteNumberFormat::U128 => MessyJsonNumberType::U128,
+            CibouletteNumberFormat::I8 => MessyJsonNumberType::I8,
+            CibouletteNumberFormat::I16 => MessyJsonNumberType::I16,
+            CibouletteNumberFormat::I32 => MessyJsonNumberType::I32,
+            CibouletteNumberFormat::I64 => MessyJsonNumberType::I64,
+            CibouletteNumberFormat::I128 => MessyJsonNumberType::I128,
+        }
+    }
+
+    fn from_messy_json(type_: &MessyJsonNumberType) -> Option<Self> {
+        #[allow(unreachable_patterns)]
+        match type_ {
+            MessyJsonNumberType::U8 => Some(CibouletteNumberFormat::U8),
+            MessyJsonNumberType::U16 => Some(CibouletteNumberFormat::U16),
+            MessyJsonNumberType::U32 => Some(CibouletteNumberFormat::U32),
+            MessyJsonNumberType::U64 => Some(CibouletteNumberFormat::U64),
+            MessyJsonNumberType::U128 => Some(CibouletteNumberFormat::U128),
+            MessyJsonNumberType::I8 => Some(CibouletteNumberFormat::I8),
+            MessyJsonNumberType::I16 => Some(CibouletteNumberFormat::I16),
+            MessyJsonNumberType::I32 => Some(CibouletteNumberFormat::I32),
+            MessyJsonNumberType::I64 => Some(CibouletteNumberFormat::I64),
+            MessyJsonNumberType::I128 => Some(CibouletteNumberFormat::I128),
+            _ => None,
+        }
+    }
+}
+
+impl CibouletteAttributeDefinition {
+    /// Set whether the attribute is optional
+    pub fn with_optional(mut self, optional: bool) -> Self {
+        match &mut self {
+            CibouletteAttributeDefinition::String { optional: x }
+            | CibouletteAttributeDefinition::Number { optional: x, .. }
+            | CibouletteAttributeDefinition::Bool { optional: x }
+            | CibouletteAttributeDefinition::Uuid { optional: x }
+            | CibouletteAttributeDefinition::Array { optional: x, .. }
+            | CibouletteAttributeDefinition::Object { optional: x, .. } => *x = optional,
+        }
+        self
+    }
+
+    /// Build the schema of an attribute
+    fn to_messy_json(&self) -> MessyJson {
+        match self {
+            CibouletteAttributeDefinition::String { optional } => {
+                MessyJson::from(MessyJsonInner::String(MessyJsonScalar::new(*optional)))
+            }
+            CibouletteAttributeDefinition::Number { format, optional } => MessyJson::from(
+                MessyJsonInner::Number(MessyJsonNumeric::new(format.to_messy_json(), *optional)),
+            ),
+            CibouletteAttributeDefinition::Bool { optional } => {
+                MessyJson::from(MessyJsonInner::Bool(MessyJsonScalar::new(*optional)))
+            }
+            CibouletteAttributeDefinition::Uuid { optional } => {
+                MessyJson::from(MessyJsonInner::Uuid(MessyJsonScalar::new(*optional)))
+            }
+            CibouletteAttributeDefinition::Array { items, optional } => {
+                MessyJson::from(MessyJsonInner::Array(MessyJsonArray::from(
+                    MessyJsonArrayInner::new(items.to_messy_json(), *optional),
+                )))
+            }
+            CibouletteAttributeDefinition::Object {
+                properties,
+                optional,
+            } => MessyJson::from(MessyJsonInner::Obj(Self::object_to_messy_json(
+                properties, *optional,
+            ))),
+        }
+    }
+
+    /// Build the schema of an object
+    fn object_to_messy_json(
+        properties: &BTreeMap<String, CibouletteAttributeDefinition>,
+        optional: bool,
+    ) -> MessyJsonObject {
+        MessyJsonObject::from(MessyJsonObjectInner::new(
+            properties
+                .iter()
+                .map(|(k, v)| (ArcStr::from(k.as_str()), v.to_messy_json()))
+                .collect(),
+            optional,
+        ))
+    }
+
+    /// Describe the schema of an attribute, located by `path` in the definition
+    pub(crate) fn from_messy_json(value: &MessyJson, path: &str) -> Result<Self, CibouletteError> {
+        #[allow(unreachable_patterns)]
+        match value.deref() {
+            MessyJsonInner::String(x) => Ok(CibouletteAttributeDefinition::String {
+                optional: x.optional(),
+            }),
+            MessyJsonInner::Number(x) => Ok(CibouletteAttributeDefinition::Number {
+                format: CibouletteNumberFormat::from_messy_json(&x.type_()).ok_or_else(|| {
+                    CibouletteError::StoreDefinition(
+                        path.to_string(),
+                        Box::new(CibouletteError::UnsupportedSchema),
+                    )
+                })?,
+                optional: x.optional(),
+            }),
+            MessyJsonInner::Bool(x) => Ok(CibouletteAttributeDefinition::Bool {
+                optional: x.optional(),
+            }),
+            MessyJsonInner::Uuid(x) => Ok(CibouletteAttributeDefinition::Uuid {
+                optional: x.optional(),
+            }),
+            MessyJsonInner::Array(x) => Ok(CibouletteAttributeDefinition::Array {
+                items: Box::new(Self::from_messy_json(
+                    x.items(),
+                    &format!("{}.items", path),
+                )?),
+                optional: x.optional(),
+            }),
+            MessyJsonInner::Obj(x) => Ok(CibouletteAttributeDefinition::Object {
+                properties: Self::properties_from_messy_json(x, &format!("{}.properties", path))?,
+                optional: x.optional(),
+            }),
+            _ => Err(CibouletteError::StoreDefinition(
+                path.to_string(),
+                Box::new(CibouletteError::UnsupportedSchema),
+            )),
+        }
+    }
+
+    /// Describe the properties of an object schema, located by `path` in the definition
+    pub(crate) fn properties_from_messy_json(
+        obj: &MessyJsonObject,
+        path: &str,
+    ) -> Result<BTreeMap<String, CibouletteAttributeDefinition>, CibouletteError> {
+        let mut res = BTreeMap::new();
+        for (name, value) in obj.properties().iter() {
+            res.insert(
+                name.to_string(),
+                Self::from_messy_json(value, &format!("{}.{}", path, name))?,
+            );
+        }
+        Ok(res)
+    }
+}
+
+/// Wrap an error with its location in the definition
+fn at<T>(path: &str, res: Result<T, CibouletteError>) -> Result<T, CibouletteError> {
+    res.map_err(|err| CibouletteError::StoreDefinition(path.to_string(), Box::new(err)))
+}
+
+impl CibouletteStoreBuilder {
+    /// ## Load a [store definition](CibouletteStoreDefinition)
+    ///
+    /// The types are added first, then the relationships in order. The errors are
+    /// located in the definition, i.e. `relationships.2.many.type`.
+    pub fn add_definition(
+        &mut self,
+        definition: &CibouletteStoreDefinition,
+    ) -> Result<(), CibouletteError> {
+        for (name, type_) in definition.types() {
+            self.add_type_definition(name, type_)?;
+        }
+        for (i, relationship) in definition.relationships().iter().enumerate() {
+            self.add_relationship_definition(&format!("relationships.{}", i), relationship)?;
+        }
+        Ok(())
+    }
+
+    /// Add a type from its definition
+    pub(crate) fn add_type_definition(
+        &mut self,
+        name: &str,
+        definition: &CibouletteTypeDefinition,
+    ) -> Result<(), CibouletteError> {
+        let path = format!("types.{}", name);
+        let mut ids = Vec::with_capacity(definition.ids().len());
+        for (i, id) in definition.ids().iter().enumerate() {
+            ids.push(match id {
+                CibouletteIdDefinition::Number { name } => {
+                    CibouletteIdType::Number(ArcStr::from(name.as_str()))
+                }
+                CibouletteIdDefinition::Text { name } => {
+                    CibouletteIdType::Text(ArcStr::from(name.as_str()))
+                }
+                CibouletteIdDefinition::Uuid { name } => {
+                    CibouletteIdType::Uuid(ArcStr::from(name.as_str()))
+                }
+                CibouletteIdDefinition::Custom { name, parser } => at(
+                    &format!("{}.ids.{}.parser", path, i),
+                    self.custom_id_type(ArcStr::from(name.as_str()), parser),
+                )?,
+            });
+        }
+        let ids = match ids.len() {
+            0 => return at(&format!("{}.ids", path), Err(CibouletteError::MissingId)),
+            1 => CibouletteSelector::Single(ids.remove(0)),
+            _ => CibouletteSelector::Multi(ids),
+        };
+        at(
+            &path,
+            self.add_type(
+                name,
+                CibouletteIdTypeSelector::new(ids),
+                CibouletteAttributeDefinition::object_to_messy_json(definition.attributes(), false),
+            ),
+        )
+    }
+
+    /// Add a relationship from its definition, located by `path`
+    pub(crate) fn add_relationship_definition(
+        &mut self,
+        path: &str,
+        definition: &CibouletteRelationshipDefinition,
+    ) -> Result<(), CibouletteError> {
+        match definition {
+            CibouletteRelationshipDefinition::OneToMany {
+                one,
+                many,
+                optional,
+            } => {
+                let opt = CibouletteRelationshipOneToManyOptionBuilder::new(
+                    at(&format!("{}.one.type", path), self.get_type(one.type_()))?.clone(),
+                    ArcStr::from(one.key().as_str()),
+                    at(&format!("{}.many.type", path), self.get_type(many.type_()))?.clone(),
+                    ArcStr::from(many.key().as_str()),
+                    *optional,
+                );
+                // The alias of each end is the name used on its type for the other end
+                let res = match (one.exposed_alias(), many.exposed_alias()) {
+                    (Some(one_alias), Some(many_alias)) => self.add_one_to_many_rel(
+                        opt,
+                        many_alias.map(ArcStr::from),
+                        one_alias.map(ArcStr::from),
+                    ),
+                    (Some(one_alias), None) => {
+                        self.add_one_to_many_rel_no_reverse(opt, one_alias.map(ArcStr::from))
+                    }
+                    (None, Some(many_alias)) => {
+                        self.add_many_to_one_rel_no_reverse(opt, many_alias.map(ArcStr::from))
+                    }
+                    (None, None) => Err(CibouletteError::UnexposedRelationship(
+                        one.type_().clone(),
+                        many.type_().clone(),
+                    )),
+                };
+                at(path, res)
+            }
+            CibouletteRelationshipDefinition::ManyToMany { bucket, from, to } => {
+                let bucket = at(&format!("{}.bucket", path), self.get_type(bucket))?.clone();
+                let from_key = (
+                    at(&format!("{}.from.type", path), self.get_type(from.type_()))?.clone(),
+                    ArcStr::from(from.key().as_str()),
+                );
+                let to_key = (
+                    at(&format!("{}.to.type", path), self.get_type(to.type_()))?.clone(),
+                    ArcStr::from(to.key().as_str()),
+                );
+                let opt = CibouletteRelationshipManyToManyOptionBuilder::new(
+                    bucket.clone(),
+                    [from_key.clone(), to_key.clone()],
+                );
+                let res = match (from.exposed_alias(), to.exposed_alias()) {
+                    (Some(from_alias), Some(to_alias)) => self.add_many_to_many_rel(
+                        (from.type_().as_str(), to_alias),
+                        (to.type_().as_str(), from_alias),
+                        opt,
+                    ),
+                    (Some(from_alias), None) => self.add_many_to_many_rel_no_reverse(
+                        from.type_(),
+                        (to.type_().as_str(), from_alias),
+                        opt,
+                    ),
+                    // The keys are listed from the end holding the relationship, for
+                    // self-referential relationships to resolve them by side
+                    (None, Some(to_alias)) => self.add_many_to_many_rel_no_reverse(
+                        to.type_(),
+                        (from.type_().as_str(), to_alias),
+                        CibouletteRelationshipManyToManyOptionBuilder::new(
+                            bucket,
+                            [to_key, from_key],
+                        ),
+                    ),
+                    (None, None) => Err(CibouletteError::UnexposedRelationship(
+                        from.type_().clone(),
+                        to.type_().clone(),
+                    )),
+                };
+                at(path, res)
+            }
+        }
+    }
+}
+
+impl CibouletteStore {
+    /// ## Dump the store as a [definition](CibouletteStoreDefinition)
+    ///
+    /// Every alias is written explicitly. The extensions, profiles, query parameters and
+    /// policies aren't part of the definition.
+    pub fn to_definition(&self) -> Result<CibouletteStoreDefinition, CibouletteError> {
+        let mut types: BTreeMap<String, CibouletteTypeDefinition> = BTreeMap::new();
+        // The ends of the relationships, by (one type, one key, many type, many key, optional)
+        let mut one_to_many: BTreeMap<(String, String, String, String, bool), [Option<String>; 2]> =
+            BTreeMap::new();
+        // The ends of the relationships, by (bucket, (type, key) of both ends)
+        let mut many_to_many: BTreeMap<(String, [(String, String); 2]), [Option<String>; 2]> =
+            BTreeMap::new();
+
+        for node in self.graph().raw_nodes() {
+            let type_ = &node.weight;
+            let path = format!("types.{}", type_.name());
+            let ids = type_
+                .ids()
+                .iter()
+                .map(|id| match id {
+                    CibouletteIdType::Number(name) => CibouletteIdDefinition::Number {
+                        name: name.to_string(),
+                    },
+                    CibouletteIdType::Text(name) => CibouletteIdDefinition::Text {
+                        name: name.to_string(),
+                    },
+                    CibouletteIdType::Uuid(name) => CibouletteIdDefinition::Uuid {
+                        name: name.to_string(),
+                    },
+                    CibouletteIdType::Custom(name, parser) => CibouletteIdDefinition::Custom {
+                        name: name.to_string(),
+                        parser: parser.name().to_string(),
+                    },
+                })
+                .collect();
+            let attributes = CibouletteAttributeDefinition::properties_from_messy_json(
+                type_.schema(),
+                &format!("{}.attributes", path),
+            )?;
+            types.insert(
+                type_.name().to_string(),
+                CibouletteTypeDefinition { ids, attributes },
+            );
+
+            for (alias, edge_i) in type_.relationships() {
+                let edge = self.graph().edge_weight(*edge_i).ok_or_else(|| {
+                    CibouletteError::RelNotInGraph(type_.name().to_string(), alias.to_string())
+                })?;
+                match edge {
+                    CibouletteRelationshipOption::OneToMany(opt)
+                    | CibouletteRelationshipOption::ManyToOne(opt) => {
+                        if opt.part_of_many_to_many().is_some() {
+                            continue;
+                        }
+                        let ends = one_to_many
+                            .entry((
+                                opt.one_resource().name().to_string(),
+                                opt.one_resource_key().to_string(),
+                                opt.many_resource().name().to_string(),
+                                opt.many_resource_key().to_string(),
+                                *opt.optional(),
+                            ))
+                            .or_default();
+                        let side = match edge {
+                            CibouletteRelationshipOption::OneToMany(_) => 0,
+                            _ => 1,
+                        };
+                        ends[side] = Some(alias.to_string());
+                    }
+                    CibouletteRelationshipOption::ManyToMany(opt) => {
+                        let mut keys = [
+                            (
+                                opt.keys()[0].0.name().to_string(),
+                                opt.keys()[0].1.to_string(),
+                            ),
+                            (
+                                opt.keys()[1].0.name().to_string(),
+                                opt.keys()[1].1.to_string(),
+                            ),
+                        ];
+                        keys.sort();
+                        // Resolved by key, both ends of self-referential relationships
+                        // being of the same type
+                        let source = (type_.name().to_string(), opt.source_key().to_string());
+                        let side = match keys[0] == source {
+                            true => 0,
+                            false => 1,
+                        };
+                        many_to_many
+                            .entry((opt.bucket_resource().name().to_string(), keys))
+                            .or_default()[side] = Some(alias.to_string());
+                    }
+                }
+            }
+        }
+
+        let end = |type_: String, key: String, alias: Option<String>| {
+            CibouletteRelationshipEndDefinition {
+                type_,
+                key,
+                exposed: alias.is_some(),
+                alias,
+            }
+        };
+        let mut relationships: Vec<CibouletteRelationshipDefinition> =
+            Vec::with_capacity(one_to_many.len() + many_to_many.len());
+        for ((one_type, one_key, many_type, many_key, optional), [one_alias, many_alias]) in
+            one_to_many
+        {
+            relationships.push(CibouletteRelationshipDefinition::OneToMany {
+                one: end(one_type, one_key, one_alias),
+                many: end(many_type, many_key, many_alias),
+                optional,
+            });
+        }
+        for ((bucket, [(from_type, from_key), (to_type, to_key)]), [from_alias, to_alias]) in
+            many_to_many
+        {
+            relationships.push(CibouletteRelationshipDefinition::ManyToMany {
+                bucket,
+                from: end(from_type, from_key, from_alias),
+                to: end(to_type, to_key, to_alias),
+            });
+        }
+        Ok(CibouletteStoreDefinition {
+            types,
+            relationships,
+        })
+    }
+}
@@ -0,0 +1,262 @@
+use super::*;
+use std::ops::Deref;
+
+/// ## A problem found in a [store](CibouletteStore) that doesn't prevent it from being built
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum CibouletteLint {
+    /// A relationship key isn't a field of the type holding it
+    MissingKey {
+        #[serde(rename = "type")]
+        type_: String,
+        key: String,
+    },
+    /// A relationship key doesn't have the type of the field it relates to
+    KeyTypeMismatch {
+        #[serde(rename = "type")]
+        type_: String,
+        key: String,
+        expected: String,
+        found: String,
+    },
+    /// A type has no relationship, so it can't be reached from the other types
+    UnreachableType {
+        #[serde(rename = "type")]
+        type_: String,
+    },
+    /// A relationship has the same name as an attribute of its type
+    AliasClash {
+        #[serde(rename = "type")]
+        type_: String,
+        alias: String,
+    },
+    /// Mandatory to-one relationships form a cycle, so none of its resources can be created first
+    MandatoryCycle { types: Vec<String> },
+}
+
+impl std::fmt::Display for CibouletteLint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CibouletteLint::MissingKey { type_, key } => {
+                write!(f, "The key `{}` isn't a field of type `{}`", key, type_)
+            }
+            CibouletteLint::KeyTypeMismatch {
+                type_,
+                key,
+                expected,
+                found,
+            } => write!(
+                f,
+                "The key `{}` of type `{}` is a `{}`, expected a `{}`",
+                key, type_, found, expected
+            ),
+            CibouletteLint::UnreachableType { type_ } => {
+                write!(f, "The type `{}` has no relationship", type_)
+            }
+            CibouletteLint::AliasClash { type_, alias } => write!(
+                f,
+                "The relationship `{}` of type `{}` is named as one of its attributes",
+                alias, type_
+            ),
+            CibouletteLint::MandatoryCycle { types } => write!(
+                f,
+                "The mandatory relationships between `{}` form a cycle",
+                types.join("`, `")
+            ),
+        }
+    }
+}
+
+/// ## Report of the [lint pass](CibouletteStore::lint) of a store
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Getters)]
+#[getset(get = "pub")]
+pub struct CibouletteLintReport {
+    /// The problems found, sorted
+    warnings: Vec<CibouletteLint>,
+}
+
+impl CibouletteLintReport {
+    /// Check if no problem was found
+    pub fn is_empty(&self) -> bool {
+        self.warnings.is_empty()
+    }
+
+    /// Fail if any problem was found
+    pub fn deny_warnings(self) -> Result<(), CibouletteError> {
+        match self.warnings.is_empty() {
+            true => Ok(()),
+            false => Err(CibouletteError::LintWarnings(self.warnings)),
+        }
+    }
+}
+
+/// Describe the kind of an id, as compared with the kind of the keys.
+///
+/// The kind of the custom ids isn't known, their parser accepting any representation
+fn id_kind(id: &CibouletteIdType) -> Option<&'static str> {
+    match id {
+        CibouletteIdType::Number(_) => Some("number"),
+        CibouletteIdType::Text(_) => Some("string"),
+        CibouletteIdType::Uuid(_) => Some("uuid"),
+        CibouletteIdType::Custom(_, _) => None,
+    }
+}
+
+/// Describe the kind of an attribute, as compared with the kind of the keys
+fn attribute_kind(value: &MessyJson) -> &'static str {
+    #[allow(unreachable_patterns)]
+    match value.deref() {
+        MessyJsonInner::String(_) => "string",
+        MessyJsonInner::Number(_) => "number",
+        MessyJsonInner::Uuid(_) => "uuid",
+        MessyJsonInner::Bool(_) => "bool",
+        MessyJsonInner::Array(_) => "array",
+        MessyJsonInner::Obj(_) => "object",
+        _ => "unknown",
+    }
+}
+
+/// Describe the kind of a field of a type, being an id or an attribute.
+///
+/// `None` if the field doesn't exist, `Some(None)` if its kind isn't known
+fn field_kind(type_: &CibouletteResourceType, field: &str) -> Option<Option<&'static str>> {
+    if let Some(id) = type_.ids().iter().find(|x| x.name().as_str() == field) {
+        return Some(id_kind(id));
+    }
+    type_
+        .schema()
+        .properties()
+        .get(field)
+        .map(|x| Some(attribute_kind(x)))
+}
+
+/// The kind of the id of a type, if not compound and known
+fn single_id_kind(type_: &CibouletteResourceType) -> Option<&'static str> {
+    match &**type_.ids() {
+        CibouletteSelector::Single(id) => id_kind(id),
+        CibouletteSelector::Multi(_) => None,
+    }
+}
+
+/// Check that the key `key` of `type_` exists and has the kind `expected`
+fn check_key(
+    lints: &mut BTreeSet<CibouletteLint>,
+    type_: &CibouletteResourceType,
+    key: &str,
+    expected: Option<&str>,
+) {
+    match (field_kind(type_, key), expected) {
+        (None, _) => {
+            lints.insert(CibouletteLint::MissingKey {
+                type_: type_.name().to_string(),
+                key: key.to_string(),
+            });
+        }
+        (Some(Some(found)), Some(expected)) if found != expected => {
+            lints.insert(CibouletteLint::KeyTypeMismatch {
+                type_: type_.name().to_string(),
+                key: key.to_string(),
+                expected: expected.to_string(),
+                found: found.to_string(),
+            });
+        }
+        _ => (),
+    }
+}
+
+impl CibouletteStore {
+    /// ## Look for the problems of the store that didn't prevent it from being built
+    ///
+    /// The relationship keys are checked against the schemas and ids of the related
+    /// types, the types without relationships, the relationships named as an attribute
+    /// and the cycles of mandatory to-one relationships are reported.
+    pub fn lint(&self) -> CibouletteLintReport {
+        let mut lints: BTreeSet<CibouletteLint> = BTreeSet::new();
+        // The mandatory to-one relationships, between the types indexes
+        let mut mandatory: petgraph::graph::Graph<(), (), petgraph::Directed, u16> =
+            petgraph::graph::Graph::with_capacity(self.graph().node_count(), 0);
+
+        for node in self.graph().node_indices() {
+            mandatory.add_node(());
+            let type_ = match self.graph().node_weight(node) {
+                Some(x) => x,
+                None => continue,
+            };
+            if self.graph().neighbors_undirected(node).next().is_none() {
+                lints.insert(CibouletteLint::UnreachableType {
+                    type_: type_.name().to_string(),
+                });
+            }
+            for alias in type_.relationships().keys() {
+                if type_.schema().properties().contains_key(alias.as_str()) {
+                    lints.insert(CibouletteLint::AliasClash {
+                        type_: type_.name().to_string(),
+                        alias: alias.to_string(),
+                    });
+                }
+            }
+        }
+        for edge in self.graph().raw_edges() {
+            match &edge.weight {
+                CibouletteRelationshipOption::OneToMany(opt)
+                | CibouletteRelationshipOption::ManyToOne(opt) => {
+                    if opt.part_of_many_to_many().is_some() {
+                        continue;
+                    }
+                    check_key(&mut lints, opt.one_resource(), opt.one_resource_key(), None);
+                    check_key(
+                        &mut lints,
+                        opt.many_resource(),
+                        opt.many_resource_key(),
+                        field_kind(opt.one_resource(), opt.one_resource_key()).flatten(),
+                    );
+                    if matches!(edge.weight, CibouletteRelationshipOption::ManyToOne(_))
+                        && !*opt.optional()
+                    {
+                        mandatory.add_edge(edge.source(), edge.target(), ());
+                    }
+                }
+                CibouletteRelationshipOption::ManyToMany(opt) => {
+                    for (type_, key) in opt.keys().iter() {
+                        check_key(
+                            &mut lints,
+                            opt.bucket_resource(),
+                            key,
+                            single_id_kind(type_),
+                        );
+                    }
+                }
+            }
+        }
+        for cycle in petgraph::algo::tarjan_scc(&mandatory) {
+            let is_cycle = match cycle.as_slice() {
+                [node] => mandatory.find_edge(*node, *node).is_some(),
+                _ => true,
+            };
+            if !is_cycle {
+                continue;
+            }
+            let mut types: Vec<String> = cycle
+                .iter()
+                .filter_map(|x| self.graph().node_weight(*x))
+                .map(|x| x.name().to_string())
+                .collect();
+            types.sort();
+            lints.insert(CibouletteLint::MandatoryCycle { types });
+        }
+        CibouletteLintReport {
+            warnings: lints.into_iter().collect(),
+        }
+    }
+}
+
+impl CibouletteStoreBuilder {
+    /// Build the [CibouletteStore](CibouletteStore), running its [lint pass](CibouletteStore::lint)
+    pub fn build_with_lints(
+        self,
+    ) -> Result<(CibouletteStore, CibouletteLintReport), CibouletteError> {
+        let store = self.build()?;
+        let report = store.lint();
+        Ok((store, report))
+    }
+}
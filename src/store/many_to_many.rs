@@ -31,6 +31,10 @@ macro_rules! cancel_rel_edge_on_failure {
 
 impl CibouletteStoreBuilder {
     /// Add a relationships (one/many-to-one/many) to the graph, with the reverse relationship
+    ///
+    /// For a self-referential relationship, the second link from the type to the bucket
+    /// is aliased `{bucket}_{key}`, `key` being the field of the second bucket key
+    /// (i.e. `friendships_friend_id`).
     pub fn add_many_to_many_rel(
         &mut self,
         (from, alias_from): (&str, Option<&str>),
@@ -39,6 +43,7 @@ impl CibouletteStoreBuilder {
     ) -> Result<(), CibouletteError> {
         let node_indexes = self.get_many_to_many_node_indexes(from, to, &opt)?;
         self.check_bucket_exists(node_indexes.bucket(), from, &opt)?;
+        let (from_side, _) = opt.sides(from, to)?;
         let edge_indexes = self.get_many_to_many_edge_indexes(&node_indexes, &opt)?;
         // Add relationship from `from` to `to`
         cancel_rel_edge_on_failure!(
@@ -62,7 +67,7 @@ impl CibouletteStoreBuilder {
                 edge_indexes.from_direct(),
             )
         );
-        let (edge_index, alias) = match opt.keys()[0].0.name() == from {
+        let (edge_index, alias) = match from_side == 0 {
             true => (edge_indexes.to(), alias_from),
             false => (edge_indexes.from(), alias_to),
         };
@@ -82,10 +87,19 @@ impl CibouletteStoreBuilder {
                 None,
             )
         );
-        let (edge_index, alias) = match opt.keys()[1].0.name() == from {
+        let (edge_index, alias) = match from_side == 1 {
             true => (edge_indexes.to(), alias_from),
             false => (edge_indexes.from(), alias_to),
         };
+        // Both ends being of the same type, the second link to the bucket is told apart by its key
+        let alias_bucket = match opt.keys()[0].0.name() == opt.keys()[1].0.name() {
+            true => Some(ArcStr::from(format!(
+                "{}_{}",
+                opt.bucket_resource().name(),
+                opt.keys()[1].1
+            ))),
+            false => None,
+        };
         cancel_rel_edge_on_failure!(
             self,
             edge_indexes,
@@ -99,7 +113,7 @@ impl CibouletteStoreBuilder {
                     edge_index
                 ),
                 alias.map(ArcStr::from),
-                None,
+                alias_bucket,
             )
         );
         Ok(())
@@ -141,8 +155,7 @@ impl CibouletteStoreBuilder {
         let node_indexes = self.get_many_to_many_node_indexes(from, to, &opt)?;
         self.check_bucket_exists(node_indexes.bucket(), from, &opt)?;
         let (from_type, bucket_type, to_type) = self.extract_many_to_many_types(&node_indexes)?;
-        let many_resource_key = opt.keys_for_type(&from_type)?;
-        let one_resource_key = opt.keys_for_type(&to_type)?;
+        let (many_resource_key, one_resource_key) = opt.keys_for_sides(from, to)?;
         let (edge_to_direct, edge_to) = self.get_many_to_many_edge_indexes_to(
             &bucket_type,
             &from_type,
@@ -198,10 +211,14 @@ impl CibouletteStoreBuilder {
             .insert(alias_arc.clone(), rel_to_insert);
         type_
             .relationships_type_to_alias_mut()
-            .insert(ArcStr::from(dest), alias_arc);
+            .entry(ArcStr::from(dest))
+            .or_default()
+            .insert(alias_arc);
         type_
             .relationships_type_to_alias_mut()
-            .insert(bucket_arc.clone(), bucket_arc);
+            .entry(bucket_arc.clone())
+            .or_default()
+            .insert(bucket_arc);
         Ok(())
     }
 
@@ -257,13 +274,14 @@ impl CibouletteStoreBuilder {
         ),
         CibouletteError,
     > {
+        let (from_side, to_side) = opt.sides(from_type.name(), to_type.name())?;
+        let from_key = opt.keys()[from_side].1.clone();
+        let to_key = opt.keys()[to_side].1.clone();
         let edge_to_i_direct = self.graph_mut().add_edge(
             indexes.to(),
             indexes.from(),
-            CibouletteRelationshipOptionBuilder::ManyToMany(opt.clone()),
+            CibouletteRelationshipOptionBuilder::ManyToMany(opt.with_source(from_side)),
         );
-        let from_key = opt.keys_for_type(&from_type)?;
-        let to_key = opt.keys_for_type(&to_type)?;
         let edge_to_i = self.graph_mut().add_edge(
             indexes.bucket(),
             indexes.to(),
@@ -296,13 +314,15 @@ impl CibouletteStoreBuilder {
         ),
         CibouletteError,
     > {
+        // The relationship goes from `to_type` to `from_type`, its ends are resolved in that order
+        let (to_side, from_side) = opt.sides(to_type.name(), from_type.name())?;
+        let from_key = opt.keys()[from_side].1.clone();
+        let to_key = opt.keys()[to_side].1.clone();
         let edge_from_i_direct = self.graph_mut().add_edge(
             indexes.from(),
             indexes.to(),
-            CibouletteRelationshipOptionBuilder::ManyToMany(opt.clone()),
+            CibouletteRelationshipOptionBuilder::ManyToMany(opt.with_source(from_side)),
         );
-        let from_key = opt.keys_for_type(&from_type)?;
-        let to_key = opt.keys_for_type(&to_type)?;
         let edge_from_i = self.graph_mut().add_edge(
             indexes.bucket(),
             indexes.from(),
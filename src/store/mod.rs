@@ -2,11 +2,21 @@ use super::*;
 use getset::CopyGetters;
 
 mod builder;
+mod definition;
+mod lint;
 mod many_to_many;
+mod model;
 mod one_to_many;
 mod relationships_options;
 mod relationships_options_builder;
 pub use builder::CibouletteStoreBuilder;
+pub use definition::{
+    CibouletteAttributeDefinition, CibouletteIdDefinition, CibouletteNumberFormat,
+    CibouletteRelationshipDefinition, CibouletteRelationshipEndDefinition,
+    CibouletteStoreDefinition, CibouletteTypeDefinition,
+};
+pub use lint::{CibouletteLint, CibouletteLintReport};
+pub use model::{CibouletteAttributeModel, CibouletteIdModel, CibouletteResourceModel};
 pub use relationships_options::{
     CibouletteRelationshipManyToManyOption, CibouletteRelationshipOneToManyOption,
     CibouletteRelationshipOption,
@@ -30,6 +40,12 @@ pub struct CibouletteStore {
         u16,
     >,
     map: BTreeMap<ArcStr, petgraph::graph::NodeIndex<u16>>,
+    public_map: BTreeMap<ArcStr, petgraph::graph::NodeIndex<u16>>,
+    id_types: BTreeMap<ArcStr, CibouletteCustomIdType>,
+    extensions: BTreeMap<ArcStr, Arc<dyn CibouletteExtension>>,
+    profiles: BTreeSet<ArcStr>,
+    query_parameters: BTreeMap<ArcStr, CibouletteCustomQueryParameter>,
+    naming_policy: CibouletteNamingPolicy,
     #[getset(get_mut = "pub")]
     config: CibouletteConfig,
 }
@@ -69,6 +85,63 @@ impl CibouletteStore {
             .ok_or_else(|| CibouletteError::UnknownType(name.to_string()))
     }
 
+    /// Get a type from the graph by its public name or one of its public aliases
+    pub fn get_public_type_if_exists(&self, name: &str) -> Option<Arc<CibouletteResourceType>> {
+        self.public_map
+            .get(name)
+            .and_then(|x| self.graph.node_weight(*x))
+            .cloned()
+    }
+
+    /// Get a type from the graph by its public name or one of its public aliases,
+    /// returning an error if not found
+    pub fn get_public_type(
+        &self,
+        name: &str,
+    ) -> Result<&Arc<CibouletteResourceType>, CibouletteError> {
+        self.public_map
+            .get(name)
+            .and_then(|x| self.graph.node_weight(*x))
+            .ok_or_else(|| CibouletteError::UnknownType(name.to_string()))
+    }
+
+    /// Get a registered extension by its URI
+    pub fn get_extension(&self, uri: &str) -> Option<&Arc<dyn CibouletteExtension>> {
+        self.extensions.get(uri)
+    }
+
+    /// Get a registered extension by its namespace
+    pub fn get_extension_by_namespace(
+        &self,
+        namespace: &str,
+    ) -> Option<&Arc<dyn CibouletteExtension>> {
+        self.extensions
+            .values()
+            .find(|x| x.namespace() == namespace)
+    }
+
+    /// Get the registered extensions from their URIs, returning an error if one is unknown
+    pub fn get_extensions<'a, I>(
+        &self,
+        uris: I,
+    ) -> Result<Vec<Arc<dyn CibouletteExtension>>, CibouletteError>
+    where
+        I: IntoIterator<Item = &'a str>,
+    {
+        uris.into_iter()
+            .map(|uri| {
+                self.get_extension(uri)
+                    .cloned()
+                    .ok_or_else(|| CibouletteError::UnknownExtension(uri.to_string()))
+            })
+            .collect()
+    }
+
+    /// Get a registered profile by its URI
+    pub fn get_profile(&self, uri: &str) -> Option<&ArcStr> {
+        self.profiles.get(uri)
+    }
+
     /// Get a relationship from the graph
     pub fn get_rel(
         &self,
@@ -0,0 +1,151 @@
+use super::*;
+
+/// ## A Rust type describing a [resource type](CibouletteResourceType)
+///
+/// Usually implemented with `#[derive(CibouletteResource)]` from the `derive` feature.
+pub trait CibouletteResourceModel {
+    /// The name of the type in the store
+    fn type_name() -> &'static str;
+
+    /// The ids and attributes of the type
+    fn type_definition() -> CibouletteTypeDefinition;
+
+    /// The relationships declared by the type
+    fn relationship_definitions() -> Vec<CibouletteRelationshipDefinition>;
+}
+
+/// ## A Rust type describing an attribute schema
+pub trait CibouletteAttributeModel {
+    /// The schema of the attribute
+    fn attribute_definition() -> CibouletteAttributeDefinition;
+}
+
+/// ## A Rust type usable as the id of a [resource type](CibouletteResourceType)
+pub trait CibouletteIdModel {
+    /// The id type, held by the field `name`
+    fn id_definition(name: &str) -> CibouletteIdDefinition;
+}
+
+macro_rules! attribute_model {
+    ($variant:ident, $($type_:ty),+) => {
+        $(
+            impl CibouletteAttributeModel for $type_ {
+                fn attribute_definition() -> CibouletteAttributeDefinition {
+                    CibouletteAttributeDefinition::$variant { optional: false }
+                }
+            }
+        )+
+    };
+}
+
+macro_rules! number_model {
+    ($(($type_:ty, $format:ident)),+) => {
+        $(
+            impl CibouletteAttributeModel for $type_ {
+                fn attribute_definition() -> CibouletteAttributeDefinition {
+                    CibouletteAttributeDefinition::Number {
+                        format: CibouletteNumberFormat::$format,
+                        optional: false,
+                    }
+                }
+            }
+
+            impl CibouletteIdModel for $type_ {
+                fn id_definition(name: &str) -> CibouletteIdDefinition {
+                    CibouletteIdDefinition::Number {
+                        name: name.to_string(),
+                    }
+                }
+            }
+        )+
+    };
+}
+
+attribute_model!(String, String, str, ArcStr, Cow<'_, str>);
+attribute_model!(Bool, bool);
+attribute_model!(Uuid, Uuid);
+number_model!(
+    (u8, U8),
+    (u16, U16),
+    (u32, U32),
+    (u64, U64),
+    (u128, U128),
+    (i8, I8),
+    (i16, I16),
+    (i32, I32),
+    (i64, I64),
+    (i128, I128)
+);
+
+impl<T: CibouletteAttributeModel> CibouletteAttributeModel for Option<T> {
+    fn attribute_definition() -> CibouletteAttributeDefinition {
+        T::attribute_definition().with_optional(true)
+    }
+}
+
+impl<T: CibouletteAttributeModel> CibouletteAttributeModel for Vec<T> {
+    fn attribute_definition() -> CibouletteAttributeDefinition {
+        CibouletteAttributeDefinition::Array {
+            items: Box::new(T::attribute_definition()),
+            optional: false,
+        }
+    }
+}
+
+impl<T: CibouletteAttributeModel + ?Sized> CibouletteAttributeModel for Box<T> {
+    fn attribute_definition() -> CibouletteAttributeDefinition {
+        T::attribute_definition()
+    }
+}
+
+impl CibouletteIdModel for Uuid {
+    fn id_definition(name: &str) -> CibouletteIdDefinition {
+        CibouletteIdDefinition::Uuid {
+            name: name.to_string(),
+        }
+    }
+}
+
+impl CibouletteIdModel for String {
+    fn id_definition(name: &str) -> CibouletteIdDefinition {
+        CibouletteIdDefinition::Text {
+            name: name.to_string(),
+        }
+    }
+}
+
+impl CibouletteStoreDefinition {
+    /// Add a [model](CibouletteResourceModel) type and its relationships to the definition
+    pub fn add_model<T: CibouletteResourceModel>(&mut self) -> Result<(), CibouletteError> {
+        if self.types().contains_key(T::type_name()) {
+            return Err(CibouletteError::UniqType(T::type_name().to_string()));
+        }
+        self.types_mut()
+            .insert(T::type_name().to_string(), T::type_definition());
+        self.relationships_mut()
+            .extend(T::relationship_definitions());
+        Ok(())
+    }
+}
+
+impl CibouletteStoreBuilder {
+    /// Add the type described by a [model](CibouletteResourceModel)
+    pub fn add_model<T: CibouletteResourceModel>(&mut self) -> Result<(), CibouletteError> {
+        self.add_type_definition(T::type_name(), &T::type_definition())
+    }
+
+    /// Add the relationships declared by a [model](CibouletteResourceModel).
+    ///
+    /// Every type they relate to must have been added beforehand.
+    pub fn add_model_relationships<T: CibouletteResourceModel>(
+        &mut self,
+    ) -> Result<(), CibouletteError> {
+        for (i, relationship) in T::relationship_definitions().iter().enumerate() {
+            self.add_relationship_definition(
+                &format!("{}.relationships.{}", T::type_name(), i),
+                relationship,
+            )?;
+        }
+        Ok(())
+    }
+}
@@ -97,7 +97,9 @@ impl CibouletteStoreBuilder {
         type_.relationships_mut().insert(alias.clone(), orig_rel_i);
         type_
             .relationships_type_to_alias_mut()
-            .insert(ArcStr::from(dest.name()), alias);
+            .entry(ArcStr::from(dest.name()))
+            .or_default()
+            .insert(alias);
         Ok(())
     }
 
@@ -8,6 +8,8 @@ pub struct CibouletteRelationshipManyToManyOption {
     pub(crate) bucket_resource: Arc<CibouletteResourceType>,
     /// The related types and their relating fields
     pub(crate) keys: [(Arc<CibouletteResourceType>, ArcStr); 2],
+    /// The index in `keys` of the type holding the relationship
+    pub(crate) source: usize,
 }
 
 /// ## One-to-Many/Many-to-One relationships options
@@ -29,8 +31,25 @@ pub struct CibouletteRelationshipOneToManyOption {
 }
 
 impl CibouletteRelationshipManyToManyOption {
-    /// Get the relating field in the bucket resource for the provided type
+    /// Get the relating field in the bucket resource for the provided type.
+    ///
+    /// Both ends of a self-referential relationship being of the same type, it fails with
+    /// [AmbiguousBucketKey](CibouletteError::AmbiguousBucketKey) for them. Use
+    /// [source_key](CibouletteRelationshipManyToManyOption::source_key) and
+    /// [target_key](CibouletteRelationshipManyToManyOption::target_key) instead.
     pub fn keys_for_type(&self, type_: &CibouletteResourceType) -> Result<ArcStr, CibouletteError> {
+        if self
+            .keys
+            .iter()
+            .filter(|(k, _)| k.as_ref() == type_)
+            .count()
+            > 1
+        {
+            return Err(CibouletteError::AmbiguousBucketKey(
+                self.bucket_resource().name().to_string(),
+                type_.name().to_string(),
+            ));
+        }
         self.keys
             .iter()
             .find(|(k, _)| k.as_ref() == type_)
@@ -42,6 +61,16 @@ impl CibouletteRelationshipManyToManyOption {
                 )
             })
     }
+
+    /// Get the relating field in the bucket resource for the type holding the relationship
+    pub fn source_key(&self) -> &ArcStr {
+        &self.keys[self.source].1
+    }
+
+    /// Get the relating field in the bucket resource for the related type
+    pub fn target_key(&self) -> &ArcStr {
+        &self.keys[1 - self.source].1
+    }
 }
 
 impl CibouletteRelationshipOneToManyOption {}
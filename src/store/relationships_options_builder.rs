@@ -1,13 +1,22 @@
 use super::*;
 
 /// ## Many-to-Many relationships option builder
-#[derive(Debug, Clone, Getters, PartialEq)]
+#[derive(Debug, Clone, Getters)]
 #[getset(get = "pub")]
 pub struct CibouletteRelationshipManyToManyOptionBuilder {
     /// The bucket resource
     bucket_resource: CibouletteResourceType,
     /// The keys associated type and their matching field in bucket
     keys: [(CibouletteResourceType, ArcStr); 2],
+    /// The index in `keys` of the type holding the relationship
+    source: usize,
+}
+
+impl PartialEq for CibouletteRelationshipManyToManyOptionBuilder {
+    /// The options of both ends of a relationship are equal, whatever end they're seen from
+    fn eq(&self, other: &Self) -> bool {
+        self.bucket_resource == other.bucket_resource && self.keys == other.keys
+    }
 }
 
 /// ## One-to-Many/Many-to-one relationships option builder
@@ -36,11 +45,63 @@ impl CibouletteRelationshipManyToManyOptionBuilder {
         CibouletteRelationshipManyToManyOptionBuilder {
             bucket_resource,
             keys,
+            source: 0,
+        }
+    }
+
+    /// Set the index in `keys` of the type holding the relationship
+    pub(crate) fn with_source(&self, source: usize) -> Self {
+        CibouletteRelationshipManyToManyOptionBuilder {
+            source,
+            ..self.clone()
         }
     }
 
-    /// Get the field for the resource in a Many-to-Many relationships
+    /// Get the indexes in `keys` of the ends of a relationship from `from` to `to`.
+    ///
+    /// When both ends are of the same type, the first key is the one of `from`.
+    pub(crate) fn sides(&self, from: &str, to: &str) -> Result<(usize, usize), CibouletteError> {
+        let from_side = self.keys.iter().position(|(k, _)| k.name() == from);
+        let to_side =
+            (0..self.keys.len()).find(|i| Some(*i) != from_side && self.keys[*i].0.name() == to);
+        match (from_side, to_side) {
+            (Some(from_side), Some(to_side)) => Ok((from_side, to_side)),
+            (None, _) => Err(CibouletteError::UnknownRelationship(
+                self.bucket_resource().name().to_string(),
+                from.to_string(),
+            )),
+            (_, None) => Err(CibouletteError::UnknownRelationship(
+                self.bucket_resource().name().to_string(),
+                to.to_string(),
+            )),
+        }
+    }
+
+    /// Get the fields of the bucket resource relating to `from` and `to`, for a relationship
+    /// from `from` to `to`.
+    ///
+    /// When both ends are of the same type, the first key is the one of `from`.
+    pub fn keys_for_sides(
+        &self,
+        from: &str,
+        to: &str,
+    ) -> Result<(ArcStr, ArcStr), CibouletteError> {
+        let (from_side, to_side) = self.sides(from, to)?;
+        Ok((self.keys[from_side].1.clone(), self.keys[to_side].1.clone()))
+    }
+
+    /// Get the field for the resource in a Many-to-Many relationships.
+    ///
+    /// Both ends of a self-referential relationship being of the same type, it fails with
+    /// [AmbiguousBucketKey](CibouletteError::AmbiguousBucketKey) for them. Use
+    /// [keys_for_sides](CibouletteRelationshipManyToManyOptionBuilder::keys_for_sides) instead.
     pub fn keys_for_type(&self, type_: &CibouletteResourceType) -> Result<ArcStr, CibouletteError> {
+        if self.keys.iter().filter(|(k, _)| k == type_).count() > 1 {
+            return Err(CibouletteError::AmbiguousBucketKey(
+                self.bucket_resource().name().to_string(),
+                type_.name().to_string(),
+            ));
+        }
         self.keys
             .iter()
             .find(|(k, _)| k == type_)
@@ -103,6 +164,7 @@ impl CibouletteRelationshipManyToManyOptionBuilder {
                     self.keys[1].1.clone(),
                 ),
             ],
+            source: self.source,
         }))
     }
 }
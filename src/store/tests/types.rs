@@ -168,3 +168,67 @@ fn get_type_with_index() {
     assert_eq!(type_.name(), "toto");
     assert_eq!(type_, type_2);
 }
+
+#[test]
+fn public_name() {
+    let mut store = crate::test_helper::gen_bag_builder();
+
+    store.set_public_name("peoples", "persons").unwrap();
+    assert_eq!(
+        store.get_public_type("persons").unwrap().name().as_str(),
+        "peoples"
+    );
+    assert_eq!(
+        store.get_type("peoples").unwrap().public_name().as_str(),
+        "persons"
+    );
+    let err = store.get_public_type("peoples").unwrap_err();
+    assert_eq!(
+        matches!(err, CibouletteError::UnknownType(x) if x == "peoples"),
+        true
+    );
+}
+
+#[test]
+fn public_alias() {
+    let mut store = crate::test_helper::gen_bag_builder();
+
+    store.add_public_alias("peoples", "persons").unwrap();
+    let store = store.build().unwrap();
+    assert_eq!(
+        store.get_public_type("persons").unwrap(),
+        store.get_type("peoples").unwrap()
+    );
+    assert_eq!(
+        store.get_public_type("peoples").unwrap(),
+        store.get_type("peoples").unwrap()
+    );
+}
+
+#[test]
+fn public_name_collision() {
+    let mut store = crate::test_helper::gen_bag_builder();
+
+    let err = store.set_public_name("peoples", "articles").unwrap_err();
+    assert_eq!(
+        matches!(err, CibouletteError::UniqType(x) if x == "articles"),
+        true
+    );
+    store.add_public_alias("peoples", "persons").unwrap();
+    let err = store.add_public_alias("articles", "persons").unwrap_err();
+    assert_eq!(
+        matches!(err, CibouletteError::UniqType(x) if x == "persons"),
+        true
+    );
+}
+
+#[test]
+fn public_name_invalid() {
+    let mut store = crate::test_helper::gen_bag_builder();
+
+    let err = store.set_public_name("peoples", "pers/ons").unwrap_err();
+    assert_eq!(
+        matches!(err, CibouletteError::InvalidMemberName(x) if x == "pers/ons"),
+        true
+    );
+}
@@ -124,6 +124,10 @@ pub fn gen_messy_json_schema_peoples() -> MessyJsonObject {
 }
 
 pub fn gen_bag() -> CibouletteStore {
+    gen_bag_builder().build().unwrap()
+}
+
+pub fn gen_bag_builder() -> CibouletteStoreBuilder {
     let mut res = CibouletteStoreBuilder::default();
 
     res.add_type(
@@ -223,7 +227,98 @@ pub fn gen_bag() -> CibouletteStore {
         ),
     )
     .unwrap(); // Peoples -> Articles
-    res.build().unwrap()
+    res
+}
+
+/// `peoples` have a `manager` (Many-to-One) and `friends` (Many-to-Many), all being `peoples`
+pub fn gen_bag_self() -> CibouletteStore {
+    let mut store = gen_bag_builder();
+    store
+        .add_type(
+            "friendships",
+            CibouletteIdTypeSelector::new(CibouletteSelector::Multi(vec![
+                CibouletteIdType::Uuid(arcstr::literal!("people_id")),
+                CibouletteIdType::Uuid(arcstr::literal!("friend_id")),
+            ])),
+            MessyJsonObject::from(MessyJsonObjectInner::new(
+                vec![
+                    (
+                        arcstr::literal!("people_id"),
+                        MessyJson::from(MessyJsonInner::String(MessyJsonScalar::new(false))),
+                    ),
+                    (
+                        arcstr::literal!("friend_id"),
+                        MessyJson::from(MessyJsonInner::String(MessyJsonScalar::new(false))),
+                    ),
+                ]
+                .into_iter()
+                .collect(),
+                false,
+            )),
+        )
+        .unwrap();
+    store
+        .add_one_to_many_rel(
+            CibouletteRelationshipOneToManyOptionBuilder::new(
+                store.get_type("peoples").unwrap().clone(),
+                arcstr::literal!("id"),
+                store.get_type("peoples").unwrap().clone(),
+                arcstr::literal!("manager"),
+                true,
+            ),
+            Some(arcstr::literal!("manager")),
+            Some(arcstr::literal!("reports")),
+        )
+        .unwrap();
+    store
+        .add_many_to_many_rel(
+            ("peoples", Some("friend_of")),
+            ("peoples", Some("friends")),
+            CibouletteRelationshipManyToManyOptionBuilder::new(
+                store.get_type("friendships").unwrap().clone(),
+                [
+                    (
+                        store.get_type("peoples").unwrap().clone(),
+                        arcstr::literal!("people_id"),
+                    ),
+                    (
+                        store.get_type("peoples").unwrap().clone(),
+                        arcstr::literal!("friend_id"),
+                    ),
+                ],
+            ),
+        )
+        .unwrap();
+    store.build().unwrap()
+}
+
+pub fn build_request<'request>(
+    store: &CibouletteStore,
+    intention: CibouletteIntention,
+    url: &'request Url,
+    body: &'request Option<&'request str>,
+) -> Result<CibouletteRequest<'request>, CibouletteError> {
+    CibouletteRequestBuilder::new(intention, url, body).build(store)
+}
+
+pub fn related_row<'request>(
+    store: &CibouletteStore,
+    base_type: &Arc<CibouletteResourceType>,
+    main_id: &'request str,
+    alias: &'request str,
+    id: &'request str,
+) -> CibouletteResponseElement<'request, String> {
+    CibouletteResponseElement::build(
+        store,
+        base_type,
+        CibouletteResourceIdentifierBuilder::new(Some(Cow::Borrowed(id)), Cow::Borrowed(alias)),
+        Some(String::from("<some data>")),
+        Some(CibouletteResourceIdentifierBuilder::new(
+            Some(Cow::Borrowed(main_id)),
+            Cow::Owned(base_type.name().to_string()),
+        )),
+    )
+    .unwrap()
 }
 
 pub fn check_ident<'request>(
@@ -0,0 +1,131 @@
+use super::*;
+
+fn gen_bag_policies() -> CibouletteStore {
+    let mut store = gen_bag_builder();
+    store
+        .set_attribute_policy("peoples", "age", CibouletteAttributePolicy::ReadOnly)
+        .unwrap();
+    store
+        .set_attribute_policy("peoples", "gender", CibouletteAttributePolicy::CreateOnly)
+        .unwrap();
+    store
+        .set_attribute_policy("peoples", "twitter", CibouletteAttributePolicy::WriteOnly)
+        .unwrap();
+    store.build().unwrap()
+}
+
+#[test]
+fn read_only_on_create() {
+    let store = gen_bag_policies();
+    let url = Url::parse("http://localhost/peoples").unwrap();
+    let body = Some(
+        r#"{"data":{"type":"peoples","attributes":{"first-name":"a","last-name":"b","age":42}}}"#,
+    );
+    let err = build_request(&store, CibouletteIntention::Create, &url, &body).unwrap_err();
+
+    assert_eq!(
+        matches!(&err, CibouletteError::ForbiddenAttribute(type_, attr) if type_ == "peoples" && attr == "age"),
+        true
+    );
+    let obj = CibouletteErrorObj::from(&err);
+    assert_eq!(obj.status(), &403);
+    assert_eq!(
+        obj.source().as_ref().unwrap().pointer().as_deref(),
+        Some("/data/attributes/age")
+    );
+}
+
+#[test]
+fn create_only() {
+    let store = gen_bag_policies();
+    let url = Url::parse("http://localhost/peoples").unwrap();
+    let body = Some(
+        r#"{"data":{"type":"peoples","attributes":{"first-name":"a","last-name":"b","gender":"c"}}}"#,
+    );
+    let request = build_request(&store, CibouletteIntention::Create, &url, &body).unwrap();
+    CibouletteCreateRequest::try_from(request).unwrap();
+
+    let url = Url::parse("http://localhost/peoples/6720877a-e27e-4e9e-9ac0-3fff4deb55f2").unwrap();
+    let body = Some(
+        r#"{"data":{"id":"6720877a-e27e-4e9e-9ac0-3fff4deb55f2","type":"peoples","attributes":{"gender":"c"}}}"#,
+    );
+    let err = build_request(&store, CibouletteIntention::Update, &url, &body).unwrap_err();
+
+    assert_eq!(
+        matches!(&err, CibouletteError::ForbiddenAttribute(_, attr) if attr == "gender"),
+        true
+    );
+}
+
+#[test]
+fn create_only_mandatory() {
+    let mut store = gen_bag_builder();
+    store
+        .set_attribute_policy(
+            "peoples",
+            "last-name",
+            CibouletteAttributePolicy::CreateOnly,
+        )
+        .unwrap();
+    let store = store.build().unwrap();
+    let url = Url::parse("http://localhost/peoples/6720877a-e27e-4e9e-9ac0-3fff4deb55f2").unwrap();
+    let body = Some(
+        r#"{"data":{"id":"6720877a-e27e-4e9e-9ac0-3fff4deb55f2","type":"peoples","attributes":{"first-name":"a"}}}"#,
+    );
+    let request = build_request(&store, CibouletteIntention::Update, &url, &body).unwrap();
+    CibouletteUpdateRequest::try_from(request).unwrap();
+
+    let body = Some(
+        r#"{"data":{"id":"6720877a-e27e-4e9e-9ac0-3fff4deb55f2","type":"peoples","attributes":{"last-name":"b"}}}"#,
+    );
+    let err = build_request(&store, CibouletteIntention::Update, &url, &body).unwrap_err();
+
+    assert_eq!(
+        matches!(&err, CibouletteError::ForbiddenAttribute(_, attr) if attr == "last-name"),
+        true
+    );
+}
+
+#[test]
+fn write_only_stripped_from_responses() {
+    let store = gen_bag_policies();
+    let url = Url::parse("http://localhost/peoples/6720877a-e27e-4e9e-9ac0-3fff4deb55f2").unwrap();
+    let request = CibouletteReadRequest::try_from(
+        build_request(&store, CibouletteIntention::Read, &url, &None).unwrap(),
+    )
+    .unwrap();
+    let base_type = store.get_type("peoples").unwrap();
+    let element: CibouletteResponseElement<'_, Value> = CibouletteResponseElement::build(
+        &store,
+        &base_type,
+        CibouletteResourceIdentifierBuilder::new(
+            Some(Cow::Borrowed("6720877a-e27e-4e9e-9ac0-3fff4deb55f2")),
+            Cow::Borrowed("peoples"),
+        ),
+        Some(serde_json::json!({ "first-name": "a", "twitter": "@a" })),
+        None,
+    )
+    .unwrap();
+    let response = CibouletteResponseDataBuilder::new(&request, vec![element])
+        .build(store.config())
+        .unwrap();
+    let response = serde_json::to_value(&response).unwrap();
+
+    assert_eq!(
+        response["data"]["attributes"],
+        serde_json::json!({ "first-name": "a" })
+    );
+}
+
+#[test]
+fn unknown_attribute() {
+    let mut store = gen_bag_builder();
+    let err = store
+        .set_attribute_policy("peoples", "nope", CibouletteAttributePolicy::ReadOnly)
+        .unwrap_err();
+
+    assert_eq!(
+        matches!(err, CibouletteError::UnknownField(type_, field) if type_ == "peoples" && field == "nope"),
+        true
+    );
+}
@@ -0,0 +1,154 @@
+use super::*;
+
+const ID: &str = "6720877a-e27e-4e9e-9ac0-3fff4deb55f2";
+
+fn gen_bag_capabilities() -> CibouletteStore {
+    let mut store = gen_bag_builder();
+    store
+        .add_type_with_capabilities(
+            "colors_view",
+            CibouletteIdTypeSelector::new(CibouletteSelector::Single(CibouletteIdType::Uuid(
+                arcstr::literal!("id"),
+            ))),
+            gen_messy_json_schema_favorite_color(),
+            CibouletteTypeCapabilities::read_only(),
+        )
+        .unwrap();
+    let mut capabilities = CibouletteRelationshipCapabilities::default();
+    *capabilities.mutable_mut() = false;
+    store
+        .set_relationship_capabilities("peoples", "articles", capabilities)
+        .unwrap();
+    *capabilities.readable_mut() = false;
+    store
+        .set_relationship_capabilities("peoples", "comments", capabilities)
+        .unwrap();
+    store.build().unwrap()
+}
+
+fn gen_bag_client_ids(policy: CibouletteClientIdPolicy) -> CibouletteStore {
+    let mut store = gen_bag_builder();
+    let mut capabilities = CibouletteTypeCapabilities::default();
+    *capabilities.client_ids_mut() = policy;
+    store
+        .add_type_with_capabilities(
+            "colors_view",
+            CibouletteIdTypeSelector::new(CibouletteSelector::Single(CibouletteIdType::Uuid(
+                arcstr::literal!("id"),
+            ))),
+            gen_messy_json_schema_favorite_color(),
+            capabilities,
+        )
+        .unwrap();
+    store.build().unwrap()
+}
+
+#[test]
+fn read_only_type() {
+    let store = gen_bag_capabilities();
+    let url = Url::parse("http://localhost/colors_view").unwrap();
+    let body = Some(r#"{"data":{"type":"colors_view","attributes":{"color":"red"}}}"#);
+    let err = CibouletteCreateRequest::try_from(
+        build_request(&store, CibouletteIntention::Create, &url, &body).unwrap(),
+    )
+    .unwrap_err();
+
+    assert_eq!(
+        matches!(&err, CibouletteError::ForbiddenOperation(type_, CibouletteIntention::Create) if type_ == "colors_view"),
+        true
+    );
+    assert_eq!(err.status_code(), 403);
+
+    let url = Url::parse(&format!("http://localhost/colors_view/{}", ID)).unwrap();
+    let err = CibouletteDeleteRequest::try_from(
+        build_request(&store, CibouletteIntention::Delete, &url, &None).unwrap(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        matches!(
+            err,
+            CibouletteError::ForbiddenOperation(_, CibouletteIntention::Delete)
+        ),
+        true
+    );
+    CibouletteReadRequest::try_from(
+        build_request(&store, CibouletteIntention::Read, &url, &None).unwrap(),
+    )
+    .unwrap();
+}
+
+#[test]
+fn immutable_relationship() {
+    let store = gen_bag_capabilities();
+    let url = Url::parse(&format!(
+        "http://localhost/peoples/{}/relationships/articles",
+        ID
+    ))
+    .unwrap();
+    let err = CibouletteUpdateRequest::try_from(
+        build_request(&store, CibouletteIntention::Update, &url, &None).unwrap(),
+    )
+    .unwrap_err();
+
+    assert_eq!(
+        matches!(err, CibouletteError::ForbiddenRelationshipOperation(type_, rel, CibouletteIntention::Update) if type_ == "peoples" && rel == "articles"),
+        true
+    );
+    CibouletteReadRequest::try_from(
+        build_request(&store, CibouletteIntention::Read, &url, &None).unwrap(),
+    )
+    .unwrap();
+}
+
+#[test]
+fn unreadable_relationship() {
+    let store = gen_bag_capabilities();
+    let url = Url::parse(&format!("http://localhost/peoples/{}?include=comments", ID)).unwrap();
+    let err = CibouletteReadRequest::try_from(
+        build_request(&store, CibouletteIntention::Read, &url, &None).unwrap(),
+    )
+    .unwrap_err();
+
+    assert_eq!(
+        matches!(err, CibouletteError::ForbiddenRelationshipOperation(_, rel, CibouletteIntention::Read) if rel == "comments"),
+        true
+    );
+}
+
+#[test]
+fn client_ids_forbidden() {
+    let store = gen_bag_client_ids(CibouletteClientIdPolicy::Forbidden);
+    let url = Url::parse("http://localhost/colors_view").unwrap();
+    let body = Some(
+        r#"{"data":{"id":"6720877a-e27e-4e9e-9ac0-3fff4deb55f2","type":"colors_view","attributes":{"color":"red"}}}"#,
+    );
+    let err = CibouletteCreateRequest::try_from(
+        build_request(&store, CibouletteIntention::Create, &url, &body).unwrap(),
+    )
+    .unwrap_err();
+
+    assert_eq!(
+        matches!(&err, CibouletteError::ClientIdForbidden(type_) if type_ == "colors_view"),
+        true
+    );
+    assert_eq!(
+        err.error_source().unwrap().pointer().as_deref(),
+        Some("/data/id")
+    );
+}
+
+#[test]
+fn client_ids_required() {
+    let store = gen_bag_client_ids(CibouletteClientIdPolicy::Required);
+    let url = Url::parse("http://localhost/colors_view").unwrap();
+    let body = Some(r#"{"data":{"type":"colors_view","attributes":{"color":"red"}}}"#);
+    let err = CibouletteCreateRequest::try_from(
+        build_request(&store, CibouletteIntention::Create, &url, &body).unwrap(),
+    )
+    .unwrap_err();
+
+    assert_eq!(
+        matches!(err, CibouletteError::MissingClientId(type_) if type_ == "colors_view"),
+        true
+    );
+}
@@ -0,0 +1,119 @@
+use super::*;
+
+fn gen_bag_constraints() -> CibouletteStore {
+    let mut store = gen_bag_builder();
+    store
+        .add_attribute_constraint(
+            "peoples",
+            "first-name",
+            CibouletteAttributeConstraint::Length {
+                min: Some(2),
+                max: Some(10),
+            },
+        )
+        .unwrap();
+    store
+        .add_attribute_constraint(
+            "peoples",
+            "twitter",
+            CibouletteAttributeConstraint::Pattern(regex::Regex::new("^@").unwrap()),
+        )
+        .unwrap();
+    store
+        .add_attribute_constraint(
+            "peoples",
+            "age",
+            CibouletteAttributeConstraint::Range {
+                min: None,
+                max: Some(150),
+            },
+        )
+        .unwrap();
+    store
+        .add_attribute_constraint(
+            "peoples",
+            "gender",
+            CibouletteAttributeConstraint::OneOf(vec![
+                arcstr::literal!("F"),
+                arcstr::literal!("M"),
+                arcstr::literal!("X"),
+            ]),
+        )
+        .unwrap();
+    store
+        .add_attribute_constraint(
+            "peoples",
+            "last-name",
+            CibouletteAttributeConstraint::Custom(Arc::new(
+                |value: &MessyJsonValue<'_>| match value {
+                    MessyJsonValue::String(x) if x.chars().all(|c| c.is_alphabetic()) => Ok(()),
+                    _ => Err("The value should only contain letters".to_string()),
+                },
+            )),
+        )
+        .unwrap();
+    store.build().unwrap()
+}
+
+#[test]
+fn valid() {
+    let store = gen_bag_constraints();
+    let url = Url::parse("http://localhost/peoples").unwrap();
+    let body = Some(
+        r#"{"data":{"type":"peoples","attributes":{"first-name":"ab","last-name":"cd","age":42,"gender":"X","twitter":"@ef"}}}"#,
+    );
+
+    build_request(&store, CibouletteIntention::Create, &url, &body).unwrap();
+}
+
+#[test]
+fn every_violation_reported() {
+    let store = gen_bag_constraints();
+    let url = Url::parse("http://localhost/peoples").unwrap();
+    let body = Some(
+        r#"{"data":{"type":"peoples","attributes":{"first-name":"a","last-name":"c3","age":420,"gender":"Y","twitter":"ef"}}}"#,
+    );
+    let err = build_request(&store, CibouletteIntention::Create, &url, &body).unwrap_err();
+
+    assert_eq!(err.status_code(), 422);
+    let mut pointers: Vec<String> = err
+        .error_objs()
+        .into_iter()
+        .map(|obj| {
+            assert_eq!(obj.status(), &422);
+            obj.source()
+                .as_ref()
+                .unwrap()
+                .pointer()
+                .as_ref()
+                .unwrap()
+                .to_string()
+        })
+        .collect();
+    pointers.sort();
+    assert_eq!(
+        pointers,
+        vec![
+            "/data/attributes/age",
+            "/data/attributes/first-name",
+            "/data/attributes/gender",
+            "/data/attributes/last-name",
+            "/data/attributes/twitter",
+        ]
+    );
+}
+
+#[test]
+fn absent_attributes_on_update() {
+    let store = gen_bag_constraints();
+    let url = Url::parse("http://localhost/peoples/6720877a-e27e-4e9e-9ac0-3fff4deb55f2").unwrap();
+    let body = Some(
+        r#"{"data":{"id":"6720877a-e27e-4e9e-9ac0-3fff4deb55f2","type":"peoples","attributes":{"gender":"Z"}}}"#,
+    );
+    let err = build_request(&store, CibouletteIntention::Update, &url, &body).unwrap_err();
+
+    assert_eq!(
+        matches!(&err, CibouletteError::ConstraintViolations(type_, violations) if type_ == "peoples" && violations.len() == 1),
+        true
+    );
+}
@@ -0,0 +1,64 @@
+use super::*;
+
+#[test]
+fn attribute_type_mismatch() {
+    let store = gen_bag();
+    let url = Url::parse("http://localhost/peoples").unwrap();
+    let body = Some(
+        r#"{"data":{"type":"peoples","attributes":{"first-name":"a","last-name":"b","age":"old"}}}"#,
+    );
+    let err = build_request(&store, CibouletteIntention::Create, &url, &body).unwrap_err();
+
+    assert_eq!(
+        matches!(&err, CibouletteError::InvalidMember(pointer, _) if pointer == "/data/attributes/age"),
+        true
+    );
+    let obj = CibouletteErrorObj::from(&err);
+    assert_eq!(obj.status, 400);
+    assert_eq!(
+        obj.source.unwrap().pointer().as_deref(),
+        Some("/data/attributes/age")
+    );
+}
+
+#[test]
+fn attribute_type_mismatch_in_compound_document() {
+    let store = gen_bag();
+    let url = Url::parse("http://localhost/peoples").unwrap();
+    let body = Some(
+        r#"{"data":[{"type":"peoples","id":"6720877a-e27e-4e9e-9ac0-3fff4deb55f2"},{"type":"peoples","id":"6720877a-e27e-4e9e-9ac0-3fff4deb55f3","attributes":{"first-name":42}}]}"#,
+    );
+    let err = build_request(&store, CibouletteIntention::Read, &url, &body).unwrap_err();
+
+    assert_eq!(
+        matches!(&err, CibouletteError::InvalidMember(pointer, _) if pointer == "/data/1/attributes/first-name"),
+        true
+    );
+}
+
+#[test]
+fn resource_member_type_mismatch() {
+    let store = gen_bag();
+    let url = Url::parse("http://localhost/peoples").unwrap();
+    let body = Some(r#"{"data":[{"type":"peoples","id":42}]}"#);
+    let err = build_request(&store, CibouletteIntention::Read, &url, &body).unwrap_err();
+
+    assert_eq!(
+        matches!(&err, CibouletteError::InvalidMember(pointer, _) if pointer == "/data/0/id"),
+        true
+    );
+    assert_eq!(
+        err.error_source().unwrap().pointer().as_deref(),
+        Some("/data/0/id")
+    );
+}
+
+#[test]
+fn syntax_error() {
+    let store = gen_bag();
+    let url = Url::parse("http://localhost/peoples").unwrap();
+    let body = Some(r#"nope"#);
+    let err = build_request(&store, CibouletteIntention::Create, &url, &body).unwrap_err();
+
+    assert_eq!(matches!(err, CibouletteError::SerdeJson(_)), true);
+}
@@ -0,0 +1,186 @@
+use super::*;
+
+const VERSION_EXT: &str = "https://example.com/ext/version";
+
+#[derive(Debug)]
+struct VersionExtension;
+
+impl CibouletteExtension for VersionExtension {
+    fn uri(&self) -> &str {
+        VERSION_EXT
+    }
+
+    fn namespace(&self) -> &str {
+        "version"
+    }
+
+    fn document_members(&self) -> &[&str] {
+        &["latest"]
+    }
+
+    fn resource_members(&self) -> &[&str] {
+        &["id"]
+    }
+
+    fn deserialize_member(
+        &self,
+        _member: &str,
+        value: &Value,
+    ) -> Result<CibouletteExtensionValue, CibouletteError> {
+        let version: u64 = serde_json::from_value(value.clone())?;
+        Ok(Arc::new(version))
+    }
+}
+
+fn gen_bag_extension() -> CibouletteStore {
+    let mut store = gen_bag_builder();
+    store.add_extension(Arc::new(VersionExtension)).unwrap();
+    store.build().unwrap()
+}
+
+const BODY: &str = r#"
+{
+	"data":
+	{
+		"type": "comments",
+		"attributes":
+		{
+			"body": "world"
+		},
+		"version:id": 42
+	},
+	"version:latest": 43
+}
+"#;
+
+#[test]
+fn typed_members() {
+    let store = gen_bag_extension();
+    let url = Url::parse("http://localhost/comments").unwrap();
+    let body = Some(BODY);
+    let media_type = format!("application/vnd.api+json; ext=\"{}\"", VERSION_EXT);
+    let builder = CibouletteRequestBuilder::new(CibouletteIntention::Create, &url, &body)
+        .with_media_type(media_type.as_str());
+    let request = CibouletteCreateRequest::try_from(builder.build(&store).unwrap()).unwrap();
+
+    assert_eq!(request.extensions().get::<u64>("version:latest"), Some(&43));
+    assert_eq!(
+        request.data().extension_data().get::<u64>("version:id"),
+        Some(&42)
+    );
+    assert_eq!(
+        request.data().extension_data().get::<String>("version:id"),
+        None
+    );
+}
+
+#[test]
+fn echoed() {
+    let store = gen_bag_extension();
+    let url = Url::parse("http://localhost/comments").unwrap();
+    let body = Some(BODY);
+    let media_type = format!("application/vnd.api+json; ext=\"{}\"", VERSION_EXT);
+    let builder = CibouletteRequestBuilder::new(CibouletteIntention::Create, &url, &body)
+        .with_media_type(media_type.as_str());
+    let request = CibouletteCreateRequest::try_from(builder.build(&store).unwrap()).unwrap();
+
+    assert_eq!(request.ext(), &[ArcStr::from(VERSION_EXT)]);
+
+    let response = CibouletteResponseDataBuilder::new(
+        &request,
+        Vec::<CibouletteResponseElement<'_, Value>>::new(),
+    )
+    .build(store.config())
+    .unwrap();
+    let response = serde_json::to_value(&response).unwrap();
+
+    assert_eq!(
+        response["jsonapi"],
+        serde_json::json!({ "version": "1.1", "ext": [VERSION_EXT] })
+    );
+}
+
+#[test]
+fn not_negotiated() {
+    let store = gen_bag_extension();
+    let url = Url::parse("http://localhost/comments").unwrap();
+    let body = Some(BODY);
+    let builder = CibouletteRequestBuilder::new(CibouletteIntention::Create, &url, &body);
+    let err = builder.build(&store).unwrap_err();
+
+    assert_eq!(
+        matches!(err, CibouletteError::ExtensionNotNegotiated(uri) if uri == VERSION_EXT),
+        true
+    );
+}
+
+#[test]
+fn unknown_extension() {
+    let store = gen_bag_extension();
+    let url = Url::parse("http://localhost/comments").unwrap();
+    let body = Some(BODY);
+    let builder = CibouletteRequestBuilder::new(CibouletteIntention::Create, &url, &body)
+        .with_media_type("application/vnd.api+json; ext=\"https://example.com/nope\"");
+    let err = builder.build(&store).unwrap_err();
+
+    assert_eq!(
+        matches!(&err, CibouletteError::UnknownExtension(uri) if uri == "https://example.com/nope"),
+        true
+    );
+    assert_eq!(err.status_code(), 415);
+}
+
+#[test]
+fn invalid_media_type() {
+    let store = gen_bag_extension();
+    let url = Url::parse("http://localhost/comments").unwrap();
+    let body = Some(BODY);
+    let builder = CibouletteRequestBuilder::new(CibouletteIntention::Create, &url, &body)
+        .with_media_type("application/vnd.api+json; charset=utf-8");
+    let err = builder.build(&store).unwrap_err();
+
+    assert_eq!(matches!(&err, CibouletteError::InvalidMediaType(_)), true);
+    assert_eq!(err.status_code(), 415);
+}
+
+#[test]
+fn undeclared_member() {
+    let store = gen_bag_extension();
+    let url = Url::parse("http://localhost/comments").unwrap();
+    let body = Some(
+        r#"
+	{
+		"data":
+		{
+			"type": "comments",
+			"attributes":
+			{
+				"body": "world"
+			},
+			"version:latest": 42
+		}
+	}
+	"#,
+    );
+    let media_type = format!("application/vnd.api+json; ext=\"{}\"", VERSION_EXT);
+    let builder = CibouletteRequestBuilder::new(CibouletteIntention::Create, &url, &body)
+        .with_media_type(media_type.as_str());
+    let err = builder.build(&store).unwrap_err();
+
+    assert_eq!(
+        matches!(err, CibouletteError::UnknownExtensionMember(name) if name == "version:latest"),
+        true
+    );
+}
+
+#[test]
+fn duplicate_extension() {
+    let mut store = gen_bag_builder();
+    store.add_extension(Arc::new(VersionExtension)).unwrap();
+    let err = store.add_extension(Arc::new(VersionExtension)).unwrap_err();
+
+    assert_eq!(
+        matches!(err, CibouletteError::UniqExtension(uri) if uri == VERSION_EXT),
+        true
+    );
+}
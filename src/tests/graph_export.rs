@@ -0,0 +1,78 @@
+use super::*;
+
+#[test]
+fn dot() {
+    let dot = gen_bag().to_dot().unwrap();
+
+    assert_eq!(dot.starts_with("digraph ciboulette {"), true);
+    assert_eq!(
+        dot.contains(
+            r#""peoples" [label="{peoples|id: uuid (id)\lage?: u64\lfirst-name: string\l"#
+        ),
+        true
+    );
+    assert_eq!(
+        dot.contains(
+            r#""comments" -> "peoples" [label="author\nManyToOne\nkeys: peoples.id <- comments.author"];"#
+        ),
+        true
+    );
+    assert_eq!(
+        dot.contains(
+            r#""articles" -> "peoples" [label="author\nManyToMany\nkeys: people-article.article_id, people-article.people_id\nbucket: people-article"];"#
+        ),
+        true
+    );
+    // The links to the buckets are told apart
+    assert_eq!(
+        dot.lines()
+            .any(|x| x.starts_with(r#"    "people-article" -> "articles""#)
+                && x.ends_with(", style=dashed];")),
+        true
+    );
+}
+
+#[test]
+fn many_to_many_direction() {
+    let store = gen_bag();
+    let dot = store.to_dot().unwrap();
+    let mermaid = store.to_mermaid().unwrap();
+
+    // Every Many-to-Many edge is drawn from the type holding it
+    assert_eq!(
+        dot.contains(
+            r#""peoples" -> "articles" [label="articles\nManyToMany\nkeys: people-article.people_id, people-article.article_id\nbucket: people-article"];"#
+        ),
+        true
+    );
+    assert_eq!(dot.contains(r#"(not exposed)\nManyToMany"#), false);
+    assert_eq!(
+        mermaid.contains("    peoples }o--o{ articles : \"articles, ManyToMany"),
+        true
+    );
+    assert_eq!(mermaid.contains("(not exposed), ManyToMany"), false);
+}
+
+#[test]
+fn mermaid() {
+    let mermaid = gen_bag().to_mermaid().unwrap();
+
+    assert_eq!(mermaid.starts_with("erDiagram\n"), true);
+    assert_eq!(
+        mermaid.contains(
+            "    people-article {\n        uuid people_id PK\n        uuid article_id PK\n"
+        ),
+        true
+    );
+    assert_eq!(mermaid.contains("        u64 age \"optional\"\n"), true);
+    assert_eq!(
+        mermaid.contains(
+            "    peoples }o--o| favorite_color : \"favorite_color, ManyToOne, keys: favorite_color.id <- peoples.favorite_color, optional\""
+        ),
+        true
+    );
+    assert_eq!(
+        mermaid.contains("    articles }o--o{ peoples : \"author, ManyToMany"),
+        true
+    );
+}
@@ -196,3 +196,60 @@ fn multi_id_in_relationship() {
     let builder = CibouletteRequestBuilder::new(INTENTION, &parsed_url, &BODY);
     builder.build(&store).unwrap();
 }
+
+#[test]
+fn public_name() {
+    let mut store = gen_bag_builder();
+    store.set_public_name("comments", "remarks").unwrap();
+    let store = store.build().unwrap();
+    let url = Url::parse("http://localhost/").unwrap();
+    let opt = url::Url::options().base_url(Some(&url));
+    const URL: &str = "/remarks";
+    const INTENTION: CibouletteIntention = CibouletteIntention::Create;
+    const BODY: Option<&str> = Some(
+        r#"
+	{
+		"data":
+		{
+			"id": "6720877a-e27e-4e9e-9ac0-3fff4deb55f2",
+			"type": "remarks",
+			"attributes":
+			{
+				"body": "world"
+			}
+		}
+	}
+	"#,
+    );
+
+    let parsed_url = opt.parse(URL).unwrap();
+    let builder = CibouletteRequestBuilder::new(INTENTION, &parsed_url, &BODY);
+    let res = builder.build(&store).unwrap();
+
+    assert_eq!(
+        matches!(res.path(), CiboulettePath::Type(x) if x.as_ref() == store.get_type("comments").unwrap().as_ref()),
+        true
+    );
+    CibouletteCreateRequest::try_from(res).unwrap();
+}
+
+#[test]
+fn public_name_hides_type_name() {
+    let mut store = gen_bag_builder();
+    store.set_public_name("comments", "remarks").unwrap();
+    let store = store.build().unwrap();
+    let url = Url::parse("http://localhost/").unwrap();
+    let opt = url::Url::options().base_url(Some(&url));
+    const URL: &str = "/comments";
+    const INTENTION: CibouletteIntention = CibouletteIntention::Read;
+    const BODY: Option<&str> = None;
+
+    let parsed_url = opt.parse(URL).unwrap();
+    let builder = CibouletteRequestBuilder::new(INTENTION, &parsed_url, &BODY);
+    let err = builder.build(&store).unwrap_err();
+
+    assert_eq!(
+        matches!(err, CibouletteError::UnknownType(x) if x == "comments"),
+        true
+    );
+}
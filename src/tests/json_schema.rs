@@ -0,0 +1,111 @@
+use super::*;
+
+#[test]
+fn bodies() {
+    let store = gen_bag();
+    let schemas = store.json_schemas("peoples").unwrap();
+    let create = schemas.create().as_ref().unwrap();
+
+    assert_eq!(
+        create["$schema"],
+        "https://json-schema.org/draft/2020-12/schema"
+    );
+    assert_eq!(
+        create["properties"]["data"]["$ref"],
+        "#/$defs/peoples-create"
+    );
+    assert_eq!(
+        create["$defs"]["peoples-create"]["properties"]["attributes"]["required"],
+        serde_json::json!(["first-name", "last-name"])
+    );
+    let update = schemas.update().as_ref().unwrap();
+    // Every attribute is optional in an update
+    assert_eq!(
+        update["$defs"]["peoples-update"]["properties"]["attributes"]
+            .get("required")
+            .is_none(),
+        true
+    );
+    assert_eq!(
+        update["$defs"]["peoples-update"]["required"],
+        serde_json::json!(["type", "id"])
+    );
+}
+
+/// Check the `type` keyword of a schema against a value
+fn type_accepts(schema: &Value, value: &Value) -> bool {
+    let type_ = match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "integer",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    };
+    match &schema["type"] {
+        Value::String(x) => x == type_,
+        Value::Array(x) => x.contains(&Value::from(type_)),
+        _ => true,
+    }
+}
+
+#[test]
+fn update_to_null() {
+    let store = gen_bag();
+    let schemas = store.json_schemas("peoples").unwrap();
+    let update = schemas.update().as_ref().unwrap();
+    let attributes = &update["$defs"]["peoples-update"]["properties"]["attributes"]["properties"];
+    let body = serde_json::json!({ "first-name": "a", "gender": null, "age": null });
+
+    for (name, value) in body.as_object().unwrap() {
+        assert_eq!(type_accepts(&attributes[name], value), true, "{}", name);
+    }
+    // The mandatory attributes can't be unset
+    assert_eq!(type_accepts(&attributes["first-name"], &Value::Null), false);
+}
+
+#[test]
+fn relationships() {
+    let store = gen_bag();
+    let schemas = store.json_schemas("peoples").unwrap();
+
+    assert_eq!(
+        schemas.relationships()["comments"]["properties"]["data"]["type"],
+        "array"
+    );
+    assert_eq!(
+        schemas.relationships()["favorite_color"]["properties"]["data"]["oneOf"][0]["$ref"],
+        "#/$defs/favorite_color-identifier"
+    );
+}
+
+#[test]
+fn responses() {
+    let store = gen_bag();
+    let schemas = store.json_schemas("comments").unwrap();
+
+    assert_eq!(
+        schemas.single()["properties"]["data"]["$ref"],
+        "#/$defs/comments"
+    );
+    assert_eq!(
+        schemas.collection()["properties"]["data"]["items"]["$ref"],
+        "#/$defs/comments"
+    );
+    assert_eq!(
+        schemas.single()["$defs"]["comments"]["properties"]["relationships"]["properties"]
+            ["author"]["properties"]["data"]["oneOf"][1],
+        serde_json::json!({ "type": "null" })
+    );
+}
+
+#[test]
+fn unknown_type() {
+    let store = gen_bag();
+    let err = store.json_schemas("nope").unwrap_err();
+
+    assert_eq!(
+        matches!(err, CibouletteError::UnknownType(x) if x == "nope"),
+        true
+    );
+}
@@ -1,8 +1,25 @@
 use super::test_helper::*;
 use super::*;
 
+mod attribute_policies;
+mod capabilities;
+mod constraints;
 mod create_requests;
 mod delete_requests;
+mod error_pointers;
+mod extensions;
+mod graph_export;
 mod inbound_request;
+mod json_schema;
+mod multiple_relationships;
+mod naming_policy;
+mod openapi;
+mod partial_attributes;
+mod profiles;
 mod read_requests;
+mod self_relationships;
+mod store_definition;
+mod store_lint;
+mod typed_attributes;
+mod unknown_members;
 mod update_requests;
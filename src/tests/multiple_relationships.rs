@@ -0,0 +1,137 @@
+use super::*;
+
+const ARTICLE_ID: &str = "c49094ce-71ab-40d4-a642-ea200f72eac6";
+const AUTHOR_ID: &str = "073b5936-0acb-4601-b4b7-9de607dfc2ef";
+const REVIEWER_ID: &str = "ea2c0ae1-e3b0-41b2-82c2-2f000d4dc367";
+
+/// `articles` relate to `peoples` both as `author` (Many-to-Many) and `reviewer` (Many-to-One)
+fn gen_bag_reviewers() -> CibouletteStore {
+    let mut store = gen_bag_builder();
+    store
+        .add_one_to_many_rel(
+            CibouletteRelationshipOneToManyOptionBuilder::new(
+                store.get_type("peoples").unwrap().clone(),
+                arcstr::literal!("id"),
+                store.get_type("articles").unwrap().clone(),
+                arcstr::literal!("reviewer"),
+                true,
+            ),
+            Some(arcstr::literal!("reviewer")),
+            Some(arcstr::literal!("reviewed")),
+        )
+        .unwrap();
+    store.build().unwrap()
+}
+
+#[test]
+fn aliases() {
+    let store = gen_bag_reviewers();
+    let articles = store.get_type("articles").unwrap();
+
+    assert_eq!(
+        articles
+            .get_aliases("peoples")
+            .unwrap()
+            .iter()
+            .map(ArcStr::as_str)
+            .collect::<Vec<&str>>(),
+        vec!["author", "reviewer"]
+    );
+    assert_eq!(
+        matches!(
+            articles.get_alias("peoples"),
+            Err(CibouletteError::AmbiguousAlias(type_, related, aliases))
+                if type_ == "articles" && related == "peoples" && aliases == &["author", "reviewer"]
+        ),
+        true
+    );
+    assert_eq!(
+        store
+            .get_type("peoples")
+            .unwrap()
+            .get_aliases("articles")
+            .unwrap()
+            .len(),
+        2
+    );
+    assert_eq!(
+        store
+            .get_type("comments")
+            .unwrap()
+            .get_alias("peoples")
+            .unwrap(),
+        "author"
+    );
+    assert_eq!(
+        store.get_rel("articles", "reviewer").unwrap().0.name(),
+        "peoples"
+    );
+    assert_eq!(
+        matches!(
+            store.get_rel("articles", "reviewer").unwrap().1,
+            CibouletteRelationshipOption::ManyToOne(_)
+        ),
+        true
+    );
+    assert_eq!(
+        matches!(
+            store.get_rel("articles", "author").unwrap().1,
+            CibouletteRelationshipOption::ManyToMany(_)
+        ),
+        true
+    );
+}
+
+#[test]
+fn include_both() {
+    let store = gen_bag_reviewers();
+    let url = Url::parse("http://localhost/").unwrap();
+    let opt = url::Url::options().base_url(Some(&url));
+    let parsed_url = opt
+        .parse(&format!("/articles/{}?include=author,reviewer", ARTICLE_ID))
+        .unwrap();
+    let builder = CibouletteRequestBuilder::new(CibouletteIntention::Read, &parsed_url, &None);
+    let request = builder.build(&store).unwrap();
+    let res = CibouletteReadRequest::try_from(request).unwrap();
+    let base_type = store.get_type("articles").unwrap();
+    let response = CibouletteResponseDataBuilder::new(
+        &res,
+        vec![
+            CibouletteResponseElement::build(
+                &store,
+                base_type,
+                CibouletteResourceIdentifierBuilder::new(
+                    Some(Cow::Borrowed(ARTICLE_ID)),
+                    Cow::Borrowed("articles"),
+                ),
+                Some(String::from("<some data>")),
+                None,
+            )
+            .unwrap(),
+            related_row(&store, base_type, ARTICLE_ID, "author", AUTHOR_ID),
+            related_row(&store, base_type, ARTICLE_ID, "author", REVIEWER_ID),
+            related_row(&store, base_type, ARTICLE_ID, "reviewer", REVIEWER_ID),
+        ],
+    )
+    .build(store.config())
+    .unwrap();
+    let response = serde_json::to_value(&response).unwrap();
+
+    assert_eq!(
+        response["data"]["relationships"]["author"]["data"],
+        serde_json::json!([
+            { "type": "peoples", "id": AUTHOR_ID },
+            { "type": "peoples", "id": REVIEWER_ID }
+        ])
+    );
+    assert_eq!(
+        response["data"]["relationships"]["reviewer"]["data"],
+        serde_json::json!({ "type": "peoples", "id": REVIEWER_ID })
+    );
+    assert_eq!(
+        response["data"]["relationships"]["reviewer"]["links"]["related"],
+        serde_json::json!(format!("/articles/{}/reviewer", ARTICLE_ID))
+    );
+    // A resource related twice is included once
+    assert_eq!(response["included"].as_array().unwrap().len(), 2);
+}
@@ -0,0 +1,343 @@
+use super::*;
+
+fn gen_bag_camel_case() -> CibouletteStore {
+    let mut store = gen_bag_builder();
+    *store.naming_policy_mut() = CibouletteNamingPolicy::CamelCase;
+    store.build().unwrap()
+}
+
+#[test]
+fn attributes_and_relationships() {
+    let store = gen_bag_camel_case();
+    const VAL: &str = r#"
+	{
+		"data":
+		{
+			"id": "6720877a-e27e-4e9e-9ac0-3fff4deb55f2",
+			"type": "peoples",
+			"attributes":
+			{
+				"firstName": "Hello",
+				"lastName": "World"
+			},
+			"relationships":
+			{
+				"favoriteColor":
+				{
+					"data":
+					{
+						"id": "b8ea4a7b-0e44-4ad5-8bd1-8e4c8bc3d10b",
+						"type": "favorite_color"
+					}
+				}
+			}
+		}
+	}
+	"#;
+    let mut deserializer = serde_json::Deserializer::from_str(VAL);
+    let doc = CibouletteBodyBuilder::deserialize(&mut deserializer)
+        .unwrap()
+        .build(&store, &CibouletteIntention::Create)
+        .unwrap();
+    let data = match doc.data() {
+        CibouletteBodyData::Object(x) => x,
+        _ => panic!("data should've been present"),
+    };
+    let data = check_single(&data);
+    assert_eq!(
+        data.attributes().clone().unwrap().eq(&serde_json::json!({
+            "first-name": "Hello",
+            "last-name": "World"
+        })),
+        true
+    );
+    assert_eq!(data.relationships().contains_key("favorite_color"), true);
+}
+
+#[test]
+fn schema_names_are_hidden() {
+    let store = gen_bag_camel_case();
+    const VAL: &str = r#"
+	{
+		"data":
+		{
+			"id": "6720877a-e27e-4e9e-9ac0-3fff4deb55f2",
+			"type": "peoples",
+			"relationships":
+			{
+				"favorite_color":
+				{
+					"data":
+					{
+						"id": "b8ea4a7b-0e44-4ad5-8bd1-8e4c8bc3d10b",
+						"type": "favorite_color"
+					}
+				}
+			}
+		}
+	}
+	"#;
+    let mut deserializer = serde_json::Deserializer::from_str(VAL);
+    let err = CibouletteBodyBuilder::deserialize(&mut deserializer)
+        .unwrap()
+        .build(&store, &CibouletteIntention::Read)
+        .unwrap_err();
+    assert_eq!(
+        matches!(err, CibouletteError::UnknownRelationship(type_, rel) if type_ == "peoples" && rel == "favorite_color"),
+        true
+    );
+}
+
+#[test]
+fn query_parameters() {
+    let store = gen_bag_camel_case();
+    let url = Url::parse("http://localhost/").unwrap();
+    let opt = url::Url::options().base_url(Some(&url));
+    const URL: &str = "/peoples?sort=-lastName&fields[peoples]=firstName&include=favoriteColor";
+    const INTENTION: CibouletteIntention = CibouletteIntention::Read;
+    const BODY: Option<&str> = None;
+
+    let parsed_url = opt.parse(URL).unwrap();
+    let builder = CibouletteRequestBuilder::new(INTENTION, &parsed_url, &BODY);
+    let res = builder.build(&store).unwrap();
+    let peoples = store.get_type("peoples").unwrap();
+
+    assert_eq!(res.query().sorting()[0].field().as_str(), "last-name");
+    assert_eq!(
+        res.query().sparse().get(peoples).unwrap().attributes()[0],
+        "first-name"
+    );
+    assert_eq!(
+        res.query().include()[0][0].relation_alias().as_str(),
+        "favorite_color"
+    );
+}
+
+#[test]
+fn query_parameters_schema_names() {
+    let store = gen_bag_camel_case();
+    let url = Url::parse("http://localhost/").unwrap();
+    let opt = url::Url::options().base_url(Some(&url));
+    const URL: &str = "/peoples?sort=last-name";
+    const INTENTION: CibouletteIntention = CibouletteIntention::Read;
+    const BODY: Option<&str> = None;
+
+    let parsed_url = opt.parse(URL).unwrap();
+    let builder = CibouletteRequestBuilder::new(INTENTION, &parsed_url, &BODY);
+    let err = builder.build(&store).unwrap_err();
+
+    assert_eq!(
+        matches!(err, CibouletteError::UnknownField(type_, field) if type_ == "peoples" && field == "last-name"),
+        true
+    );
+}
+
+#[test]
+fn path_relationship() {
+    let store = gen_bag_camel_case();
+    let url = Url::parse("http://localhost/").unwrap();
+    let opt = url::Url::options().base_url(Some(&url));
+    const URL: &str = "/peoples/6720877a-e27e-4e9e-9ac0-3fff4deb55f2/favoriteColor";
+    const INTENTION: CibouletteIntention = CibouletteIntention::Read;
+    const BODY: Option<&str> = None;
+
+    let parsed_url = opt.parse(URL).unwrap();
+    let builder = CibouletteRequestBuilder::new(INTENTION, &parsed_url, &BODY);
+    let res = builder.build(&store).unwrap();
+
+    assert_eq!(
+        matches!(res.path(), CiboulettePath::TypeIdRelated(_, _, rel) if rel.relation_alias().as_str() == "favorite_color"),
+        true
+    );
+}
+
+#[test]
+fn response_attributes() {
+    let store = gen_bag_camel_case();
+    let url = Url::parse("http://localhost/").unwrap();
+    let opt = url::Url::options().base_url(Some(&url));
+    const URL: &str = "/peoples/6720877a-e27e-4e9e-9ac0-3fff4deb55f2";
+    const INTENTION: CibouletteIntention = CibouletteIntention::Read;
+
+    let parsed_url = opt.parse(URL).unwrap();
+    let builder = CibouletteRequestBuilder::new(INTENTION, &parsed_url, &None);
+    let request = builder.build(&store).unwrap();
+    let res = CibouletteReadRequest::try_from(request).unwrap();
+    let base_type = store.get_type("peoples").unwrap();
+    let element = CibouletteResponseElement::build(
+        &store,
+        &base_type,
+        CibouletteResourceIdentifierBuilder::new(
+            Some(Cow::Borrowed("6720877a-e27e-4e9e-9ac0-3fff4deb55f2")),
+            Cow::Borrowed("peoples"),
+        ),
+        Some(serde_json::json!({ "first-name": "Hello", "age": 42 })),
+        None,
+    )
+    .unwrap();
+    let response = CibouletteResponseDataBuilder::new(&res, vec![element])
+        .build(store.config())
+        .unwrap();
+    let response = serde_json::to_value(&response).unwrap();
+
+    assert_eq!(
+        response["data"]["attributes"],
+        serde_json::json!({ "firstName": "Hello", "age": 42 })
+    );
+}
+
+#[test]
+fn member_name_clash() {
+    let mut store = CibouletteStoreBuilder::default();
+    *store.naming_policy_mut() = CibouletteNamingPolicy::CamelCase;
+    store
+        .add_type(
+            "toto",
+            CibouletteIdTypeSelector::new(CibouletteSelector::Single(CibouletteIdType::Uuid(
+                arcstr::literal!("id"),
+            ))),
+            MessyJsonObject::from(MessyJsonObjectInner::new(
+                vec![
+                    (
+                        arcstr::literal!("first_name"),
+                        MessyJson::from(MessyJsonInner::String(MessyJsonScalar::new(false))),
+                    ),
+                    (
+                        arcstr::literal!("first-name"),
+                        MessyJson::from(MessyJsonInner::String(MessyJsonScalar::new(false))),
+                    ),
+                ]
+                .into_iter()
+                .collect(),
+                false,
+            )),
+        )
+        .unwrap();
+    let err = store.build().unwrap_err();
+    assert_eq!(
+        matches!(err, CibouletteError::MemberNameClash(type_, name) if type_ == "toto" && name == "firstName"),
+        true
+    );
+}
+
+fn gen_bag_nested() -> CibouletteStore {
+    let zip_code = || {
+        MessyJsonObject::from(MessyJsonObjectInner::new(
+            vec![(
+                arcstr::literal!("zip_code"),
+                MessyJson::from(MessyJsonInner::String(MessyJsonScalar::new(false))),
+            )]
+            .into_iter()
+            .collect(),
+            false,
+        ))
+    };
+    let mut store = CibouletteStoreBuilder::default();
+    *store.naming_policy_mut() = CibouletteNamingPolicy::CamelCase;
+    store
+        .add_type(
+            "toto",
+            CibouletteIdTypeSelector::new(CibouletteSelector::Single(CibouletteIdType::Uuid(
+                arcstr::literal!("id"),
+            ))),
+            MessyJsonObject::from(MessyJsonObjectInner::new(
+                vec![
+                    (
+                        arcstr::literal!("home_address"),
+                        MessyJson::from(MessyJsonInner::Obj(zip_code())),
+                    ),
+                    (
+                        arcstr::literal!("past_addresses"),
+                        MessyJson::from(MessyJsonInner::Array(MessyJsonArray::from(
+                            MessyJsonArrayInner::new(
+                                MessyJson::from(MessyJsonInner::Obj(zip_code())),
+                                false,
+                            ),
+                        ))),
+                    ),
+                ]
+                .into_iter()
+                .collect(),
+                false,
+            )),
+        )
+        .unwrap();
+    store.build().unwrap()
+}
+
+#[test]
+fn nested_attributes() {
+    let store = gen_bag_nested();
+    const VAL: &str = r#"
+	{
+		"data":
+		{
+			"id": "6720877a-e27e-4e9e-9ac0-3fff4deb55f2",
+			"type": "toto",
+			"attributes":
+			{
+				"homeAddress": { "zipCode": "75001" },
+				"pastAddresses": [{ "zipCode": "69001" }]
+			}
+		}
+	}
+	"#;
+    let mut deserializer = serde_json::Deserializer::from_str(VAL);
+    let doc = CibouletteBodyBuilder::deserialize(&mut deserializer)
+        .unwrap()
+        .build(&store, &CibouletteIntention::Create)
+        .unwrap();
+    let data = match doc.data() {
+        CibouletteBodyData::Object(x) => x,
+        _ => panic!("data should've been present"),
+    };
+    let data = check_single(&data);
+    assert_eq!(
+        data.attributes().clone().unwrap().eq(&serde_json::json!({
+            "home_address": { "zip_code": "75001" },
+            "past_addresses": [{ "zip_code": "69001" }]
+        })),
+        true
+    );
+}
+
+#[test]
+fn nested_response_attributes() {
+    let store = gen_bag_nested();
+    let url = Url::parse("http://localhost/").unwrap();
+    let opt = url::Url::options().base_url(Some(&url));
+    const URL: &str = "/toto/6720877a-e27e-4e9e-9ac0-3fff4deb55f2";
+    const INTENTION: CibouletteIntention = CibouletteIntention::Read;
+
+    let parsed_url = opt.parse(URL).unwrap();
+    let builder = CibouletteRequestBuilder::new(INTENTION, &parsed_url, &None);
+    let request = builder.build(&store).unwrap();
+    let res = CibouletteReadRequest::try_from(request).unwrap();
+    let base_type = store.get_type("toto").unwrap();
+    let element = CibouletteResponseElement::build(
+        &store,
+        &base_type,
+        CibouletteResourceIdentifierBuilder::new(
+            Some(Cow::Borrowed("6720877a-e27e-4e9e-9ac0-3fff4deb55f2")),
+            Cow::Borrowed("toto"),
+        ),
+        Some(serde_json::json!({
+            "home_address": { "zip_code": "75001" },
+            "past_addresses": [{ "zip_code": "69001" }]
+        })),
+        None,
+    )
+    .unwrap();
+    let response = CibouletteResponseDataBuilder::new(&res, vec![element])
+        .build(store.config())
+        .unwrap();
+    let response = serde_json::to_value(&response).unwrap();
+
+    assert_eq!(
+        response["data"]["attributes"],
+        serde_json::json!({
+            "homeAddress": { "zipCode": "75001" },
+            "pastAddresses": [{ "zipCode": "69001" }]
+        })
+    );
+}
@@ -0,0 +1,180 @@
+use super::*;
+
+#[test]
+fn paths() {
+    let store = gen_bag();
+    let doc = store.openapi("test", "1.0.0");
+
+    assert_eq!(doc["openapi"], "3.1.0");
+    assert_eq!(doc["info"]["title"], "test");
+    let paths = doc["paths"].as_object().unwrap();
+    assert_eq!(paths.contains_key("/peoples"), true);
+    assert_eq!(paths.contains_key("/peoples/{id}"), true);
+    assert_eq!(paths.contains_key("/peoples/{id}/comments"), true);
+    assert_eq!(
+        paths.contains_key("/peoples/{id}/relationships/comments"),
+        true
+    );
+    assert_eq!(
+        paths["/peoples/{id}/relationships/comments"]
+            .as_object()
+            .unwrap()
+            .contains_key("delete"),
+        true
+    );
+    // Resource linkages can't be removed from a to-one relationship
+    assert_eq!(
+        paths["/peoples/{id}/relationships/favorite_color"]
+            .as_object()
+            .unwrap()
+            .contains_key("delete"),
+        false
+    );
+}
+
+#[test]
+fn capabilities() {
+    let mut store = gen_bag_builder();
+    store
+        .add_type_with_capabilities(
+            "colors",
+            CibouletteIdTypeSelector::new(CibouletteSelector::Single(CibouletteIdType::Text(
+                arcstr::literal!("id"),
+            ))),
+            MessyJsonObject::default(),
+            CibouletteTypeCapabilities::read_only(),
+        )
+        .unwrap();
+    let mut capabilities = CibouletteRelationshipCapabilities::default();
+    *capabilities.readable_mut() = false;
+    store
+        .set_relationship_capabilities("peoples", "comments", capabilities)
+        .unwrap();
+    let store = store.build().unwrap();
+    let doc = store.openapi("test", "1.0.0");
+    let paths = doc["paths"].as_object().unwrap();
+
+    let collection = paths["/colors"].as_object().unwrap();
+    assert_eq!(collection.contains_key("get"), true);
+    assert_eq!(collection.contains_key("post"), false);
+    let resource = paths["/colors/{id}"].as_object().unwrap();
+    assert_eq!(resource.contains_key("patch"), false);
+    assert_eq!(resource.contains_key("delete"), false);
+    assert_eq!(
+        doc["components"]["schemas"]
+            .as_object()
+            .unwrap()
+            .contains_key("colors-create"),
+        false
+    );
+    assert_eq!(paths.contains_key("/peoples/{id}/comments"), false);
+}
+
+#[test]
+fn create_schema() {
+    let store = gen_bag();
+    let doc = store.openapi("test", "1.0.0");
+    let schema = &doc["components"]["schemas"]["peoples-create"];
+
+    assert_eq!(
+        schema["properties"]["attributes"]["required"],
+        serde_json::json!(["first-name", "last-name"])
+    );
+    assert_eq!(
+        schema["properties"]["attributes"]["properties"]["age"]["type"],
+        serde_json::json!(["integer", "null"])
+    );
+    assert_eq!(
+        schema["properties"]["attributes"]["properties"]["first-name"]["type"],
+        "string"
+    );
+    assert_eq!(schema["required"], serde_json::json!(["type"]));
+}
+
+#[test]
+fn constraints() {
+    let mut store = gen_bag_builder();
+    store
+        .add_attribute_constraint(
+            "peoples",
+            "age",
+            CibouletteAttributeConstraint::Range {
+                min: Some(u128::from(u64::MAX) + 1),
+                max: Some(u128::MAX),
+            },
+        )
+        .unwrap();
+    store
+        .add_attribute_constraint(
+            "peoples",
+            "gender",
+            CibouletteAttributeConstraint::OneOf(vec![arcstr::literal!("F")]),
+        )
+        .unwrap();
+    let doc = store.build().unwrap().openapi("test", "1.0.0");
+    let attributes =
+        &doc["components"]["schemas"]["peoples-create"]["properties"]["attributes"]["properties"];
+
+    assert_eq!(attributes["age"]["minimum"], Value::from(u64::MAX as f64));
+    assert_eq!(attributes["age"]["maximum"], Value::from(u128::MAX as f64));
+    assert_eq!(attributes["gender"]["enum"], serde_json::json!(["F", null]));
+}
+
+#[test]
+fn query_parameters() {
+    let store = gen_bag();
+    let doc = store.openapi("test", "1.0.0");
+    let parameters = &doc["components"]["parameters"];
+
+    let include = parameters["comments-include"]["schema"]["items"]["enum"]
+        .as_array()
+        .unwrap();
+    assert_eq!(include.contains(&Value::from("author")), true);
+    assert_eq!(
+        include.contains(&Value::from("author.favorite_color")),
+        true
+    );
+    assert_eq!(include.contains(&Value::from("author.comments")), true);
+    // The paths never go through the same relationship twice
+    assert_eq!(
+        include.contains(&Value::from("author.comments.author")),
+        false
+    );
+    let sort = parameters["comments-sort"]["schema"]["items"]["enum"]
+        .as_array()
+        .unwrap();
+    assert_eq!(sort.contains(&Value::from("-body")), true);
+    assert_eq!(sort.contains(&Value::from("author.first-name")), true);
+}
+
+#[test]
+fn self_referential_include_paths() {
+    let store = gen_bag_self();
+    let doc = store.openapi("test", "1.0.0");
+    let parameters = &doc["components"]["parameters"];
+
+    let include = parameters["peoples-include"]["schema"]["items"]["enum"]
+        .as_array()
+        .unwrap();
+    for path in &[
+        "manager",
+        "reports",
+        "friends",
+        "friend_of",
+        "manager.reports",
+        "friends.manager",
+        "articles.author",
+    ] {
+        assert_eq!(include.contains(&Value::from(*path)), true, "{}", path);
+    }
+    assert_eq!(include.contains(&Value::from("friends.friends")), false);
+    // The paths are at most 3 relationships long
+    assert_eq!(
+        include.contains(&Value::from("manager.reports.friends")),
+        true
+    );
+    assert_eq!(
+        include.contains(&Value::from("manager.reports.friends.friend_of")),
+        false
+    );
+}
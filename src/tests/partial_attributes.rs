@@ -0,0 +1,117 @@
+use super::*;
+
+#[test]
+fn create_missing_attributes() {
+    let store = gen_bag();
+    let url = Url::parse("http://localhost/comments").unwrap();
+    let body = Some(r#"{"data":{"type":"comments"}}"#);
+    let request = CibouletteRequestBuilder::new(CibouletteIntention::Create, &url, &body)
+        .build(&store)
+        .unwrap();
+    let err = CibouletteCreateRequest::try_from(request).unwrap_err();
+
+    assert_eq!(
+        matches!(&err, CibouletteError::MissingAttribute(type_, attr) if type_ == "comments" && attr == "body"),
+        true
+    );
+    assert_eq!(
+        err.error_source().unwrap().pointer().as_deref(),
+        Some("/data/attributes")
+    );
+}
+
+#[test]
+fn mandatory_attributes() {
+    let store = gen_bag();
+    let peoples = store.get_type("peoples").unwrap();
+
+    assert_eq!(
+        peoples
+            .mandatory_attributes()
+            .iter()
+            .map(ArcStr::as_str)
+            .collect::<Vec<&str>>(),
+        vec!["first-name", "last-name"]
+    );
+}
+
+#[test]
+fn create_missing_mandatory_attribute() {
+    let store = gen_bag();
+    let url = Url::parse("http://localhost/peoples").unwrap();
+    let body = Some(r#"{"data":{"type":"peoples","attributes":{"first-name":"a"}}}"#);
+    let request = build_request(&store, CibouletteIntention::Create, &url, &body).unwrap();
+    let err = CibouletteCreateRequest::try_from(request).unwrap_err();
+
+    assert_eq!(
+        matches!(&err, CibouletteError::MissingAttribute(type_, attr) if type_ == "peoples" && attr == "last-name"),
+        true
+    );
+}
+
+#[test]
+fn create_missing_read_only_attribute() {
+    let mut store = gen_bag_builder();
+    store
+        .set_attribute_policy("peoples", "last-name", CibouletteAttributePolicy::ReadOnly)
+        .unwrap();
+    let store = store.build().unwrap();
+    let url = Url::parse("http://localhost/peoples").unwrap();
+    let body = Some(r#"{"data":{"type":"peoples","attributes":{"first-name":"a"}}}"#);
+    let request = build_request(&store, CibouletteIntention::Create, &url, &body).unwrap();
+    let request = CibouletteCreateRequest::try_from(request).unwrap();
+
+    assert_eq!(request.data().attribute("last-name").is_present(), false);
+}
+
+#[test]
+fn create_complete() {
+    let store = gen_bag();
+    let url = Url::parse("http://localhost/peoples").unwrap();
+    let body =
+        Some(r#"{"data":{"type":"peoples","attributes":{"first-name":"a","last-name":"b"}}}"#);
+    let request = CibouletteRequestBuilder::new(CibouletteIntention::Create, &url, &body)
+        .build(&store)
+        .unwrap();
+    let request = CibouletteCreateRequest::try_from(request).unwrap();
+
+    assert_eq!(request.data().attribute("age").is_present(), false);
+    assert_eq!(
+        matches!(
+            request.data().attribute("first-name"),
+            CibouletteAttributeValue::Set(_)
+        ),
+        true
+    );
+}
+
+#[test]
+fn update_partial() {
+    let store = gen_bag();
+    let url = Url::parse("http://localhost/peoples/6720877a-e27e-4e9e-9ac0-3fff4deb55f2").unwrap();
+    let body = Some(
+        r#"{"data":{"id":"6720877a-e27e-4e9e-9ac0-3fff4deb55f2","type":"peoples","attributes":{"first-name":"a","gender":null}}}"#,
+    );
+    let request = CibouletteRequestBuilder::new(CibouletteIntention::Update, &url, &body)
+        .build(&store)
+        .unwrap();
+    let request = CibouletteUpdateRequest::try_from(request).unwrap();
+    let data = match request.data() {
+        CibouletteUpdateRequestType::MainType(data) => data,
+        _ => panic!("should be a resource update"),
+    };
+
+    assert_eq!(
+        matches!(
+            data.attribute("first-name"),
+            CibouletteAttributeValue::Set(_)
+        ),
+        true
+    );
+    assert_eq!(data.attribute("gender"), CibouletteAttributeValue::Null);
+    assert_eq!(
+        data.attribute("last-name"),
+        CibouletteAttributeValue::Absent
+    );
+    assert_eq!(data.attribute("twitter"), CibouletteAttributeValue::Absent);
+}
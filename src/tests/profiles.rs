@@ -0,0 +1,84 @@
+use super::*;
+
+const TIMESTAMPS_PROFILE: &str = "https://example.com/profiles/timestamps";
+
+fn gen_bag_profile() -> CibouletteStore {
+    let mut store = gen_bag_builder();
+    store.add_profile(TIMESTAMPS_PROFILE).unwrap();
+    store.build().unwrap()
+}
+
+#[test]
+fn negotiated_and_echoed() {
+    let store = gen_bag_profile();
+    let url = Url::parse("http://localhost/peoples/6720877a-e27e-4e9e-9ac0-3fff4deb55f2").unwrap();
+    let accept = format!(
+        "application/vnd.api+json; profile=\"{} https://example.com/profiles/unknown\"",
+        TIMESTAMPS_PROFILE
+    );
+    let builder = CibouletteRequestBuilder::new(CibouletteIntention::Read, &url, &None)
+        .with_accept(accept.as_str());
+    let request = CibouletteReadRequest::try_from(builder.build(&store).unwrap()).unwrap();
+
+    assert_eq!(request.profiles(), &[ArcStr::from(TIMESTAMPS_PROFILE)]);
+
+    let base_type = store.get_type("peoples").unwrap();
+    let element: CibouletteResponseElement<'_, Value> = CibouletteResponseElement::build(
+        &store,
+        &base_type,
+        CibouletteResourceIdentifierBuilder::new(
+            Some(Cow::Borrowed("6720877a-e27e-4e9e-9ac0-3fff4deb55f2")),
+            Cow::Borrowed("peoples"),
+        ),
+        None,
+        None,
+    )
+    .unwrap();
+    let response = CibouletteResponseDataBuilder::new(&request, vec![element])
+        .build(store.config())
+        .unwrap();
+    let response = serde_json::to_value(&response).unwrap();
+
+    assert_eq!(
+        response["jsonapi"],
+        serde_json::json!({ "version": "1.1", "profile": [TIMESTAMPS_PROFILE] })
+    );
+}
+
+#[test]
+fn unknown_profiles_are_ignored() {
+    let store = gen_bag_profile();
+    let url = Url::parse("http://localhost/peoples").unwrap();
+    let builder = CibouletteRequestBuilder::new(CibouletteIntention::Read, &url, &None)
+        .with_accept("application/vnd.api+json; profile=\"https://example.com/profiles/unknown\"");
+    let request = CibouletteReadRequest::try_from(builder.build(&store).unwrap()).unwrap();
+
+    assert_eq!(request.profiles().is_empty(), true);
+}
+
+#[test]
+fn not_negotiated_from_content_type() {
+    let store = gen_bag_profile();
+    let url = Url::parse("http://localhost/peoples").unwrap();
+    let media_type = format!(
+        "application/vnd.api+json; profile=\"{}\"",
+        TIMESTAMPS_PROFILE
+    );
+    let builder = CibouletteRequestBuilder::new(CibouletteIntention::Read, &url, &None)
+        .with_media_type(media_type.as_str());
+    let request = CibouletteReadRequest::try_from(builder.build(&store).unwrap()).unwrap();
+
+    assert_eq!(request.profiles().is_empty(), true);
+}
+
+#[test]
+fn duplicate_profile() {
+    let mut store = gen_bag_builder();
+    store.add_profile(TIMESTAMPS_PROFILE).unwrap();
+    let err = store.add_profile(TIMESTAMPS_PROFILE).unwrap_err();
+
+    assert_eq!(
+        matches!(err, CibouletteError::UniqProfile(uri) if uri == TIMESTAMPS_PROFILE),
+        true
+    );
+}
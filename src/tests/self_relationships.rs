@@ -0,0 +1,240 @@
+use super::*;
+
+const ALICE: &str = "073b5936-0acb-4601-b4b7-9de607dfc2ef";
+const BOB: &str = "ea2c0ae1-e3b0-41b2-82c2-2f000d4dc367";
+const CAROL: &str = "c49094ce-71ab-40d4-a642-ea200f72eac6";
+
+fn read_request<'request>(
+    store: &CibouletteStore,
+    url: &'request Url,
+) -> CibouletteReadRequest<'request> {
+    let request = build_request(store, CibouletteIntention::Read, url, &None).unwrap();
+    CibouletteReadRequest::try_from(request).unwrap()
+}
+
+fn main_row<'request>(
+    store: &CibouletteStore,
+    id: &'request str,
+) -> CibouletteResponseElement<'request, String> {
+    CibouletteResponseElement::build(
+        store,
+        store.get_type("peoples").unwrap(),
+        CibouletteResourceIdentifierBuilder::new(Some(Cow::Borrowed(id)), Cow::Borrowed("peoples")),
+        Some(String::from("<some data>")),
+        None,
+    )
+    .unwrap()
+}
+
+fn many_to_many_keys(store: &CibouletteStore, alias: &str) -> (String, String) {
+    let details = store
+        .get_type("peoples")
+        .unwrap()
+        .get_relationship_details(store, alias)
+        .unwrap();
+
+    assert_eq!(details.related_type().name(), "peoples");
+    match details.relation_option() {
+        CibouletteRelationshipOption::ManyToMany(opt) => {
+            (opt.source_key().to_string(), opt.target_key().to_string())
+        }
+        _ => panic!("`{}` should be a Many-to-Many relationship", alias),
+    }
+}
+
+#[test]
+fn keys_by_side() {
+    let store = gen_bag_self();
+    let peoples = store.get_type("peoples").unwrap();
+
+    assert_eq!(
+        many_to_many_keys(&store, "friends"),
+        ("people_id".to_string(), "friend_id".to_string())
+    );
+    assert_eq!(
+        many_to_many_keys(&store, "friend_of"),
+        ("friend_id".to_string(), "people_id".to_string())
+    );
+    assert_eq!(
+        matches!(
+            peoples.get_relationship_details(&store, "manager").unwrap().relation_option(),
+            CibouletteRelationshipOption::ManyToOne(opt)
+                if opt.one_resource().name() == "peoples" && opt.many_resource_key() == "manager"
+        ),
+        true
+    );
+    assert_eq!(
+        matches!(
+            peoples
+                .get_relationship_details(&store, "reports")
+                .unwrap()
+                .relation_option(),
+            CibouletteRelationshipOption::OneToMany(_)
+        ),
+        true
+    );
+    assert_eq!(
+        peoples
+            .get_aliases("peoples")
+            .unwrap()
+            .iter()
+            .map(ArcStr::as_str)
+            .collect::<Vec<&str>>(),
+        vec!["friend_of", "friends", "manager", "reports"]
+    );
+    // Both links to the bucket are kept
+    assert_eq!(peoples.relationships().contains_key("friendships"), true);
+    assert_eq!(
+        peoples
+            .relationships()
+            .contains_key("friendships_friend_id"),
+        true
+    );
+    // The keys can't be told apart by type
+    match peoples
+        .get_relationship_details(&store, "friends")
+        .unwrap()
+        .relation_option()
+    {
+        CibouletteRelationshipOption::ManyToMany(opt) => assert_eq!(
+            matches!(
+                opt.keys_for_type(peoples),
+                Err(CibouletteError::AmbiguousBucketKey(bucket, type_))
+                    if bucket == "friendships" && type_ == "peoples"
+            ),
+            true
+        ),
+        _ => panic!("`friends` should be a Many-to-Many relationship"),
+    }
+}
+
+#[test]
+fn include_manager_and_friends() {
+    let store = gen_bag_self();
+    let peoples = store.get_type("peoples").unwrap();
+    let url = Url::parse(&format!(
+        "http://localhost/peoples/{}?include=manager,friends",
+        ALICE
+    ))
+    .unwrap();
+    let request = read_request(&store, &url);
+    let response = CibouletteResponseDataBuilder::new(
+        &request,
+        vec![
+            main_row(&store, ALICE),
+            related_row(&store, peoples, ALICE, "manager", BOB),
+            related_row(&store, peoples, ALICE, "friends", CAROL),
+            related_row(&store, peoples, ALICE, "friends", BOB),
+        ],
+    )
+    .build(store.config())
+    .unwrap();
+    let response = serde_json::to_value(&response).unwrap();
+
+    assert_eq!(
+        response["data"]["relationships"]["manager"]["data"],
+        serde_json::json!({ "type": "peoples", "id": BOB })
+    );
+    assert_eq!(
+        response["data"]["relationships"]["friends"]["data"],
+        serde_json::json!([
+            { "type": "peoples", "id": CAROL },
+            { "type": "peoples", "id": BOB }
+        ])
+    );
+    assert_eq!(
+        response["included"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|x| x["id"].as_str().unwrap())
+            .collect::<Vec<&str>>(),
+        vec![CAROL, BOB]
+    );
+}
+
+#[test]
+fn include_reports_in_main_data() {
+    let store = gen_bag_self();
+    let peoples = store.get_type("peoples").unwrap();
+    let url = Url::parse("http://localhost/peoples?include=reports").unwrap();
+    let request = read_request(&store, &url);
+    let response = CibouletteResponseDataBuilder::new(
+        &request,
+        vec![
+            main_row(&store, ALICE),
+            main_row(&store, BOB),
+            related_row(&store, peoples, ALICE, "reports", BOB),
+        ],
+    )
+    .build(store.config())
+    .unwrap();
+    let response = serde_json::to_value(&response).unwrap();
+
+    assert_eq!(
+        response["data"][0]["relationships"]["reports"]["data"],
+        serde_json::json!({ "type": "peoples", "id": BOB })
+    );
+    // The related resource already being part of the main data, it isn't included
+    assert_eq!(response.get("included"), None);
+}
+
+#[test]
+fn definition_round_trip() {
+    let definition = gen_bag_self().to_definition().unwrap();
+    let friendships = definition
+        .relationships()
+        .iter()
+        .find_map(|x| match x {
+            CibouletteRelationshipDefinition::ManyToMany { bucket, from, to }
+                if bucket == "friendships" =>
+            {
+                Some((from.clone(), to.clone()))
+            }
+            _ => None,
+        })
+        .unwrap();
+
+    // The ends are sorted by key, each alias staying with its key
+    assert_eq!(
+        friendships,
+        (
+            CibouletteRelationshipEndDefinition::new(
+                "peoples".to_string(),
+                "friend_id".to_string(),
+                Some("friend_of".to_string())
+            ),
+            CibouletteRelationshipEndDefinition::new(
+                "peoples".to_string(),
+                "people_id".to_string(),
+                Some("friends".to_string())
+            )
+        )
+    );
+    let mut builder = CibouletteStoreBuilder::default();
+    builder.add_definition(&definition).unwrap();
+    let store = builder.build().unwrap();
+    assert_eq!(store.to_definition().unwrap(), definition);
+    assert_eq!(
+        many_to_many_keys(&store, "friends"),
+        ("people_id".to_string(), "friend_id".to_string())
+    );
+}
+
+#[test]
+fn dot() {
+    let dot = gen_bag_self().to_dot().unwrap();
+
+    assert_eq!(
+        dot.contains(
+            r#""peoples" -> "peoples" [label="friends\nManyToMany\nkeys: friendships.people_id, friendships.friend_id\nbucket: friendships"];"#
+        ),
+        true
+    );
+    assert_eq!(
+        dot.contains(
+            r#""peoples" -> "peoples" [label="manager\nManyToOne\nkeys: peoples.id <- peoples.manager\noptional"];"#
+        ),
+        true
+    );
+}
@@ -0,0 +1,174 @@
+use super::*;
+
+const DEFINITION: &str = r#"
+{
+    "types": {
+        "peoples": {
+            "ids": [{ "type": "uuid", "name": "id" }],
+            "attributes": {
+                "first-name": { "type": "string" },
+                "age": { "type": "number", "format": "u8", "optional": true },
+                "tags": { "type": "array", "items": { "type": "string" }, "optional": true }
+            }
+        },
+        "articles": {
+            "ids": [{ "type": "uuid", "name": "id" }],
+            "attributes": { "title": { "type": "string" } }
+        },
+        "people-article": {
+            "ids": [
+                { "type": "uuid", "name": "people_id" },
+                { "type": "uuid", "name": "article_id" }
+            ],
+            "attributes": {
+                "people_id": { "type": "uuid" },
+                "article_id": { "type": "uuid" }
+            }
+        }
+    },
+    "relationships": [
+        {
+            "kind": "one_to_many",
+            "one": { "type": "peoples", "key": "id", "alias": "written" },
+            "many": { "type": "articles", "key": "author", "alias": "author" }
+        },
+        {
+            "kind": "many_to_many",
+            "bucket": "people-article",
+            "from": { "type": "articles", "key": "article_id", "alias": "readers" },
+            "to": { "type": "peoples", "key": "people_id", "exposed": false }
+        }
+    ]
+}
+"#;
+
+fn load(definition: &CibouletteStoreDefinition) -> Result<CibouletteStore, CibouletteError> {
+    let mut builder = CibouletteStoreBuilder::default();
+    builder.add_definition(definition)?;
+    builder.build()
+}
+
+#[test]
+fn load_json() {
+    let definition: CibouletteStoreDefinition = serde_json::from_str(DEFINITION).unwrap();
+    let store = load(&definition).unwrap();
+
+    let peoples = store.get_type("peoples").unwrap();
+    assert_eq!(peoples.mandatory_attributes().contains("first-name"), true);
+    assert_eq!(peoples.mandatory_attributes().contains("age"), false);
+    assert_eq!(peoples.relationships().contains_key("written"), true);
+    let articles = store.get_type("articles").unwrap();
+    assert_eq!(articles.relationships().contains_key("author"), true);
+    assert_eq!(articles.relationships().contains_key("readers"), true);
+    // The Many-to-Many relationship isn't exposed on `peoples`
+    assert_eq!(peoples.relationships().contains_key("articles"), false);
+    let bucket = store.get_type("people-article").unwrap();
+    assert_eq!(bucket.ids().len(), 2);
+}
+
+#[test]
+fn dump_round_trip() {
+    let definition = gen_bag().to_definition().unwrap();
+    let serialized = serde_json::to_string(&definition).unwrap();
+    let deserialized: CibouletteStoreDefinition = serde_json::from_str(&serialized).unwrap();
+
+    assert_eq!(deserialized, definition);
+    assert_eq!(
+        load(&deserialized).unwrap().to_definition().unwrap(),
+        definition
+    );
+    assert_eq!(
+        definition.relationships().iter().any(|x| matches!(x,
+            CibouletteRelationshipDefinition::ManyToMany { bucket, .. } if bucket == "people-article"
+        )),
+        true
+    );
+}
+
+#[test]
+fn dump_defaults_aliases() {
+    let definition = gen_bag().to_definition().unwrap();
+    let comments_author = definition.relationships().iter().find_map(|x| match x {
+        CibouletteRelationshipDefinition::OneToMany { one, many, .. }
+            if one.type_() == "peoples" && many.type_() == "comments" =>
+        {
+            Some((one.alias().clone(), many.alias().clone()))
+        }
+        _ => None,
+    });
+
+    assert_eq!(
+        comments_author,
+        Some((Some("comments".to_string()), Some("author".to_string())))
+    );
+}
+
+#[test]
+fn unknown_type() {
+    let mut definition: CibouletteStoreDefinition = serde_json::from_str(DEFINITION).unwrap();
+    if let CibouletteRelationshipDefinition::OneToMany { many, .. } =
+        &mut definition.relationships_mut()[0]
+    {
+        *many.type_mut() = "comments".to_string();
+    }
+    let err = load(&definition).unwrap_err();
+
+    assert_eq!(
+        matches!(&err, CibouletteError::StoreDefinition(path, inner)
+            if path == "relationships.0.many.type"
+            && matches!(**inner, CibouletteError::UnknownType(ref x) if x == "comments")),
+        true
+    );
+}
+
+#[test]
+fn unknown_custom_id_type() {
+    let definition: CibouletteStoreDefinition = serde_json::from_value(serde_json::json!({
+        "types": {
+            "peoples": {
+                "ids": [{ "type": "custom", "name": "id", "parser": "nope" }]
+            }
+        }
+    }))
+    .unwrap();
+    let err = load(&definition).unwrap_err();
+
+    assert_eq!(
+        matches!(&err, CibouletteError::StoreDefinition(path, inner)
+            if path == "types.peoples.ids.0.parser"
+            && matches!(**inner, CibouletteError::UnknownIdType(_))),
+        true
+    );
+}
+
+#[test]
+fn unexposed_relationship() {
+    let mut definition: CibouletteStoreDefinition = serde_json::from_str(DEFINITION).unwrap();
+    if let CibouletteRelationshipDefinition::ManyToMany { from, .. } =
+        &mut definition.relationships_mut()[1]
+    {
+        *from.exposed_mut() = false;
+    }
+    let err = load(&definition).unwrap_err();
+
+    assert_eq!(
+        matches!(&err, CibouletteError::StoreDefinition(path, inner)
+            if path == "relationships.1"
+            && matches!(**inner, CibouletteError::UnexposedRelationship(_, _))),
+        true
+    );
+}
+
+#[test]
+fn unknown_field() {
+    let res = serde_json::from_value::<CibouletteStoreDefinition>(serde_json::json!({
+        "types": {
+            "peoples": {
+                "ids": [{ "type": "uuid", "name": "id" }],
+                "attributes": { "age": { "type": "number", "signed": true } }
+            }
+        }
+    }));
+
+    assert_eq!(res.is_err(), true);
+}
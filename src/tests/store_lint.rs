@@ -0,0 +1,162 @@
+use super::*;
+
+const DEFINITION: &str = r#"
+{
+    "types": {
+        "a": {
+            "ids": [{ "type": "uuid", "name": "id" }],
+            "attributes": {
+                "b_id": { "type": "string" },
+                "name": { "type": "string" }
+            }
+        },
+        "b": {
+            "ids": [{ "type": "uuid", "name": "id" }],
+            "attributes": { "a_id": { "type": "uuid" } }
+        },
+        "lonely": {
+            "ids": [{ "type": "uuid", "name": "id" }],
+            "attributes": {}
+        }
+    },
+    "relationships": [
+        {
+            "kind": "one_to_many",
+            "one": { "type": "a", "key": "id", "alias": "bs" },
+            "many": { "type": "b", "key": "a_id", "alias": "a" }
+        },
+        {
+            "kind": "one_to_many",
+            "one": { "type": "b", "key": "id", "alias": "as" },
+            "many": { "type": "a", "key": "b_id", "alias": "name" }
+        }
+    ]
+}
+"#;
+
+fn build_with_lints() -> (CibouletteStore, CibouletteLintReport) {
+    let definition: CibouletteStoreDefinition = serde_json::from_str(DEFINITION).unwrap();
+    let mut builder = CibouletteStoreBuilder::default();
+    builder.add_definition(&definition).unwrap();
+    builder.build_with_lints().unwrap()
+}
+
+#[test]
+fn missing_keys() {
+    let report = gen_bag().lint();
+
+    assert_eq!(
+        report.warnings(),
+        &vec![
+            CibouletteLint::MissingKey {
+                type_: "comments".to_string(),
+                key: "article".to_string()
+            },
+            CibouletteLint::MissingKey {
+                type_: "comments".to_string(),
+                key: "author".to_string()
+            },
+            CibouletteLint::MissingKey {
+                type_: "peoples".to_string(),
+                key: "favorite_color".to_string()
+            },
+        ]
+    );
+}
+
+#[test]
+fn every_lint() {
+    let (store, report) = build_with_lints();
+
+    assert_eq!(report, store.lint());
+    assert_eq!(
+        report.warnings(),
+        &vec![
+            CibouletteLint::KeyTypeMismatch {
+                type_: "a".to_string(),
+                key: "b_id".to_string(),
+                expected: "uuid".to_string(),
+                found: "string".to_string()
+            },
+            CibouletteLint::UnreachableType {
+                type_: "lonely".to_string()
+            },
+            CibouletteLint::AliasClash {
+                type_: "a".to_string(),
+                alias: "name".to_string()
+            },
+            CibouletteLint::MandatoryCycle {
+                types: vec!["a".to_string(), "b".to_string()]
+            },
+        ]
+    );
+    assert_eq!(
+        serde_json::to_value(&report.warnings()[0]).unwrap(),
+        serde_json::json!({
+            "kind": "key_type_mismatch",
+            "type": "a",
+            "key": "b_id",
+            "expected": "uuid",
+            "found": "string"
+        })
+    );
+}
+
+#[derive(Debug)]
+struct SlugIdParser;
+
+impl CibouletteIdParser for SlugIdParser {
+    fn name(&self) -> &str {
+        "slug"
+    }
+
+    fn parse<'request>(
+        &self,
+        id: Cow<'request, str>,
+    ) -> Result<Cow<'request, str>, CibouletteError> {
+        Ok(id)
+    }
+}
+
+#[test]
+fn custom_id_keys() {
+    let definition: CibouletteStoreDefinition = serde_json::from_value(serde_json::json!({
+        "types": {
+            "a": {
+                "ids": [{ "type": "custom", "name": "id", "parser": "slug" }]
+            },
+            "b": {
+                "ids": [{ "type": "uuid", "name": "id" }],
+                "attributes": { "a_id": { "type": "string" } }
+            }
+        },
+        "relationships": [
+            {
+                "kind": "one_to_many",
+                "one": { "type": "a", "key": "id", "alias": "bs" },
+                "many": { "type": "b", "key": "a_id", "alias": "a" }
+            }
+        ]
+    }))
+    .unwrap();
+    let mut builder = CibouletteStoreBuilder::default();
+    builder.add_id_type(Arc::new(SlugIdParser)).unwrap();
+    builder.add_definition(&definition).unwrap();
+
+    assert_eq!(builder.build().unwrap().lint().is_empty(), true);
+}
+
+#[test]
+fn deny_warnings() {
+    let (_store, report) = build_with_lints();
+
+    assert_eq!(report.is_empty(), false);
+    assert_eq!(
+        matches!(report.deny_warnings(), Err(CibouletteError::LintWarnings(x)) if x.len() == 4),
+        true
+    );
+    assert_eq!(
+        CibouletteLintReport::default().deny_warnings().is_ok(),
+        true
+    );
+}
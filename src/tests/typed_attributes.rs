@@ -0,0 +1,140 @@
+use super::*;
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct PeopleCreate {
+    #[serde(rename = "first-name")]
+    first_name: String,
+    #[serde(rename = "last-name")]
+    last_name: String,
+    age: Option<u8>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct PeopleUpdate {
+    #[serde(rename = "first-name", default)]
+    first_name: CibouletteAttributePatch<String>,
+    #[serde(rename = "last-name", default)]
+    last_name: CibouletteAttributePatch<String>,
+    #[serde(default)]
+    gender: CibouletteAttributePatch<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct WrongAge {
+    #[allow(dead_code)]
+    age: String,
+}
+
+fn update_request<'request>(
+    store: &'request CibouletteStore,
+    url: &'request Url,
+    body: &'request Option<&'request str>,
+) -> CibouletteUpdateRequest<'request> {
+    let request = build_request(store, CibouletteIntention::Update, url, body).unwrap();
+    CibouletteUpdateRequest::try_from(request).unwrap()
+}
+
+#[test]
+fn create() {
+    let store = gen_bag();
+    let url = Url::parse("http://localhost/peoples").unwrap();
+    let body = Some(
+        r#"{"data":{"type":"peoples","attributes":{"first-name":"a","last-name":"b","age":42}}}"#,
+    );
+    let request = CibouletteRequestBuilder::new(CibouletteIntention::Create, &url, &body)
+        .build(&store)
+        .unwrap();
+    let request = CibouletteCreateRequest::try_from(request).unwrap();
+
+    assert_eq!(
+        request.attributes_as::<PeopleCreate>().unwrap(),
+        PeopleCreate {
+            first_name: "a".to_string(),
+            last_name: "b".to_string(),
+            age: Some(42),
+        }
+    );
+}
+
+#[test]
+fn update_partial() {
+    let store = gen_bag();
+    let url = Url::parse("http://localhost/peoples/6720877a-e27e-4e9e-9ac0-3fff4deb55f2").unwrap();
+    let body = Some(
+        r#"{"data":{"id":"6720877a-e27e-4e9e-9ac0-3fff4deb55f2","type":"peoples","attributes":{"first-name":"a","gender":null}}}"#,
+    );
+    let request = update_request(&store, &url, &body);
+    let attributes = request.attributes_as::<PeopleUpdate>().unwrap();
+
+    assert_eq!(
+        attributes,
+        PeopleUpdate {
+            first_name: CibouletteAttributePatch::Set("a".to_string()),
+            last_name: CibouletteAttributePatch::Absent,
+            gender: CibouletteAttributePatch::Null,
+        }
+    );
+    assert_eq!(
+        Option::<Option<String>>::from(attributes.gender),
+        Some(None)
+    );
+}
+
+#[test]
+fn type_mismatch() {
+    let store = gen_bag();
+    let url = Url::parse("http://localhost/peoples/6720877a-e27e-4e9e-9ac0-3fff4deb55f2").unwrap();
+    let body = Some(
+        r#"{"data":{"id":"6720877a-e27e-4e9e-9ac0-3fff4deb55f2","type":"peoples","attributes":{"age":42}}}"#,
+    );
+    let request = update_request(&store, &url, &body);
+    let err = request.attributes_as::<WrongAge>().unwrap_err();
+
+    assert_eq!(
+        err.error_source().unwrap().pointer().as_deref(),
+        Some("/data/attributes/age")
+    );
+}
+
+#[test]
+fn type_mismatch_in_compound_document() {
+    let store = gen_bag();
+    let url = Url::parse("http://localhost/peoples").unwrap();
+    let body = Some(
+        r#"{"data":[{"type":"peoples","id":"6720877a-e27e-4e9e-9ac0-3fff4deb55f2"},{"type":"peoples","id":"6720877a-e27e-4e9e-9ac0-3fff4deb55f3","attributes":{"age":42}}]}"#,
+    );
+    let request = CibouletteReadRequest::try_from(
+        build_request(&store, CibouletteIntention::Read, &url, &body).unwrap(),
+    )
+    .unwrap();
+    let err = check_multi(request.data())[1]
+        .attributes_as::<WrongAge>()
+        .unwrap_err();
+
+    assert_eq!(
+        err.error_source().unwrap().pointer().as_deref(),
+        Some("/data/1/attributes/age")
+    );
+}
+
+#[test]
+fn relationship_update() {
+    let store = gen_bag();
+    let url =
+        Url::parse("http://localhost/peoples/6720877a-e27e-4e9e-9ac0-3fff4deb55f2/relationships/favorite_color")
+            .unwrap();
+    let body =
+        Some(r#"{"data":{"type":"favorite_color","id":"3fec6234-717b-4a93-8977-992168be747c"}}"#);
+    let request = update_request(&store, &url, &body);
+
+    assert_eq!(
+        matches!(
+            request.attributes_as::<PeopleUpdate>(),
+            Err(CibouletteError::WrongPathType(
+                CiboulettePathType::TypeIdRelationship,
+                _
+            ))
+        ),
+        true
+    );
+}
@@ -0,0 +1,194 @@
+use super::*;
+
+const BODY: &str = r#"{"nope":1,"data":{"type":"peoples","other":2,"attributes":{"first-name":"a","last-name":"b","shoe-size":42}}}"#;
+
+const TAG_EXT: &str = "https://example.com/ext/tag";
+
+#[derive(Debug)]
+struct TagExtension;
+
+impl CibouletteExtension for TagExtension {
+    fn uri(&self) -> &str {
+        TAG_EXT
+    }
+
+    fn namespace(&self) -> &str {
+        "tag"
+    }
+
+    fn document_members(&self) -> &[&str] {
+        &[]
+    }
+
+    fn resource_members(&self) -> &[&str] {
+        &["label"]
+    }
+
+    fn deserialize_member(
+        &self,
+        _member: &str,
+        value: &Value,
+    ) -> Result<CibouletteExtensionValue, CibouletteError> {
+        let label: String = serde_json::from_value(value.clone())?;
+        Ok(Arc::new(label))
+    }
+}
+
+fn gen_bag_policy(
+    policy: CibouletteUnknownMembersPolicy,
+    type_policy: Option<CibouletteUnknownMembersPolicy>,
+) -> CibouletteStore {
+    let mut store = gen_bag_builder();
+    store.add_extension(Arc::new(TagExtension)).unwrap();
+    *store.config_mut().unknown_members_policy_mut() = policy;
+    if let Some(type_policy) = type_policy {
+        store
+            .set_unknown_members_policy("peoples", type_policy)
+            .unwrap();
+    }
+    store.build().unwrap()
+}
+
+fn build_create<'request>(
+    store: &CibouletteStore,
+    url: &'request Url,
+    body: &'request Option<&'request str>,
+) -> Result<CibouletteCreateRequest<'request>, CibouletteError> {
+    let request = build_request(store, CibouletteIntention::Create, url, body)?;
+    CibouletteCreateRequest::try_from(request)
+}
+
+#[test]
+fn ignore() {
+    let store = gen_bag_policy(CibouletteUnknownMembersPolicy::Ignore, None);
+    let url = Url::parse("http://localhost/peoples").unwrap();
+    let body = Some(BODY);
+    let request = build_create(&store, &url, &body).unwrap();
+
+    assert_eq!(request.dropped_members().is_empty(), true);
+    assert_eq!(request.data().attribute("shoe-size").is_present(), false);
+    assert_eq!(request.data().attribute("first-name").is_present(), true);
+}
+
+#[test]
+fn ignore_and_report() {
+    let store = gen_bag_policy(CibouletteUnknownMembersPolicy::IgnoreAndReport, None);
+    let url = Url::parse("http://localhost/peoples").unwrap();
+    let body = Some(BODY);
+    let request = build_create(&store, &url, &body).unwrap();
+
+    assert_eq!(
+        request.dropped_members(),
+        &vec![
+            "/nope".to_string(),
+            "/data/other".to_string(),
+            "/data/attributes/shoe-size".to_string()
+        ]
+    );
+    assert_eq!(
+        request.data().dropped_members(),
+        &vec![
+            "/data/other".to_string(),
+            "/data/attributes/shoe-size".to_string()
+        ]
+    );
+}
+
+#[test]
+fn strict() {
+    let store = gen_bag_policy(CibouletteUnknownMembersPolicy::Strict, None);
+    let url = Url::parse("http://localhost/peoples").unwrap();
+    let body = Some(
+        r#"{"data":{"type":"peoples","attributes":{"first-name":"a","last-name":"b","shoe-size":42}}}"#,
+    );
+    let err = build_create(&store, &url, &body).unwrap_err();
+
+    assert_eq!(
+        matches!(&err, CibouletteError::UnknownMember(pointer) if pointer == "/data/attributes/shoe-size"),
+        true
+    );
+    assert_eq!(
+        err.error_source().unwrap().pointer().as_deref(),
+        Some("/data/attributes/shoe-size")
+    );
+}
+
+#[test]
+fn strict_top_level() {
+    let store = gen_bag_policy(CibouletteUnknownMembersPolicy::Strict, None);
+    let url = Url::parse("http://localhost/peoples").unwrap();
+    let body = Some(BODY);
+    let err = build_create(&store, &url, &body).unwrap_err();
+
+    assert_eq!(
+        matches!(&err, CibouletteError::UnknownMember(pointer) if pointer == "/nope"),
+        true
+    );
+}
+
+#[test]
+fn type_override() {
+    let store = gen_bag_policy(
+        CibouletteUnknownMembersPolicy::Ignore,
+        Some(CibouletteUnknownMembersPolicy::Strict),
+    );
+    let url = Url::parse("http://localhost/peoples").unwrap();
+    let body = Some(BODY);
+    let err = build_create(&store, &url, &body).unwrap_err();
+
+    assert_eq!(
+        matches!(&err, CibouletteError::UnknownMember(pointer) if pointer == "/data/other"),
+        true
+    );
+}
+
+#[test]
+fn at_members_and_extensions_are_not_reported() {
+    let store = gen_bag_policy(CibouletteUnknownMembersPolicy::Strict, None);
+    let url = Url::parse("http://localhost/peoples").unwrap();
+    let body = Some(
+        r#"{"@context":1,"data":{"type":"peoples","@meta":2,"tag:label":"x","attributes":{"@hidden":3,"first-name":"a","last-name":"b"}}}"#,
+    );
+    let media_type = format!("application/vnd.api+json; ext=\"{}\"", TAG_EXT);
+    let request = CibouletteRequestBuilder::new(CibouletteIntention::Create, &url, &body)
+        .with_media_type(media_type.as_str())
+        .build(&store)
+        .unwrap();
+    let request = CibouletteCreateRequest::try_from(request).unwrap();
+
+    assert_eq!(request.dropped_members().is_empty(), true);
+    assert_eq!(
+        request.data().extension_data().get::<String>("tag:label"),
+        Some(&"x".to_string())
+    );
+}
+
+#[test]
+fn extensions_not_negotiated() {
+    let store = gen_bag_policy(CibouletteUnknownMembersPolicy::Strict, None);
+    let url = Url::parse("http://localhost/peoples").unwrap();
+    let body = Some(
+        r#"{"data":{"type":"peoples","tag:label":"x","attributes":{"first-name":"a","last-name":"b"}}}"#,
+    );
+    let err = build_create(&store, &url, &body).unwrap_err();
+
+    assert_eq!(
+        matches!(err, CibouletteError::ExtensionNotNegotiated(uri) if uri == TAG_EXT),
+        true
+    );
+}
+
+#[test]
+fn escaped_pointer() {
+    let store = gen_bag_policy(CibouletteUnknownMembersPolicy::IgnoreAndReport, None);
+    let url = Url::parse("http://localhost/peoples").unwrap();
+    let body = Some(
+        r#"{"data":{"type":"peoples","attributes":{"first-name":"a","last-name":"b","a/b~c":1}}}"#,
+    );
+    let request = build_create(&store, &url, &body).unwrap();
+
+    assert_eq!(
+        request.dropped_members(),
+        &vec!["/data/attributes/a~1b~0c".to_string()]
+    );
+}